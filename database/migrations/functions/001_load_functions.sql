@@ -1,6 +1,7 @@
 {{ template "auth/user_has_image_access.sql" }}
 {{ template "dashboard/get_job_stats.sql" }}
 {{ template "dashboard/search_applications.sql" }}
+{{ template "dashboard/search_audit_log.sql" }}
 {{ template "img/get_image_version.sql" }}
 {{ template "jobboard/get_stats.sql" }}
 {{ template "jobboard/search_jobs.sql" }}
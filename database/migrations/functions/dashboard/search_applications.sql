@@ -5,15 +5,37 @@ create or replace function search_applications(
 )
 returns table(applications json, total bigint) as $$
 declare
+    v_after_application_id uuid := ((p_filters->'after')->>'application_id')::uuid;
+    v_after_applied_at timestamptz := ((p_filters->'after')->>'applied_at')::timestamptz;
     v_job_id uuid := (p_filters->>'job_id')::uuid;
     v_limit int := coalesce((p_filters->>'limit')::int, 20);
     v_offset int := coalesce((p_filters->>'offset')::int, 0);
+    v_status text := (p_filters->>'status');
 begin
     return query
     with filtered_applications as (
         select
             a.application_id,
             a.created_at as applied_at,
+            a.cover_letter,
+            a.rating,
+            a.screening_answers,
+            a.status,
+            (
+                select count(*)
+                from application_note n
+                where n.application_id = a.application_id
+            ) as notes_count,
+            (
+                select json_agg(json_build_object(
+                    'changed_at', c.created_at,
+                    'changed_by', u.name,
+                    'status', c.status
+                ) order by c.created_at desc)
+                from application_status_change c
+                left join "user" u on c.changed_by = u.user_id
+                where c.application_id = a.application_id
+            ) as status_history,
             j.job_id,
             j.title as job_title,
             (
@@ -28,6 +50,15 @@ begin
             p.job_seeker_profile_id,
             p.photo_id,
             p.name,
+            p.email,
+            (
+                select nullif(jsonb_strip_nulls(jsonb_build_object(
+                    'location_id', pl.location_id,
+                    'city', pl.city,
+                    'country', pl.country,
+                    'state', pl.state
+                )), '{}'::jsonb)
+            ) as location,
             (
                 select format(
                     '%s at %s', experience->>'title', experience->>'company'
@@ -42,17 +73,27 @@ begin
         join job j on a.job_id = j.job_id
         join job_seeker_profile p on a.job_seeker_profile_id = p.job_seeker_profile_id
         left join location l on j.location_id = l.location_id
+        left join location pl on p.location_id = pl.location_id
         where j.employer_id = p_employer_id
         and j.status <> 'deleted'
         and
             case when v_job_id is not null then
             a.job_id = v_job_id else true end
+        and
+            case when v_status is not null then
+            a.status = v_status else true end
     )
     select
         (
             select coalesce(json_agg(json_build_object(
                 'application_id', application_id,
                 'applied_at', applied_at,
+                'cover_letter', cover_letter,
+                'notes_count', notes_count,
+                'rating', rating,
+                'screening_answers', screening_answers,
+                'status', status,
+                'status_history', status_history,
                 'job_id', job_id,
                 'job_title', job_title,
                 'job_location', job_location,
@@ -60,12 +101,18 @@ begin
                 'job_workplace', job_workplace,
                 'photo_id', photo_id,
                 'name', name,
+                'email', email,
+                'location', location,
                 'last_position', last_position
             )), '[]')
             from (
                 select *
                 from filtered_applications
-                order by applied_at desc
+                where
+                    case when v_after_applied_at is not null then
+                    (applied_at, application_id) < (v_after_applied_at, v_after_application_id)
+                    else true end
+                order by applied_at desc, application_id desc
                 limit v_limit
                 offset v_offset
             ) filtered_applications_page
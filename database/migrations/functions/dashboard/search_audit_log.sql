@@ -0,0 +1,70 @@
+-- Returns the audit log entries that match the filters provided. When an
+-- employer is provided, only the entries of that employer are returned.
+create or replace function search_audit_log(
+    p_employer_id uuid,
+    p_filters jsonb
+)
+returns table(entries json, total bigint) as $$
+declare
+    v_limit int := coalesce((p_filters->>'limit')::int, 20);
+    v_offset int := coalesce((p_filters->>'offset')::int, 0);
+begin
+    return query
+    with filtered_entries as (
+        select
+            a.audit_log_id,
+            a.action,
+            a.created_at,
+            a.details,
+            a.employer_id,
+            a.target_id,
+            u.name as actor_name,
+            e.company
+        from audit_log a
+        left join "user" u on a.actor_id = u.user_id
+        left join employer e on a.employer_id = e.employer_id
+        where
+            case when p_employer_id is not null then
+            a.employer_id = p_employer_id else true end
+    )
+    select
+        (
+            select coalesce(json_agg(json_build_object(
+                'action', action,
+                'audit_log_id', audit_log_id,
+                'created_at', created_at,
+                'actor_name', actor_name,
+                'company', company,
+                'details', details,
+                'employer_id', employer_id,
+                'target_id', target_id,
+                'target_name', (
+                    case
+                        when action like 'application-%' then (
+                            select p.name
+                            from application ap
+                            join job_seeker_profile p using (job_seeker_profile_id)
+                            where ap.application_id = target_id
+                        )
+                        when action like 'job-%' then (
+                            select title from job where job_id = target_id
+                        )
+                        when action like 'team-member-%' then (
+                            select name from "user" where user_id = target_id
+                        )
+                    end
+                )
+            ) order by created_at desc), '[]')
+            from (
+                select *
+                from filtered_entries
+                order by created_at desc
+                limit v_limit
+                offset v_offset
+            ) filtered_entries_page
+        ),
+        (
+            select count(*) from filtered_entries
+        );
+end
+$$ language plpgsql;
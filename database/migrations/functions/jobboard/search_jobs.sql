@@ -12,6 +12,8 @@ declare
     v_max_distance real := (p_filters->>'max_distance')::real;
     v_offset int := coalesce((p_filters->>'offset')::int, 0);
     v_open_source int := (p_filters->>'open_source')::int;
+    v_published_after timestamptz := (p_filters->>'published_after')::timestamptz;
+    v_published_before timestamptz := (p_filters->>'published_before')::timestamptz;
     v_salary_min bigint := (p_filters->>'salary_min')::bigint;
     v_seniority text := (p_filters->>'seniority');
     v_skills text[];
@@ -133,6 +135,14 @@ begin
             case when v_date_from is not null and v_date_to is not null then
                 j.published_at::date >= v_date_from and j.published_at::date <= v_date_to
             else true end
+        and
+            case when v_published_after is not null then
+                j.published_at > v_published_after
+            else true end
+        and
+            case when v_published_before is not null then
+                j.published_at <= v_published_before
+            else true end
         and
             case when v_foundation is not null then
                 j.job_id = any(
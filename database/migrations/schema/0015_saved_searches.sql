@@ -0,0 +1,21 @@
+create table saved_search (
+    saved_search_id uuid primary key default gen_random_uuid(),
+    user_id uuid not null references "user" on delete cascade,
+    name text not null check (name <> ''),
+    filters jsonb not null,
+    frequency text not null check (frequency in ('daily', 'weekly')),
+    created_at timestamptz default current_timestamp not null,
+
+    last_run_at timestamptz
+);
+
+create index saved_search_user_id_idx on saved_search (user_id);
+
+insert into notification_kind (name) values ('job-alert-digest');
+
+---- create above / drop below ----
+
+delete from notification where kind = 'job-alert-digest';
+delete from notification_kind where name = 'job-alert-digest';
+
+drop table if exists saved_search;
@@ -0,0 +1,10 @@
+alter table "user" add column notify_new_applications boolean not null default true;
+
+insert into notification_kind (name) values ('new-application');
+
+---- create above / drop below ----
+
+delete from notification where kind = 'new-application';
+delete from notification_kind where name = 'new-application';
+
+alter table "user" drop column notify_new_applications;
@@ -0,0 +1,7 @@
+insert into notification_kind (name) values ('job-approved');
+insert into notification_kind (name) values ('job-rejected');
+
+---- create above / drop below ----
+
+delete from notification where kind in ('job-approved', 'job-rejected');
+delete from notification_kind where name in ('job-approved', 'job-rejected');
@@ -0,0 +1,38 @@
+create table application_status (
+    application_status_id uuid primary key default gen_random_uuid(),
+
+    name text not null unique check (name <> '')
+);
+
+insert into application_status (name) values ('new');
+insert into application_status (name) values ('reviewing');
+insert into application_status (name) values ('interviewing');
+insert into application_status (name) values ('offered');
+insert into application_status (name) values ('hired');
+insert into application_status (name) values ('rejected');
+
+alter table application add column status text not null default 'new'
+    references application_status (name) on delete restrict;
+
+create index application_status_idx on application (status);
+
+create table application_status_change (
+    application_status_change_id uuid primary key default gen_random_uuid(),
+    application_id uuid not null references application on delete cascade,
+    status text not null references application_status (name) on delete restrict,
+    created_at timestamptz not null default current_timestamp,
+
+    changed_by uuid references "user" on delete set null
+);
+
+create index application_status_change_application_id_idx on application_status_change (application_id);
+
+insert into application_status_change (application_id, status, created_at)
+select application_id, status, created_at
+from application;
+
+---- create above / drop below ----
+
+drop table if exists application_status_change;
+alter table application drop column status;
+drop table if exists application_status;
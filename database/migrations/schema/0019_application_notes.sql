@@ -0,0 +1,17 @@
+alter table application add column rating smallint check (rating between 1 and 5);
+
+create table application_note (
+    application_note_id uuid primary key default gen_random_uuid(),
+    application_id uuid not null references application on delete cascade,
+    content text not null check (content <> ''),
+    created_at timestamptz not null default current_timestamp,
+
+    user_id uuid references "user" on delete set null
+);
+
+create index application_note_application_id_idx on application_note (application_id);
+
+---- create above / drop below ----
+
+drop table if exists application_note;
+alter table application drop column rating;
@@ -0,0 +1,19 @@
+create table resume (
+    resume_id uuid primary key default gen_random_uuid(),
+    data bytea not null,
+    file_name text not null check (file_name <> ''),
+    created_at timestamptz not null default current_timestamp,
+
+    created_by uuid references "user" on delete set null
+);
+
+create index resume_created_by_idx on resume (created_by);
+
+alter table job_seeker_profile add column resume_id uuid references resume (resume_id) on delete set null;
+
+create index job_seeker_profile_resume_id_idx on job_seeker_profile (resume_id);
+
+---- create above / drop below ----
+
+alter table job_seeker_profile drop column resume_id;
+drop table if exists resume;
@@ -0,0 +1,8 @@
+alter table job add column screening_questions jsonb;
+
+alter table application add column screening_answers jsonb;
+
+---- create above / drop below ----
+
+alter table application drop column screening_answers;
+alter table job drop column screening_questions;
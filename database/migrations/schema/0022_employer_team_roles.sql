@@ -0,0 +1,21 @@
+create table employer_role (
+    employer_role_id uuid primary key default gen_random_uuid(),
+
+    name text not null unique check (name <> '')
+);
+
+insert into employer_role (name) values ('owner');
+insert into employer_role (name) values ('admin');
+insert into employer_role (name) values ('recruiter');
+insert into employer_role (name) values ('viewer');
+
+alter table employer_team add column role text not null default 'recruiter'
+    references employer_role (name) on delete restrict;
+
+-- Existing approved team members keep full access to their employers
+update employer_team set role = 'owner' where approved = true;
+
+---- create above / drop below ----
+
+alter table employer_team drop column role;
+drop table if exists employer_role;
@@ -0,0 +1,58 @@
+create table audit_action (
+    audit_action_id uuid primary key default gen_random_uuid(),
+
+    name text not null unique check (name <> '')
+);
+
+insert into audit_action (name) values ('application-status-updated');
+insert into audit_action (name) values ('employer-added');
+insert into audit_action (name) values ('employer-updated');
+insert into audit_action (name) values ('job-added');
+insert into audit_action (name) values ('job-approved');
+insert into audit_action (name) values ('job-archived');
+insert into audit_action (name) values ('job-deleted');
+insert into audit_action (name) values ('job-published');
+insert into audit_action (name) values ('job-rejected');
+insert into audit_action (name) values ('job-updated');
+insert into audit_action (name) values ('team-member-added');
+insert into audit_action (name) values ('team-member-joined');
+insert into audit_action (name) values ('team-member-removed');
+insert into audit_action (name) values ('team-member-role-updated');
+
+-- Entries must outlive the users, employers and jobs they refer to, so the
+-- identifiers below intentionally don't reference those tables
+create table audit_log (
+    audit_log_id uuid primary key default gen_random_uuid(),
+    action text not null references audit_action (name) on delete restrict,
+    created_at timestamptz not null default current_timestamp,
+
+    actor_id uuid,
+    details jsonb,
+    employer_id uuid,
+    target_id uuid
+);
+
+create index audit_log_created_at_idx on audit_log (created_at);
+create index audit_log_employer_id_created_at_idx on audit_log (employer_id, created_at);
+
+-- The audit log is append-only
+create or replace function audit_log_prevent_changes()
+returns trigger as $$
+begin
+    raise exception 'audit log entries cannot be modified or deleted';
+end
+$$ language plpgsql;
+
+create trigger audit_log_prevent_changes
+before update or delete on audit_log
+for each row execute function audit_log_prevent_changes();
+
+create trigger audit_log_prevent_truncate
+before truncate on audit_log
+for each statement execute function audit_log_prevent_changes();
+
+---- create above / drop below ----
+
+drop table if exists audit_log;
+drop function if exists audit_log_prevent_changes;
+drop table if exists audit_action;
@@ -0,0 +1,15 @@
+-- Snapshot of a job as it was right before being updated
+create table job_revision (
+    job_revision_id uuid primary key default gen_random_uuid(),
+    job_id uuid not null references job on delete cascade,
+    created_at timestamptz not null default current_timestamp,
+    data jsonb not null,
+
+    created_by uuid references "user" (user_id) on delete set null
+);
+
+create index job_revision_job_id_created_at_idx on job_revision (job_id, created_at);
+
+---- create above / drop below ----
+
+drop table if exists job_revision;
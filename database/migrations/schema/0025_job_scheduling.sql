@@ -0,0 +1,24 @@
+insert into job_status (name) values ('scheduled');
+
+alter table job add publish_date date;
+alter table job add closing_date date;
+alter table job add expiry_reminder_sent_at timestamptz;
+
+insert into notification_kind (name) values ('job-expiring');
+
+insert into audit_action (name) values ('job-renewed');
+
+---- create above / drop below ----
+
+update job set status = 'pending-approval' where status = 'scheduled';
+delete from job_status where name = 'scheduled';
+
+alter table job drop column publish_date;
+alter table job drop column closing_date;
+alter table job drop column expiry_reminder_sent_at;
+
+delete from notification where kind = 'job-expiring';
+delete from notification_kind where name = 'job-expiring';
+
+-- The audit log is append-only, so the job-renewed action is kept as entries
+-- may already refer to it
@@ -0,0 +1,14 @@
+-- Delivery attempts, used to retry failed notifications with backoff. Once
+-- the maximum number of attempts is reached, notifications are marked as
+-- processed keeping the last error (dead-lettered)
+alter table notification add attempts int not null default 0 check (attempts >= 0);
+alter table notification add last_attempt_at timestamptz;
+
+create index notification_failed_idx on notification (processed_at) where processed = 'true' and error is not null;
+
+---- create above / drop below ----
+
+drop index if exists notification_failed_idx;
+
+alter table notification drop column attempts;
+alter table notification drop column last_attempt_at;
@@ -0,0 +1,24 @@
+-- Notify the notifications workers listening when there are new notifications
+-- pending delivery, so that they don't have to wait for the next poll
+create or replace function notification_enqueued()
+returns trigger as $$
+begin
+    perform pg_notify('notification_enqueued', '');
+    return null;
+end
+$$ language plpgsql;
+
+create trigger notification_enqueued
+after insert on notification
+for each statement execute function notification_enqueued();
+
+create trigger notification_retried
+after update of processed on notification
+for each row when (old.processed and not new.processed)
+execute function notification_enqueued();
+
+---- create above / drop below ----
+
+drop trigger if exists notification_retried on notification;
+drop trigger if exists notification_enqueued on notification;
+drop function if exists notification_enqueued;
@@ -0,0 +1,35 @@
+-- Notification kinds users have opted in or out of (kinds without an entry
+-- are enabled)
+create table notification_preference (
+    user_id uuid not null references "user" on delete cascade,
+    kind text not null references notification_kind (name) on delete cascade,
+    enabled boolean not null,
+    updated_at timestamptz default current_timestamp not null,
+
+    primary key (user_id, kind)
+);
+
+insert into notification_preference (user_id, kind, enabled)
+select user_id, 'new-application', false
+from "user"
+where notify_new_applications = false;
+
+alter table "user" drop column notify_new_applications;
+
+-- How often job alert digests are sent, overriding the saved searches one
+alter table "user" add column job_alert_digest_frequency text
+    check (job_alert_digest_frequency in ('daily', 'weekly'));
+
+---- create above / drop below ----
+
+alter table "user" add column notify_new_applications boolean not null default true;
+
+update "user" u set notify_new_applications = false
+from notification_preference p
+where p.user_id = u.user_id
+and p.kind = 'new-application'
+and p.enabled = false;
+
+alter table "user" drop column job_alert_digest_frequency;
+
+drop table if exists notification_preference;
@@ -0,0 +1,8 @@
+-- Notifications are claimed by the workers before being delivered, so that
+-- they are not kept locked while the delivery is in progress. Claims that are
+-- not released in time (i.e. the worker crashed) expire
+alter table notification add claimed_at timestamptz;
+
+---- create above / drop below ----
+
+alter table notification drop column claimed_at;
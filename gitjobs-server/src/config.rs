@@ -2,7 +2,7 @@
 
 use std::{collections::HashMap, path::PathBuf};
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use deadpool_postgres::Config as DbConfig;
 use figment::{
     Figment,
@@ -11,6 +11,11 @@ use figment::{
 use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
+use crate::notifications::NotificationKind;
+
+/// Minimum length of the secret used to sign the unsubscribe links.
+const MIN_UNSUBSCRIBE_SECRET_LEN: usize = 32;
+
 /// Main server configuration struct, containing all sub-configurations.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub(crate) struct Config {
@@ -22,6 +27,10 @@ pub(crate) struct Config {
     pub log: LogConfig,
     /// HTTP server configuration.
     pub server: HttpServerConfig,
+
+    /// Notifications channels configuration.
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
 }
 
 impl Config {
@@ -29,6 +38,9 @@ impl Config {
     #[instrument(err)]
     pub(crate) fn new(config_file: Option<&PathBuf>) -> Result<Self> {
         let mut figment = Figment::new()
+            .merge(Serialized::default("email.batch_size", 10))
+            .merge(Serialized::default("email.poll_interval_secs", 15))
+            .merge(Serialized::default("email.workers", 1))
             .merge(Serialized::default("log.format", "json"))
             .merge(Serialized::default("server.addr", "127.0.0.1:9000"));
 
@@ -36,10 +48,22 @@ impl Config {
             figment = figment.merge(Yaml::file(config_file));
         }
 
-        figment
-            .merge(Env::prefixed("GITJOBS_").split("__"))
-            .extract()
-            .map_err(Into::into)
+        let cfg: Self = figment.merge(Env::prefixed("GITJOBS_").split("__")).extract()?;
+        cfg.validate()?;
+
+        Ok(cfg)
+    }
+
+    /// Check the configuration values that can't be validated when
+    /// deserializing it.
+    fn validate(&self) -> Result<()> {
+        // The unsubscribe links are signed with this secret, so anyone could
+        // forge them if it was empty or easy to guess
+        if self.server.unsubscribe_secret.len() < MIN_UNSUBSCRIBE_SECRET_LEN {
+            bail!("server.unsubscribe_secret must be at least {MIN_UNSUBSCRIBE_SECRET_LEN} characters long");
+        }
+
+        Ok(())
     }
 }
 
@@ -52,6 +76,15 @@ pub(crate) struct EmailConfig {
     pub from_name: String,
     /// SMTP server configuration.
     pub smtp: SmtpConfig,
+
+    /// Maximum number of notifications each worker claims and delivers at once.
+    pub batch_size: i64,
+    /// Seconds to wait before checking again for pending notifications when
+    /// the queue is empty. Workers are also woken up as soon as a new
+    /// notification is enqueued.
+    pub poll_interval_secs: u64,
+    /// Number of concurrent workers that deliver notifications.
+    pub workers: usize,
 }
 
 /// SMTP server configuration.
@@ -67,6 +100,54 @@ pub(crate) struct SmtpConfig {
     pub password: String,
 }
 
+/// Notifications channels configuration.
+///
+/// Channels are defined by name and selected per notification kind in the
+/// routes. Kinds without a route are delivered using the built-in `email`
+/// channel, except for job published announcements, which are only posted
+/// to the channels explicitly configured for them.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub(crate) struct NotificationsConfig {
+    /// Channels available, indexed by name.
+    #[serde(default)]
+    pub channels: HashMap<String, ChannelConfig>,
+    /// Names of the channels used to deliver each notification kind.
+    #[serde(default)]
+    pub routes: HashMap<NotificationKind, Vec<String>>,
+}
+
+/// Notification channel configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum ChannelConfig {
+    /// Discord channel, using an incoming webhook.
+    Discord {
+        /// Discord webhook URL.
+        webhook_url: String,
+    },
+    /// Email channel, using the SMTP server in the email configuration.
+    Email,
+    /// Matrix room.
+    Matrix {
+        /// Access token of the user posting the messages.
+        access_token: String,
+        /// Homeserver URL (i.e. `https://matrix.org`).
+        homeserver_url: String,
+        /// Room ID (i.e. `!abcdef:matrix.org`).
+        room_id: String,
+    },
+    /// Slack channel, using an incoming webhook.
+    Slack {
+        /// Slack webhook URL.
+        webhook_url: String,
+    },
+    /// Generic webhook, receiving the messages as JSON.
+    Webhook {
+        /// Webhook URL.
+        url: String,
+    },
+}
+
 /// Logging configuration.
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub(crate) struct LogConfig {
@@ -97,6 +178,8 @@ pub(crate) struct HttpServerConfig {
     pub oauth2: OAuth2Config,
     /// `Oidc` providers configuration.
     pub oidc: OidcConfig,
+    /// Secret used to sign the links to unsubscribe from notifications.
+    pub unsubscribe_secret: String,
 
     /// Optional analytics configuration.
     pub analytics: Option<AnalyticsConfig>,
@@ -105,6 +188,10 @@ pub(crate) struct HttpServerConfig {
     /// Optional cookie configuration.
     pub cookie: Option<CookieConfig>,
     /// Optional Slack webhook URL to post new jobs published notifications.
+    ///
+    /// Deprecated: configure a `slack` channel for the `job-published`
+    /// notifications instead. It's only used when no route has been defined
+    /// for them.
     pub slack_webhook_url: Option<String>,
 }
 
@@ -200,3 +287,66 @@ pub(crate) struct OidcProviderConfig {
     /// Scopes requested from the provider.
     pub scopes: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use uuid::Uuid;
+
+    use super::*;
+
+    /// Minimal configuration file, with a placeholder for the server options.
+    const CONFIG: &str = "
+db: {}
+email:
+  from_address: no-reply@example.com
+  from_name: GitJobs
+  smtp:
+    host: localhost
+    port: 25
+    username: user
+    password: pass
+server:
+  base_url: http://localhost:9000
+  login:
+    email: true
+    github: false
+    linuxfoundation: false
+  oauth2: {}
+  oidc: {}
+";
+
+    /// Load the configuration from a file with the extra server options
+    /// provided appended to the minimal configuration.
+    fn load_config(server_options: &str) -> Result<Config> {
+        let path = std::env::temp_dir().join(format!("gitjobs-config-{}.yml", Uuid::new_v4()));
+        fs::write(&path, format!("{CONFIG}{server_options}")).unwrap();
+        let cfg = Config::new(Some(&path));
+        fs::remove_file(&path).unwrap();
+        cfg
+    }
+
+    #[test]
+    fn config_with_unsubscribe_secret_is_loaded() {
+        let cfg = load_config("  unsubscribe_secret: 0123456789abcdef0123456789abcdef\n").unwrap();
+        assert_eq!(cfg.server.unsubscribe_secret, "0123456789abcdef0123456789abcdef");
+    }
+
+    #[test]
+    fn config_without_unsubscribe_secret_is_rejected() {
+        assert!(load_config("").is_err());
+    }
+
+    #[test]
+    fn config_with_empty_unsubscribe_secret_is_rejected() {
+        let err = load_config("  unsubscribe_secret: \"\"\n").unwrap_err();
+        assert!(err.to_string().contains("unsubscribe_secret"));
+    }
+
+    #[test]
+    fn config_with_short_unsubscribe_secret_is_rejected() {
+        let err = load_config("  unsubscribe_secret: secret\n").unwrap_err();
+        assert!(err.to_string().contains("unsubscribe_secret"));
+    }
+}
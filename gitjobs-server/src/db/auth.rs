@@ -11,6 +11,8 @@ use uuid::Uuid;
 use crate::{
     auth::{User, UserSummary},
     db::PgDB,
+    notifications::NotificationKind,
+    templates::{auth::UserNotificationsSettings, dashboard::employer::team::EmployerRole},
 };
 
 /// Trait for database operations related to authentication and authorization.
@@ -34,6 +36,21 @@ pub(crate) trait DBAuth {
     /// Retrieves a user by their username.
     async fn get_user_by_username(&self, username: &str) -> Result<Option<User>>;
 
+    /// Retrieves the role of a user in the team of an employer. Returns `None`
+    /// if the user is not an approved member of the team.
+    async fn get_user_employer_role(
+        &self,
+        user_id: &Uuid,
+        employer_id: &Uuid,
+    ) -> Result<Option<EmployerRole>>;
+
+    /// Retrieves the role of a user in the team of the employer that owns a
+    /// job. Returns `None` if the user is not an approved member of the team.
+    async fn get_user_job_role(&self, user_id: &Uuid, job_id: &Uuid) -> Result<Option<EmployerRole>>;
+
+    /// Retrieves the email notifications settings for a user.
+    async fn get_user_notifications_settings(&self, user_id: &Uuid) -> Result<UserNotificationsSettings>;
+
     /// Retrieves the password hash for a user.
     async fn get_user_password(&self, user_id: &Uuid) -> Result<Option<String>>;
 
@@ -53,6 +70,13 @@ pub(crate) trait DBAuth {
     /// Updates user details in the database.
     async fn update_user_details(&self, user_id: &Uuid, user_summary: &UserSummary) -> Result<()>;
 
+    /// Updates a user's email notifications settings in the database.
+    async fn update_user_notifications_settings(
+        &self,
+        user_id: &Uuid,
+        settings: &UserNotificationsSettings,
+    ) -> Result<()>;
+
     /// Updates a user's password in the database.
     async fn update_user_password(&self, user_id: &Uuid, new_password: &str) -> Result<()>;
 
@@ -62,12 +86,6 @@ pub(crate) trait DBAuth {
     /// Checks if a user has access to a specific job seeker profile.
     async fn user_has_profile_access(&self, user_id: &Uuid, job_seeker_profile_id: &Uuid) -> Result<bool>;
 
-    /// Checks if a user owns a specific employer.
-    async fn user_owns_employer(&self, user_id: &Uuid, employer_id: &Uuid) -> Result<bool>;
-
-    /// Checks if a user owns a specific job.
-    async fn user_owns_job(&self, user_id: &Uuid, job_id: &Uuid) -> Result<bool>;
-
     /// Verifies a user's email address using a verification code.
     async fn verify_email(&self, code: &Uuid) -> Result<()>;
 }
@@ -270,6 +288,100 @@ impl DBAuth for PgDB {
         Ok(user)
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_user_employer_role(
+        &self,
+        user_id: &Uuid,
+        employer_id: &Uuid,
+    ) -> Result<Option<EmployerRole>> {
+        trace!("db: get user employer role");
+
+        let db = self.pool.get().await?;
+        let role = db
+            .query_opt(
+                "
+                select role
+                from employer_team
+                where user_id = $1::uuid
+                and employer_id = $2::uuid
+                and approved = true;
+                ",
+                &[&user_id, &employer_id],
+            )
+            .await?
+            .map(|row| row.get::<_, String>("role").parse().expect("valid employer role"));
+
+        Ok(role)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_user_job_role(&self, user_id: &Uuid, job_id: &Uuid) -> Result<Option<EmployerRole>> {
+        trace!("db: get user job role");
+
+        let db = self.pool.get().await?;
+        let role = db
+            .query_opt(
+                "
+                select et.role
+                from job j
+                join employer_team et using (employer_id)
+                where et.user_id = $1::uuid
+                and j.job_id = $2::uuid
+                and et.approved = true;
+                ",
+                &[&user_id, &job_id],
+            )
+            .await?
+            .map(|row| row.get::<_, String>("role").parse().expect("valid employer role"));
+
+        Ok(role)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_user_notifications_settings(&self, user_id: &Uuid) -> Result<UserNotificationsSettings> {
+        trace!("db: get user notifications settings");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                r#"
+                select
+                    u.job_alert_digest_frequency,
+                    coalesce(
+                        jsonb_object_agg(p.kind, p.enabled) filter (where p.kind is not null),
+                        '{}'::jsonb
+                    ) as preferences
+                from "user" u
+                left join notification_preference p using (user_id)
+                where u.user_id = $1::uuid
+                group by u.user_id;
+                "#,
+                &[&user_id],
+            )
+            .await?;
+
+        // Notification kinds without a preference are enabled
+        let preferences: serde_json::Value = row.get("preferences");
+        let is_enabled = |kind: NotificationKind| {
+            preferences
+                .get(kind.to_string())
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true)
+        };
+        let settings = UserNotificationsSettings {
+            notify_job_alert_digest: is_enabled(NotificationKind::JobAlertDigest),
+            notify_job_approved: is_enabled(NotificationKind::JobApproved),
+            notify_job_expiring: is_enabled(NotificationKind::JobExpiring),
+            notify_job_rejected: is_enabled(NotificationKind::JobRejected),
+            notify_new_applications: is_enabled(NotificationKind::NewApplication),
+            job_alert_digest_frequency: row
+                .get::<_, Option<String>>("job_alert_digest_frequency")
+                .map(|frequency| frequency.parse().expect("valid frequency")),
+        };
+
+        Ok(settings)
+    }
+
     #[instrument(skip(self), err)]
     async fn get_user_password(&self, user_id: &Uuid) -> Result<Option<String>> {
         trace!("db: get user password");
@@ -444,6 +556,51 @@ impl DBAuth for PgDB {
         Ok(())
     }
 
+    #[instrument(skip(self), err)]
+    async fn update_user_notifications_settings(
+        &self,
+        user_id: &Uuid,
+        settings: &UserNotificationsSettings,
+    ) -> Result<()> {
+        trace!("db: update user notifications settings");
+
+        let (kinds, enabled): (Vec<String>, Vec<bool>) = settings
+            .preferences()
+            .into_iter()
+            .map(|(kind, enabled)| (kind.to_string(), enabled))
+            .unzip();
+
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+        tx.execute(
+            r#"
+            update "user" set
+                job_alert_digest_frequency = $2::text
+            where user_id = $1::uuid;
+            "#,
+            &[
+                &user_id,
+                &settings.job_alert_digest_frequency.as_ref().map(ToString::to_string),
+            ],
+        )
+        .await?;
+        tx.execute(
+            "
+            insert into notification_preference (user_id, kind, enabled)
+            select $1::uuid, kind, enabled
+            from unnest($2::text[], $3::boolean[]) as p(kind, enabled)
+            on conflict (user_id, kind) do update set
+                enabled = excluded.enabled,
+                updated_at = current_timestamp;
+            ",
+            &[&user_id, &kinds, &enabled],
+        )
+        .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self, new_password), err)]
     async fn update_user_password(&self, user_id: &Uuid, new_password: &str) -> Result<()> {
         trace!("db: update user password");
@@ -504,53 +661,6 @@ impl DBAuth for PgDB {
         Ok(row.get(0))
     }
 
-    #[instrument(skip(self), err)]
-    async fn user_owns_employer(&self, user_id: &Uuid, employer_id: &Uuid) -> Result<bool> {
-        trace!("db: check if user owns employer");
-
-        let db = self.pool.get().await?;
-        let row = db
-            .query_one(
-                "
-                select exists (
-                    select 1
-                    from employer_team
-                    where user_id = $1::uuid
-                    and employer_id = $2::uuid
-                    and approved = true
-                ) as owns_employer;
-                ",
-                &[&user_id, &employer_id],
-            )
-            .await?;
-
-        Ok(row.get("owns_employer"))
-    }
-
-    #[instrument(skip(self), err)]
-    async fn user_owns_job(&self, user_id: &Uuid, job_id: &Uuid) -> Result<bool> {
-        trace!("db: check if user owns job");
-
-        let db = self.pool.get().await?;
-        let row = db
-            .query_one(
-                "
-                select exists (
-                    select 1
-                    from job j
-                    join employer_team et using (employer_id)
-                    where et.user_id = $1::uuid
-                    and j.job_id = $2::uuid
-                    and et.approved = true
-                ) as owns_job;
-                ",
-                &[&user_id, &job_id],
-            )
-            .await?;
-
-        Ok(row.get("owns_job"))
-    }
-
     #[instrument(skip(self, code), err)]
     async fn verify_email(&self, code: &Uuid) -> Result<()> {
         trace!("db: verify email");
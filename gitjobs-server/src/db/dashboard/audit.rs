@@ -0,0 +1,181 @@
+//! This module defines database operations for the audit log.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use deadpool_postgres::Transaction;
+use serde::{Deserialize, Serialize};
+use tokio_postgres::types::Json;
+use tracing::{instrument, trace};
+use uuid::Uuid;
+
+use crate::{
+    PgDB,
+    templates::dashboard::audit::{AuditAction, AuditLogDetails, AuditLogEntry, Filters},
+};
+
+/// Trait for audit log database operations.
+#[async_trait]
+pub(crate) trait DBDashBoardAudit {
+    /// Searches the audit log entries, optionally scoped to an employer.
+    async fn search_audit_log(
+        &self,
+        employer_id: Option<&Uuid>,
+        filters: &Filters,
+    ) -> Result<AuditLogSearchOutput>;
+}
+
+#[async_trait]
+impl DBDashBoardAudit for PgDB {
+    #[instrument(skip(self), err)]
+    async fn search_audit_log(
+        &self,
+        employer_id: Option<&Uuid>,
+        filters: &Filters,
+    ) -> Result<AuditLogSearchOutput> {
+        trace!("db: search audit log");
+
+        // Query database
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "select entries::text, total from search_audit_log($1::uuid, $2::jsonb)",
+                &[&employer_id, &Json(filters)],
+            )
+            .await?;
+
+        // Prepare search output
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let output = AuditLogSearchOutput {
+            entries: serde_json::from_str(&row.get::<_, String>("entries"))?,
+            total: row.get::<_, i64>("total") as usize,
+        };
+
+        Ok(output)
+    }
+}
+
+/// Appends an entry to the audit log as part of the transaction provided, so
+/// that it's only recorded if the action it describes is committed.
+pub(crate) async fn add_audit_log_entry(tx: &Transaction<'_>, entry: &NewAuditLogEntry) -> Result<()> {
+    tx.execute(
+        "
+        insert into audit_log (
+            action,
+            actor_id,
+            details,
+            employer_id,
+            target_id
+        ) values (
+            $1::text,
+            $2::uuid,
+            $3::jsonb,
+            $4::uuid,
+            $5::uuid
+        );
+        ",
+        &[
+            &entry.action.to_string(),
+            &entry.actor_id,
+            &entry.details.as_ref().map(Json),
+            &entry.employer_id,
+            &entry.target_id,
+        ],
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Returns the names of the top level fields whose values differ between the
+/// two snapshots provided, sorted alphabetically.
+pub(crate) fn changed_fields(before: &serde_json::Value, after: &serde_json::Value) -> Vec<String> {
+    let empty = serde_json::Map::new();
+    let before = before.as_object().unwrap_or(&empty);
+    let after = after.as_object().unwrap_or(&empty);
+
+    let mut fields: Vec<String> = before
+        .keys()
+        .chain(after.keys())
+        .filter(|key| before.get(*key) != after.get(*key))
+        .cloned()
+        .collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+}
+
+/// Audit log search results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct AuditLogSearchOutput {
+    /// List of entries matching the search.
+    pub entries: Vec<AuditLogEntry>,
+    /// Total number of entries found.
+    pub total: usize,
+}
+
+/// New entry to append to the audit log.
+#[derive(Debug, Clone)]
+pub(crate) struct NewAuditLogEntry {
+    /// Action performed.
+    pub action: AuditAction,
+    /// Unique identifier of the user who performed the action.
+    pub actor_id: Uuid,
+
+    /// Additional details about the action.
+    pub details: Option<AuditLogDetails>,
+    /// Unique identifier of the employer the action applies to.
+    pub employer_id: Option<Uuid>,
+    /// Unique identifier of the target of the action.
+    pub target_id: Option<Uuid>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::changed_fields;
+
+    #[test]
+    fn changed_fields_added_removed_and_changed() {
+        let before = json!({"description": "Old", "removed": 1, "same": true, "title": "Engineer"});
+        let after = json!({"added": [], "description": "New", "same": true, "title": "Engineer"});
+
+        assert_eq!(
+            changed_fields(&before, &after),
+            vec!["added", "description", "removed"]
+        );
+    }
+
+    #[test]
+    fn changed_fields_nested_values_compared_as_a_whole() {
+        let before = json!({"salary": {"min": 1, "max": 2}, "skills": ["rust"]});
+        let after = json!({"salary": {"min": 1, "max": 3}, "skills": ["rust"]});
+
+        assert_eq!(changed_fields(&before, &after), vec!["salary"]);
+    }
+
+    #[test]
+    fn changed_fields_null_differs_from_missing() {
+        let before = json!({"location": null});
+        let after = json!({});
+
+        assert_eq!(changed_fields(&before, &after), vec!["location"]);
+    }
+
+    #[test]
+    fn changed_fields_unchanged() {
+        let value = json!({"title": "Engineer"});
+
+        assert!(changed_fields(&value, &value).is_empty());
+    }
+
+    #[test]
+    fn changed_fields_non_object_input() {
+        let object = json!({"title": "Engineer"});
+
+        assert_eq!(changed_fields(&json!(null), &object), vec!["title"]);
+        assert_eq!(changed_fields(&object, &json!([1, 2])), vec!["title"]);
+        assert!(changed_fields(&json!("a"), &json!(1)).is_empty());
+    }
+}
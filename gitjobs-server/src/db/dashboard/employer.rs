@@ -1,7 +1,9 @@
 //! This module defines database operations for the employer dashboard.
 
-use anyhow::{Result, bail};
+use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use deadpool_postgres::{GenericClient, Transaction};
 use serde::{Deserialize, Serialize};
 use tokio_postgres::types::Json;
 use tracing::{instrument, trace};
@@ -9,12 +11,17 @@ use uuid::Uuid;
 
 use crate::{
     PgDB,
+    db::dashboard::audit::{NewAuditLogEntry, add_audit_log_entry, changed_fields},
     templates::{
+        dashboard::audit::{AuditAction, AuditLogDetails},
         dashboard::employer::{
-            applications::{self, Application},
+            applications::{
+                self, Application, ApplicationFeedback, ApplicationRatingInput, ApplicationStatus,
+                NewApplicationNote,
+            },
             employers::{Employer, EmployerSummary},
             jobs::{Job, JobStats, JobSummary},
-            team::{TeamInvitation, TeamMember},
+            team::{EmployerRole, TeamInvitation, TeamMember},
         },
         helpers::normalize_salary,
         misc::{Certification, Foundation},
@@ -27,25 +34,50 @@ pub(crate) trait DBDashBoardEmployer {
     /// Accepts a team member invitation for an employer.
     async fn accept_team_member_invitation(&self, employer_id: &Uuid, user_id: &Uuid) -> Result<()>;
 
+    /// Adds a note to an application received by the employer.
+    async fn add_application_note(
+        &self,
+        employer_id: &Uuid,
+        profile_id: &Uuid,
+        user_id: &Uuid,
+        note: &NewApplicationNote,
+    ) -> Result<()>;
+
     /// Adds a new employer to the database.
     async fn add_employer(&self, user_id: &Uuid, employer: &Employer) -> Result<Uuid>;
 
     /// Adds a new job for an employer.
-    async fn add_job(&self, employer_id: &Uuid, job: &Job) -> Result<()>;
+    async fn add_job(&self, employer_id: &Uuid, job: &Job, actor_id: &Uuid) -> Result<()>;
+
+    /// Adds several new jobs for an employer in a single transaction, so that
+    /// either all of them are added or none is.
+    async fn add_jobs(&self, employer_id: &Uuid, jobs: &[Job], actor_id: &Uuid) -> Result<()>;
 
     /// Adds a team member to an employer's team.
-    async fn add_team_member(&self, employer_id: &Uuid, email: &str) -> Result<Option<Uuid>>;
+    async fn add_team_member(
+        &self,
+        employer_id: &Uuid,
+        email: &str,
+        role: &EmployerRole,
+        actor_id: &Uuid,
+    ) -> Result<Option<Uuid>>;
 
     /// Archives a job, marking it as no longer active.
-    async fn archive_job(&self, job_id: &Uuid) -> Result<()>;
+    async fn archive_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()>;
 
     /// Mark a job as deleted in the database (soft delete).
-    async fn delete_job(&self, job_id: &Uuid) -> Result<()>;
+    async fn delete_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()>;
 
     /// Deletes a team member from an employer's team.
     ///
-    /// There must be at least one approved team member left after deletion.
-    async fn delete_team_member(&self, employer_id: &Uuid, user_id: &Uuid) -> Result<()>;
+    /// There must be at least one approved owner left after deletion. Returns
+    /// false if the member could not be deleted because of this.
+    async fn delete_team_member(&self, employer_id: &Uuid, user_id: &Uuid, actor_id: &Uuid) -> Result<bool>;
+
+    /// Creates a new draft job from a copy of an existing one, returning the
+    /// identifier of the new job. Returns None if the job was not found or it
+    /// has been deleted.
+    async fn duplicate_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<Option<Uuid>>;
 
     /// Retrieves available filter options for applications.
     async fn get_applications_filters_options(
@@ -65,9 +97,21 @@ pub(crate) trait DBDashBoardEmployer {
     /// Retrieves a job's statistics.
     async fn get_job_stats(&self, job_id: &Uuid) -> Result<JobStats>;
 
+    /// Retrieves the role of a team member, whether the invitation has been
+    /// accepted or not.
+    async fn get_team_member_role(&self, employer_id: &Uuid, user_id: &Uuid) -> Result<Option<EmployerRole>>;
+
     /// Retrieves the count of invitations for a user.
     async fn get_user_invitations_count(&self, user_id: &Uuid) -> Result<usize>;
 
+    /// Lists the applications of a job seeker to the employer's jobs, including
+    /// the notes and ratings left by the team.
+    async fn list_applications_feedback(
+        &self,
+        employer_id: &Uuid,
+        profile_id: &Uuid,
+    ) -> Result<Vec<ApplicationFeedback>>;
+
     /// Lists all jobs for an employer.
     async fn list_employer_jobs(&self, employer_id: &Uuid) -> Result<Vec<JobSummary>>;
 
@@ -87,7 +131,11 @@ pub(crate) trait DBDashBoardEmployer {
     async fn list_user_invitations(&self, user_id: &Uuid) -> Result<Vec<TeamInvitation>>;
 
     /// Publishes a job, setting it to pending approval.
-    async fn publish_job(&self, job_id: &Uuid) -> Result<()>;
+    async fn publish_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()>;
+
+    /// Extends the closing date of a published job, returning the new closing
+    /// date if the job was renewed.
+    async fn renew_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<Option<NaiveDate>>;
 
     /// Searches applications for an employer with filters.
     async fn search_applications(
@@ -96,11 +144,41 @@ pub(crate) trait DBDashBoardEmployer {
         filters: &applications::Filters,
     ) -> Result<ApplicationsSearchOutput>;
 
+    /// Updates the rating of an application received by the employer.
+    async fn update_application_rating(
+        &self,
+        employer_id: &Uuid,
+        profile_id: &Uuid,
+        rating: &ApplicationRatingInput,
+    ) -> Result<()>;
+
+    /// Updates the status of an application, recording the change in its
+    /// history. Returns false if the application was not received by the
+    /// employer.
+    async fn update_application_status(
+        &self,
+        employer_id: &Uuid,
+        application_id: &Uuid,
+        status: &ApplicationStatus,
+        user_id: &Uuid,
+    ) -> Result<bool>;
+
     /// Updates an employer's details.
-    async fn update_employer(&self, employer_id: &Uuid, employer: &Employer) -> Result<()>;
+    async fn update_employer(&self, employer_id: &Uuid, employer: &Employer, actor_id: &Uuid) -> Result<()>;
+
+    /// Updates a job's details, keeping a revision with the previous version.
+    async fn update_job(&self, job_id: &Uuid, job: &Job, actor_id: &Uuid) -> Result<()>;
 
-    /// Updates a job's details.
-    async fn update_job(&self, job_id: &Uuid, job: &Job) -> Result<()>;
+    /// Updates the role of a team member. There must be at least one approved
+    /// owner left on the team. Returns false if the role could not be updated
+    /// because of this.
+    async fn update_team_member_role(
+        &self,
+        employer_id: &Uuid,
+        user_id: &Uuid,
+        role: &EmployerRole,
+        actor_id: &Uuid,
+    ) -> Result<bool>;
 }
 
 #[async_trait]
@@ -109,15 +187,76 @@ impl DBDashBoardEmployer for PgDB {
     async fn accept_team_member_invitation(&self, employer_id: &Uuid, user_id: &Uuid) -> Result<()> {
         trace!("db: accept team member invitation");
 
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Accept invitation
+        let n = tx
+            .execute(
+                "
+                update employer_team
+                set approved = true
+                where employer_id = $1::uuid
+                and user_id = $2::uuid;
+                ",
+                &[&employer_id, &user_id],
+            )
+            .await?;
+
+        // Record action in the audit log
+        if n == 1 {
+            let entry = NewAuditLogEntry {
+                action: AuditAction::TeamMemberJoined,
+                actor_id: *user_id,
+                details: None,
+                employer_id: Some(*employer_id),
+                target_id: Some(*user_id),
+            };
+            add_audit_log_entry(&tx, &entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, note), err)]
+    async fn add_application_note(
+        &self,
+        employer_id: &Uuid,
+        profile_id: &Uuid,
+        user_id: &Uuid,
+        note: &NewApplicationNote,
+    ) -> Result<()> {
+        trace!("db: add application note");
+
         let db = self.pool.get().await?;
         db.execute(
             "
-            update employer_team
-            set approved = true
-            where employer_id = $1::uuid
-            and user_id = $2::uuid;
+            insert into application_note (
+                application_id,
+                content,
+                user_id
+            )
+            select
+                a.application_id,
+                $4::text,
+                $3::uuid
+            from application a
+            join job j on a.job_id = j.job_id
+            where a.application_id = $5::uuid
+            and a.job_seeker_profile_id = $2::uuid
+            and j.employer_id = $1::uuid;
             ",
-            &[&employer_id, &user_id],
+            &[
+                &employer_id,
+                &profile_id,
+                &user_id,
+                &note.content,
+                &note.application_id,
+            ],
         )
         .await?;
 
@@ -172,17 +311,29 @@ impl DBDashBoardEmployer for PgDB {
             insert into employer_team (
                 employer_id,
                 user_id,
-                approved
+                approved,
+                role
             ) values (
                 $1::uuid,
                 $2::uuid,
-                true
+                true,
+                'owner'
             );
             ",
             &[&employer_id, &user_id],
         )
         .await?;
 
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::EmployerAdded,
+            actor_id: *user_id,
+            details: None,
+            employer_id: Some(employer_id),
+            target_id: Some(employer_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
         // Commit transaction
         tx.commit().await?;
 
@@ -190,7 +341,7 @@ impl DBDashBoardEmployer for PgDB {
     }
 
     #[instrument(skip(self, job), err)]
-    async fn add_job(&self, employer_id: &Uuid, job: &Job) -> Result<()> {
+    async fn add_job(&self, employer_id: &Uuid, job: &Job, actor_id: &Uuid) -> Result<()> {
         trace!("db: add job");
 
         // Begin transaction
@@ -198,124 +349,25 @@ impl DBDashBoardEmployer for PgDB {
         let tx = db.transaction().await?;
 
         // Insert job
-        let row = tx
-            .query_one(
-                "
-                insert into job (
-                    employer_id,
-                    kind,
-                    status,
-                    location_id,
-                    workplace,
-                    title,
-                    description,
-                    apply_instructions,
-                    apply_url,
-                    benefits,
-                    open_source,
-                    qualifications,
-                    responsibilities,
-                    salary,
-                    salary_usd_year,
-                    salary_currency,
-                    salary_min,
-                    salary_min_usd_year,
-                    salary_max,
-                    salary_max_usd_year,
-                    salary_period,
-                    seniority,
-                    skills,
-                    tz_end,
-                    tz_start,
-                    upstream_commitment
-                )
-                select
-                    $1::uuid,
-                    $2::text,
-                    $3::text,
-                    $4::uuid,
-                    $5::text,
-                    $6::text,
-                    $7::text,
-                    $8::text,
-                    $9::text,
-                    $10::text[],
-                    $11::int,
-                    $12::text,
-                    $13::text,
-                    $14::bigint,
-                    $15::bigint,
-                    $16::text,
-                    $17::bigint,
-                    $18::bigint,
-                    $19::bigint,
-                    $20::bigint,
-                    $21::text,
-                    $22::text,
-                    $23::text[],
-                    $24::text,
-                    $25::text,
-                    $26::int
-                returning job_id;
-                ",
-                &[
-                    &employer_id,
-                    &job.kind.to_string(),
-                    &job.status.to_string(),
-                    &job.location.as_ref().map(|l| l.location_id),
-                    &job.workplace.to_string(),
-                    &job.title,
-                    &job.description,
-                    &job.apply_instructions,
-                    &job.apply_url,
-                    &job.benefits,
-                    &job.open_source,
-                    &job.qualifications,
-                    &job.responsibilities,
-                    &job.salary,
-                    &job.salary_usd_year,
-                    &job.salary_currency,
-                    &job.salary_min,
-                    &job.salary_min_usd_year,
-                    &job.salary_max,
-                    &job.salary_max_usd_year,
-                    &job.salary_period,
-                    &job.seniority.as_ref().map(ToString::to_string),
-                    &job.skills,
-                    &job.tz_end,
-                    &job.tz_start,
-                    &job.upstream_commitment,
-                ],
-            )
-            .await?;
-        let job_id: Uuid = row.get("job_id");
+        insert_job(&tx, employer_id, job, actor_id).await?;
 
-        // Insert job projects
-        if let Some(projects) = &job.projects {
-            for project in projects {
-                tx.execute(
-                    "
-                    insert into job_project (job_id, project_id)
-                    values ($1::uuid, $2::uuid);
-                    ",
-                    &[&job_id, &project.project_id],
-                )
-                .await?;
-            }
-        }
+        // Commit transaction
+        tx.commit().await?;
 
-        // Insert job certifications
-        if let Some(certifications) = &job.certifications {
-            for certification in certifications {
-                tx.execute(
-                    "
-                    insert into job_certification (job_id, certification_id)
-                    values ($1::uuid, $2::uuid);
-                    ",
-                    &[&job_id, &certification.certification_id],
-                )
-                .await?;
-            }
+        Ok(())
+    }
+
+    #[instrument(skip(self, jobs), err)]
+    async fn add_jobs(&self, employer_id: &Uuid, jobs: &[Job], actor_id: &Uuid) -> Result<()> {
+        trace!("db: add jobs");
+
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Insert jobs
+        for job in jobs {
+            insert_job(&tx, employer_id, job, actor_id).await?;
         }
 
         // Commit transaction
@@ -325,107 +377,200 @@ impl DBDashBoardEmployer for PgDB {
     }
 
     #[instrument(skip(self, email), err)]
-    async fn add_team_member(&self, employer_id: &Uuid, email: &str) -> Result<Option<Uuid>> {
+    async fn add_team_member(
+        &self,
+        employer_id: &Uuid,
+        email: &str,
+        role: &EmployerRole,
+        actor_id: &Uuid,
+    ) -> Result<Option<Uuid>> {
         trace!("db: add team member");
 
-        let db = self.pool.get().await?;
-        let user_id = db
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Add team member
+        let user_id: Option<Uuid> = tx
             .query_opt(
                 r#"
                 insert into employer_team (
                     employer_id,
                     user_id,
-                    approved
+                    approved,
+                    role
                 )
                 select
                     $1::uuid,
                     user_id,
-                    false
+                    false,
+                    $3::text
                 from "user"
                 where email = $2::text
                 on conflict do nothing
                 returning user_id;
                 "#,
-                &[&employer_id, &email],
+                &[&employer_id, &email, &role.to_string()],
             )
             .await?
             .map(|row| row.get("user_id"));
 
+        // Record action in the audit log
+        if let Some(user_id) = user_id {
+            let entry = NewAuditLogEntry {
+                action: AuditAction::TeamMemberAdded,
+                actor_id: *actor_id,
+                details: Some(AuditLogDetails {
+                    role: Some(*role),
+                    ..Default::default()
+                }),
+                employer_id: Some(*employer_id),
+                target_id: Some(user_id),
+            };
+            add_audit_log_entry(&tx, &entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit().await?;
+
         Ok(user_id)
     }
 
     #[instrument(skip(self), err)]
-    async fn archive_job(&self, job_id: &Uuid) -> Result<()> {
+    async fn archive_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()> {
         trace!("db: archive job");
 
-        let db = self.pool.get().await?;
-        db.execute(
-            "
-            update job
-            set
-                status = 'archived',
-                archived_at = current_timestamp,
-                updated_at = current_timestamp
-            where job_id = $1::uuid
-            and (status = 'pending-approval' or status = 'published');
-            ",
-            &[&job_id],
-        )
-        .await?;
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Archive job
+        let employer_id: Option<Uuid> = tx
+            .query_opt(
+                "
+                update job
+                set
+                    status = 'archived',
+                    archived_at = current_timestamp,
+                    updated_at = current_timestamp
+                where job_id = $1::uuid
+                and (status = 'pending-approval' or status = 'published' or status = 'scheduled')
+                returning employer_id;
+                ",
+                &[&job_id],
+            )
+            .await?
+            .map(|row| row.get("employer_id"));
+
+        // Record action in the audit log
+        if employer_id.is_some() {
+            let entry = NewAuditLogEntry {
+                action: AuditAction::JobArchived,
+                actor_id: *actor_id,
+                details: None,
+                employer_id,
+                target_id: Some(*job_id),
+            };
+            add_audit_log_entry(&tx, &entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit().await?;
 
         Ok(())
     }
 
     #[instrument(skip(self), err)]
-    async fn delete_job(&self, job_id: &Uuid) -> Result<()> {
+    async fn delete_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()> {
         trace!("db: delete job");
 
-        let db = self.pool.get().await?;
-        db.execute(
-            "
-            update job
-            set
-                status = 'deleted',
-                deleted_at = current_timestamp
-            where job_id = $1::uuid;
-            ",
-            &[&job_id],
-        )
-        .await?;
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Delete job
+        let employer_id: Option<Uuid> = tx
+            .query_opt(
+                "
+                update job
+                set
+                    status = 'deleted',
+                    deleted_at = current_timestamp
+                where job_id = $1::uuid
+                and status <> 'deleted'
+                returning employer_id;
+                ",
+                &[&job_id],
+            )
+            .await?
+            .map(|row| row.get("employer_id"));
+
+        // Record action in the audit log (only if the job was deleted now)
+        if employer_id.is_some() {
+            let entry = NewAuditLogEntry {
+                action: AuditAction::JobDeleted,
+                actor_id: *actor_id,
+                details: None,
+                employer_id,
+                target_id: Some(*job_id),
+            };
+            add_audit_log_entry(&tx, &entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit().await?;
 
         Ok(())
     }
 
     /// Delete team member.
     ///
-    /// There must be at least one approved team member left on the team.
+    /// There must be at least one approved owner left on the team.
     ///
-    /// - If the team member is approved, we can only delete it if there is at
-    ///   least one other approved team member left on the team.
+    /// - If the team member is an approved owner, we can only delete it if
+    ///   there is at least one other approved owner left on the team.
     ///
-    /// - If the team member is not approved, we can delete it directly.
+    /// - Otherwise, we can delete it directly.
     ///
     #[instrument(skip(self), err)]
-    async fn delete_team_member(&self, employer_id: &Uuid, user_id: &Uuid) -> Result<()> {
+    async fn delete_team_member(&self, employer_id: &Uuid, user_id: &Uuid, actor_id: &Uuid) -> Result<bool> {
         trace!("db: delete team member");
 
-        let db = self.pool.get().await?;
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
 
-        let approved: bool = db
+        // Lock the employer's owners, so that concurrent changes to the team
+        // can't leave it without any
+        tx.execute(
+            "
+            select user_id
+            from employer_team
+            where employer_id = $1::uuid
+            and role = 'owner'
+            order by user_id
+            for update;
+            ",
+            &[&employer_id],
+        )
+        .await?;
+
+        let row = tx
             .query_one(
                 "
-                select approved
+                select approved, role
                 from employer_team
                 where employer_id = $1::uuid
                 and user_id = $2::uuid;
                 ",
                 &[&employer_id, &user_id],
             )
-            .await?
-            .get("approved");
+            .await?;
+        let approved: bool = row.get("approved");
+        let role: EmployerRole = row.get::<_, String>("role").parse().expect("valid employer role");
 
-        if approved {
-            let n = db
+        if approved && role == EmployerRole::Owner {
+            let n = tx
                 .execute(
                     "
                     delete from employer_team
@@ -437,16 +582,17 @@ impl DBDashBoardEmployer for PgDB {
                             from employer_team
                             where employer_id = $1::uuid
                             and approved = true
+                            and role = 'owner'
                         ) > 1;
                     ",
                     &[&employer_id, &user_id],
                 )
                 .await?;
             if n == 0 {
-                bail!("cannot delete last approved team member");
+                return Ok(false);
             }
         } else {
-            db.execute(
+            tx.execute(
                 "
                 delete from employer_team
                 where employer_id = $1::uuid
@@ -457,7 +603,141 @@ impl DBDashBoardEmployer for PgDB {
             .await?;
         }
 
-        Ok(())
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::TeamMemberRemoved,
+            actor_id: *actor_id,
+            details: None,
+            employer_id: Some(*employer_id),
+            target_id: Some(*user_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
+        // Commit transaction
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn duplicate_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<Option<Uuid>> {
+        trace!("db: duplicate job");
+
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Insert copy of the job as a draft
+        let Some(row) = tx
+            .query_opt(
+                "
+                insert into job (
+                    employer_id,
+                    kind,
+                    status,
+                    location_id,
+                    workplace,
+                    title,
+                    description,
+                    apply_instructions,
+                    apply_url,
+                    benefits,
+                    open_source,
+                    qualifications,
+                    responsibilities,
+                    salary,
+                    salary_usd_year,
+                    salary_currency,
+                    salary_min,
+                    salary_min_usd_year,
+                    salary_max,
+                    salary_max_usd_year,
+                    salary_period,
+                    seniority,
+                    skills,
+                    tz_end,
+                    tz_start,
+                    upstream_commitment,
+                    screening_questions
+                )
+                select
+                    employer_id,
+                    kind,
+                    'draft',
+                    location_id,
+                    workplace,
+                    title,
+                    description,
+                    apply_instructions,
+                    apply_url,
+                    benefits,
+                    open_source,
+                    qualifications,
+                    responsibilities,
+                    salary,
+                    salary_usd_year,
+                    salary_currency,
+                    salary_min,
+                    salary_min_usd_year,
+                    salary_max,
+                    salary_max_usd_year,
+                    salary_period,
+                    seniority,
+                    skills,
+                    tz_end,
+                    tz_start,
+                    upstream_commitment,
+                    screening_questions
+                from job
+                where job_id = $1::uuid
+                and status <> 'deleted'
+                returning job_id, employer_id;
+                ",
+                &[&job_id],
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+        let new_job_id: Uuid = row.get("job_id");
+        let employer_id: Uuid = row.get("employer_id");
+
+        // Copy job projects and certifications
+        tx.execute(
+            "
+            insert into job_project (job_id, project_id)
+            select $2::uuid, project_id
+            from job_project
+            where job_id = $1::uuid;
+            ",
+            &[&job_id, &new_job_id],
+        )
+        .await?;
+        tx.execute(
+            "
+            insert into job_certification (job_id, certification_id)
+            select $2::uuid, certification_id
+            from job_certification
+            where job_id = $1::uuid;
+            ",
+            &[&job_id, &new_job_id],
+        )
+        .await?;
+
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::JobAdded,
+            actor_id: *actor_id,
+            details: None,
+            employer_id: Some(employer_id),
+            target_id: Some(new_job_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
+        // Commit transaction
+        tx.commit().await?;
+
+        Ok(Some(new_job_id))
     }
 
     #[instrument(skip(self), err)]
@@ -562,131 +842,14 @@ impl DBDashBoardEmployer for PgDB {
         };
 
         Ok(employer)
-    }
-
-    #[instrument(skip(self), err)]
-    async fn get_job_dashboard(&self, job_id: &Uuid) -> Result<Job> {
-        trace!("db: get job dashboard");
-
-        let db = self.pool.get().await?;
-        let row = db
-            .query_one(
-                "
-                select
-                    j.description,
-                    j.status,
-                    j.title,
-                    j.kind,
-                    j.workplace,
-                    j.apply_instructions,
-                    j.apply_url,
-                    j.benefits,
-                    j.job_id,
-                    j.location_id,
-                    j.open_source,
-                    j.published_at,
-                    j.qualifications,
-                    j.responsibilities,
-                    j.review_notes,
-                    j.salary,
-                    j.salary_currency,
-                    j.salary_min,
-                    j.salary_max,
-                    j.salary_period,
-                    j.seniority,
-                    j.skills,
-                    j.tz_end,
-                    j.tz_start,
-                    j.updated_at,
-                    j.upstream_commitment,
-                    (
-                        select nullif(jsonb_strip_nulls(jsonb_build_object(
-                            'location_id', l.location_id,
-                            'city', l.city,
-                            'country', l.country,
-                            'state', l.state
-                        )), '{}'::jsonb)
-                    ) as location,
-                    (
-                        select json_agg(json_build_object(
-                            'project_id', p.project_id,
-                            'foundation', p.foundation,
-                            'logo_url', p.logo_url,
-                            'maturity', p.maturity,
-                            'name', p.name
-                        ))
-                        from project p
-                        left join job_project jp using (project_id)
-                        left join job j using (job_id)
-                        where j.job_id = $1::uuid
-                    ) as projects,
-                    (
-                        select json_agg(json_build_object(
-                            'certification_id', c.certification_id,
-                            'name', c.name,
-                            'provider', c.provider,
-                            'short_name', c.short_name,
-                            'description', c.description,
-                            'url', c.url,
-                            'logo_url', c.logo_url
-                        ))
-                        from certification c
-                        left join job_certification jc using (certification_id)
-                        left join job j using (job_id)
-                        where j.job_id = $1::uuid
-                    ) as certifications
-                from job j
-                left join location l using (location_id)
-                where job_id = $1::uuid
-                and status <> 'deleted';
-                ",
-                &[&job_id],
-            )
-            .await?;
-
-        let job = Job {
-            description: row.get("description"),
-            status: row.get::<_, String>("status").parse().expect("valid job status"),
-            title: row.get("title"),
-            kind: row.get::<_, String>("kind").parse().expect("valid job kind"),
-            workplace: row.get::<_, String>("workplace").parse().expect("valid workplace"),
-            apply_instructions: row.get("apply_instructions"),
-            apply_url: row.get("apply_url"),
-            benefits: row.get("benefits"),
-            certifications: row
-                .get::<_, Option<serde_json::Value>>("certifications")
-                .map(|v| serde_json::from_value(v).expect("certifications should be valid json")),
-            job_id: row.get("job_id"),
-            location: row
-                .get::<_, Option<serde_json::Value>>("location")
-                .map(|v| serde_json::from_value(v).expect("location should be valid json")),
-            open_source: row.get("open_source"),
-            projects: row
-                .get::<_, Option<serde_json::Value>>("projects")
-                .map(|v| serde_json::from_value(v).expect("projects should be valid json")),
-            published_at: row.get("published_at"),
-            qualifications: row.get("qualifications"),
-            responsibilities: row.get("responsibilities"),
-            review_notes: row.get("review_notes"),
-            salary: row.get("salary"),
-            salary_usd_year: None,
-            salary_currency: row.get("salary_currency"),
-            salary_min: row.get("salary_min"),
-            salary_min_usd_year: None,
-            salary_max: row.get("salary_max"),
-            salary_max_usd_year: None,
-            salary_period: row.get("salary_period"),
-            seniority: row
-                .get::<_, Option<String>>("seniority")
-                .map(|s| s.parse().expect("valid seniority")),
-            skills: row.get("skills"),
-            tz_end: row.get("tz_end"),
-            tz_start: row.get("tz_start"),
-            updated_at: row.get("updated_at"),
-            upstream_commitment: row.get("upstream_commitment"),
-        };
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_job_dashboard(&self, job_id: &Uuid) -> Result<Job> {
+        trace!("db: get job dashboard");
 
-        Ok(job)
+        let db = self.pool.get().await?;
+        select_job_dashboard(&db, job_id).await
     }
 
     #[instrument(skip(self), err)]
@@ -724,6 +887,27 @@ impl DBDashBoardEmployer for PgDB {
         Ok(stats)
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_team_member_role(&self, employer_id: &Uuid, user_id: &Uuid) -> Result<Option<EmployerRole>> {
+        trace!("db: get team member role");
+
+        let db = self.pool.get().await?;
+        let role = db
+            .query_opt(
+                "
+                select role
+                from employer_team
+                where employer_id = $1::uuid
+                and user_id = $2::uuid;
+                ",
+                &[&employer_id, &user_id],
+            )
+            .await?
+            .map(|row| row.get::<_, String>("role").parse().expect("valid employer role"));
+
+        Ok(role)
+    }
+
     #[instrument(skip(self), err)]
     async fn get_user_invitations_count(&self, user_id: &Uuid) -> Result<usize> {
         trace!("db: get user invitations count");
@@ -746,6 +930,69 @@ impl DBDashBoardEmployer for PgDB {
         Ok(count)
     }
 
+    #[instrument(skip(self), err)]
+    async fn list_applications_feedback(
+        &self,
+        employer_id: &Uuid,
+        profile_id: &Uuid,
+    ) -> Result<Vec<ApplicationFeedback>> {
+        trace!("db: list applications feedback");
+
+        let db = self.pool.get().await?;
+        let applications = db
+            .query(
+                r#"
+                select
+                    a.application_id,
+                    a.created_at as applied_at,
+                    a.cover_letter,
+                    a.rating,
+                    a.screening_answers,
+                    a.status,
+                    j.title as job_title,
+                    (
+                        select json_agg(json_build_object(
+                            'content', n.content,
+                            'created_at', n.created_at,
+                            'user_name', u.name
+                        ) order by n.created_at desc)
+                        from application_note n
+                        left join "user" u on n.user_id = u.user_id
+                        where n.application_id = a.application_id
+                    ) as notes
+                from application a
+                join job j on a.job_id = j.job_id
+                where a.job_seeker_profile_id = $2::uuid
+                and j.employer_id = $1::uuid
+                and j.status <> 'deleted'
+                order by a.created_at desc;
+                "#,
+                &[&employer_id, &profile_id],
+            )
+            .await?
+            .iter()
+            .map(|row| ApplicationFeedback {
+                application_id: row.get("application_id"),
+                applied_at: row.get("applied_at"),
+                job_title: row.get("job_title"),
+                cover_letter: row.get("cover_letter"),
+                notes: row
+                    .get::<_, Option<serde_json::Value>>("notes")
+                    .map(|v| serde_json::from_value(v).expect("notes should be valid json")),
+                rating: row.get::<_, Option<i16>>("rating").and_then(|r| u8::try_from(r).ok()),
+                screening_answers: row
+                    .get::<_, Option<serde_json::Value>>("screening_answers")
+                    .map(|v| serde_json::from_value(v).expect("screening answers should be valid json")),
+                status: row
+                    .get::<_, String>("status")
+                    .parse()
+                    .expect("valid application status"),
+            })
+            .collect();
+
+        Ok(applications)
+    }
+
     #[instrument(skip(self), err)]
     async fn list_employer_jobs(&self, employer_id: &Uuid) -> Result<Vec<JobSummary>> {
         trace!("db: list employer jobs");
@@ -761,6 +1008,8 @@ impl DBDashBoardEmployer for PgDB {
                     j.status,
                     j.workplace,
                     j.archived_at,
+                    j.closing_date,
+                    j.publish_date,
                     j.published_at,
                     j.review_notes,
                     l.city,
@@ -782,8 +1031,10 @@ impl DBDashBoardEmployer for PgDB {
                 status: row.get::<_, String>("status").parse().expect("valid job status"),
                 workplace: row.get::<_, String>("workplace").parse().expect("valid workplace"),
                 city: row.get("city"),
+                closing_date: row.get("closing_date"),
                 country: row.get("country"),
                 archived_at: row.get("archived_at"),
+                publish_date: row.get("publish_date"),
                 published_at: row.get("published_at"),
                 review_notes: row.get("review_notes"),
             })
@@ -885,6 +1136,7 @@ impl DBDashBoardEmployer for PgDB {
                 r#"
                 select
                     et.approved,
+                    et.role,
                     u.email,
                     u.name,
                     u.user_id,
@@ -902,6 +1154,7 @@ impl DBDashBoardEmployer for PgDB {
                 approved: row.get("approved"),
                 email: row.get("email"),
                 name: row.get("name"),
+                role: row.get::<_, String>("role").parse().expect("valid employer role"),
                 user_id: row.get("user_id"),
                 username: row.get("username"),
             })
@@ -943,13 +1196,15 @@ impl DBDashBoardEmployer for PgDB {
     }
 
     #[instrument(skip(self), err)]
-    async fn publish_job(&self, job_id: &Uuid) -> Result<()> {
+    async fn publish_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()> {
         trace!("db: publish job");
 
-        let db = self.pool.get().await?;
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
 
         // Get current job salary details to refresh the usd/year versions
-        let row = db
+        let row = tx
             .query_one(
                 "
                 select
@@ -971,31 +1226,97 @@ impl DBDashBoardEmployer for PgDB {
         let period: Option<String> = row.get("salary_period");
 
         // Update job
-        db.execute(
-            "
-            update job
-            set
-                status = 'pending-approval',
-                updated_at = current_timestamp,
-                archived_at = null,
-                salary_usd_year = $2::bigint,
-                salary_min_usd_year = $3::bigint,
-                salary_max_usd_year = $4::bigint
-            where job_id = $1::uuid
-            and (status = 'archived' or status = 'draft' or status = 'rejected');
-            ",
-            &[
-                &job_id,
-                &normalize_salary(salary, currency.as_ref(), period.as_ref()).await,
-                &normalize_salary(salary_min.or(salary), currency.as_ref(), period.as_ref()).await,
-                &normalize_salary(salary_max.or(salary), currency.as_ref(), period.as_ref()).await,
-            ],
-        )
-        .await?;
+        let employer_id: Option<Uuid> = tx
+            .query_opt(
+                "
+                update job
+                set
+                    status = 'pending-approval',
+                    updated_at = current_timestamp,
+                    archived_at = null,
+                    salary_usd_year = $2::bigint,
+                    salary_min_usd_year = $3::bigint,
+                    salary_max_usd_year = $4::bigint
+                where job_id = $1::uuid
+                and (status = 'archived' or status = 'draft' or status = 'rejected')
+                returning employer_id;
+                ",
+                &[
+                    &job_id,
+                    &normalize_salary(salary, currency.as_ref(), period.as_ref()).await,
+                    &normalize_salary(salary_min.or(salary), currency.as_ref(), period.as_ref()).await,
+                    &normalize_salary(salary_max.or(salary), currency.as_ref(), period.as_ref()).await,
+                ],
+            )
+            .await?
+            .map(|row| row.get("employer_id"));
+
+        // Record action in the audit log
+        if employer_id.is_some() {
+            let entry = NewAuditLogEntry {
+                action: AuditAction::JobPublished,
+                actor_id: *actor_id,
+                details: None,
+                employer_id,
+                target_id: Some(*job_id),
+            };
+            add_audit_log_entry(&tx, &entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit().await?;
 
         Ok(())
     }
 
+    #[instrument(skip(self), err)]
+    async fn renew_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<Option<NaiveDate>> {
+        trace!("db: renew job");
+
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Extend the job's closing date (jobs without one expire 30 days after
+        // being published)
+        let Some(row) = tx
+            .query_opt(
+                "
+                update job
+                set
+                    closing_date = greatest(
+                        coalesce(closing_date, (published_at + '30 days'::interval)::date),
+                        current_date
+                    ) + 30,
+                    expiry_reminder_sent_at = null,
+                    updated_at = current_timestamp
+                where job_id = $1::uuid
+                and status = 'published'
+                returning employer_id, closing_date;
+                ",
+                &[&job_id],
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::JobRenewed,
+            actor_id: *actor_id,
+            details: None,
+            employer_id: Some(row.get("employer_id")),
+            target_id: Some(*job_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
+        // Commit transaction
+        tx.commit().await?;
+
+        Ok(Some(row.get("closing_date")))
+    }
+
     #[instrument(skip(self))]
     async fn search_applications(
         &self,
@@ -1004,12 +1325,19 @@ impl DBDashBoardEmployer for PgDB {
     ) -> Result<ApplicationsSearchOutput> {
         trace!("db: search applications");
 
+        // Prepare filters, including the keyset position (it's not serialized
+        // with the filters, as users can't set it)
+        let mut filters_json = serde_json::to_value(filters)?;
+        if let Some(after) = &filters.after {
+            filters_json["after"] = serde_json::to_value(after)?;
+        }
+
         // Query database
         let db = self.pool.get().await?;
         let row = db
             .query_one(
                 "select applications::text, total from search_applications($1::uuid, $2::jsonb)",
-                &[&employer_id, &Json(filters)],
+                &[&employer_id, &Json(filters_json)],
             )
             .await?;
 
@@ -1023,12 +1351,140 @@ impl DBDashBoardEmployer for PgDB {
         Ok(output)
     }
 
-    #[instrument(skip(self, employer), err)]
-    async fn update_employer(&self, employer_id: &Uuid, employer: &Employer) -> Result<()> {
-        trace!("db: update employer");
+    #[instrument(skip(self), err)]
+    async fn update_application_rating(
+        &self,
+        employer_id: &Uuid,
+        profile_id: &Uuid,
+        rating: &ApplicationRatingInput,
+    ) -> Result<()> {
+        trace!("db: update application rating");
 
         let db = self.pool.get().await?;
         db.execute(
+            "
+            update application a
+            set
+                rating = $4::smallint,
+                updated_at = current_timestamp
+            from job j
+            where a.job_id = j.job_id
+            and a.application_id = $3::uuid
+            and a.job_seeker_profile_id = $2::uuid
+            and j.employer_id = $1::uuid;
+            ",
+            &[
+                &employer_id,
+                &profile_id,
+                &rating.application_id,
+                &rating.rating.map(i16::from),
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn update_application_status(
+        &self,
+        employer_id: &Uuid,
+        application_id: &Uuid,
+        status: &ApplicationStatus,
+        user_id: &Uuid,
+    ) -> Result<bool> {
+        trace!("db: update application status");
+
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Check the application belongs to one of the employer's jobs
+        let Some(row) = tx
+            .query_opt(
+                "
+                select a.status
+                from application a
+                join job j on a.job_id = j.job_id
+                where a.application_id = $2::uuid
+                and j.employer_id = $1::uuid
+                for update of a;
+                ",
+                &[&employer_id, &application_id],
+            )
+            .await?
+        else {
+            return Ok(false);
+        };
+        if row.get::<_, String>("status") == status.to_string() {
+            return Ok(true);
+        }
+
+        // Update application status
+        tx.execute(
+            "
+            update application
+            set
+                status = $2::text,
+                updated_at = current_timestamp
+            where application_id = $1::uuid;
+            ",
+            &[&application_id, &status.to_string()],
+        )
+        .await?;
+
+        // Record status change
+        tx.execute(
+            "
+            insert into application_status_change (
+                application_id,
+                status,
+                changed_by
+            ) values (
+                $1::uuid,
+                $2::text,
+                $3::uuid
+            );
+            ",
+            &[&application_id, &status.to_string(), &user_id],
+        )
+        .await?;
+
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::ApplicationStatusUpdated,
+            actor_id: *user_id,
+            details: Some(AuditLogDetails {
+                status: Some(*status),
+                ..Default::default()
+            }),
+            employer_id: Some(*employer_id),
+            target_id: Some(*application_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
+        // Commit transaction
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    #[instrument(skip(self, employer), err)]
+    async fn update_employer(&self, employer_id: &Uuid, employer: &Employer, actor_id: &Uuid) -> Result<()> {
+        trace!("db: update employer");
+
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Update employer, keeping snapshots to summarize the changes
+        let snapshot_query = "
+            select to_jsonb(e) - '{created_at,updated_at}'::text[] as snapshot
+            from employer e
+            where employer_id = $1::uuid;
+        ";
+        let before: serde_json::Value = tx.query_one(snapshot_query, &[&employer_id]).await?.get("snapshot");
+        tx.execute(
             "
             update employer
             set
@@ -1054,18 +1510,71 @@ impl DBDashBoardEmployer for PgDB {
             ],
         )
         .await?;
+        let after: serde_json::Value = tx.query_one(snapshot_query, &[&employer_id]).await?.get("snapshot");
+
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::EmployerUpdated,
+            actor_id: *actor_id,
+            details: Some(AuditLogDetails {
+                changed_fields: Some(changed_fields(&before, &after)),
+                ..Default::default()
+            }),
+            employer_id: Some(*employer_id),
+            target_id: Some(*employer_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
+        // Commit transaction
+        tx.commit().await?;
 
         Ok(())
     }
 
     #[instrument(skip(self, job), err)]
-    async fn update_job(&self, job_id: &Uuid, job: &Job) -> Result<()> {
+    async fn update_job(&self, job_id: &Uuid, job: &Job, actor_id: &Uuid) -> Result<()> {
         trace!("db: update job");
 
         // Begin transaction
         let mut db = self.pool.get().await?;
         let tx = db.transaction().await?;
 
+        // Lock the job and get its current version, to keep it as a revision
+        tx.execute(
+            "select 1 from job where job_id = $1::uuid for update;",
+            &[&job_id],
+        )
+        .await?;
+        let previous_job = select_job_dashboard(&tx, job_id).await?;
+
+        // Take a snapshot of the job before updating it to summarize the changes
+        let snapshot_query = "
+            select
+                j.employer_id,
+                (
+                    to_jsonb(j)
+                    - '{created_at,expiry_reminder_sent_at,salary_max_usd_year,salary_min_usd_year,salary_usd_year,tsdoc,updated_at}'::text[]
+                ) || jsonb_build_object(
+                    'certifications', array(
+                        select certification_id
+                        from job_certification
+                        where job_id = j.job_id
+                        order by certification_id
+                    ),
+                    'projects', array(
+                        select project_id
+                        from job_project
+                        where job_id = j.job_id
+                        order by project_id
+                    )
+                ) as snapshot
+            from job j
+            where job_id = $1::uuid;
+        ";
+        let row = tx.query_one(snapshot_query, &[&job_id]).await?;
+        let employer_id: Uuid = row.get("employer_id");
+        let before: serde_json::Value = row.get("snapshot");
+
         // Update job
         let rows_updated = tx
             .execute(
@@ -1097,6 +1606,13 @@ impl DBDashBoardEmployer for PgDB {
                     tz_end = $24::text,
                     tz_start = $25::text,
                     upstream_commitment = $26::int,
+                    screening_questions = $27::jsonb,
+                    publish_date = $28::date,
+                    closing_date = $29::date,
+                    expiry_reminder_sent_at = case
+                        when closing_date is distinct from $29::date then null
+                        else expiry_reminder_sent_at
+                    end,
                     updated_at = current_timestamp
                 where job_id = $1::uuid
                 and status <> 'deleted';
@@ -1128,11 +1644,24 @@ impl DBDashBoardEmployer for PgDB {
                     &job.tz_end,
                     &job.tz_start,
                     &job.upstream_commitment,
+                    &job.screening_questions.as_ref().map(Json),
+                    &job.publish_date,
+                    &job.closing_date,
                 ],
             )
             .await?;
 
         if rows_updated == 1 {
+            // Keep previous version of the job
+            tx.execute(
+                "
+                insert into job_revision (job_id, data, created_by)
+                values ($1::uuid, $2::jsonb, $3::uuid);
+                ",
+                &[&job_id, &Json(&previous_job), &actor_id],
+            )
+            .await?;
+
             // Update job projects
             tx.execute("delete from job_project where job_id = $1::uuid;", &[&job_id])
                 .await?;
@@ -1167,6 +1696,20 @@ impl DBDashBoardEmployer for PgDB {
                     .await?;
                 }
             }
+
+            // Record action in the audit log
+            let after: serde_json::Value = tx.query_one(snapshot_query, &[&job_id]).await?.get("snapshot");
+            let entry = NewAuditLogEntry {
+                action: AuditAction::JobUpdated,
+                actor_id: *actor_id,
+                details: Some(AuditLogDetails {
+                    changed_fields: Some(changed_fields(&before, &after)),
+                    ..Default::default()
+                }),
+                employer_id: Some(employer_id),
+                target_id: Some(*job_id),
+            };
+            add_audit_log_entry(&tx, &entry).await?;
         }
 
         // Commit transaction
@@ -1174,6 +1717,361 @@ impl DBDashBoardEmployer for PgDB {
 
         Ok(())
     }
+
+    #[instrument(skip(self), err)]
+    async fn update_team_member_role(
+        &self,
+        employer_id: &Uuid,
+        user_id: &Uuid,
+        role: &EmployerRole,
+        actor_id: &Uuid,
+    ) -> Result<bool> {
+        trace!("db: update team member role");
+
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Lock the employer's owners, so that concurrent changes to the team
+        // can't leave it without any
+        tx.execute(
+            "
+            select user_id
+            from employer_team
+            where employer_id = $1::uuid
+            and role = 'owner'
+            order by user_id
+            for update;
+            ",
+            &[&employer_id],
+        )
+        .await?;
+
+        // Update role
+        let n = tx
+            .execute(
+                "
+                update employer_team
+                set role = $3::text
+                where employer_id = $1::uuid
+                and user_id = $2::uuid
+                and (
+                    $3::text = 'owner'
+                    or role <> 'owner'
+                    or approved = false
+                    or (
+                        select count(*)
+                        from employer_team
+                        where employer_id = $1::uuid
+                        and approved = true
+                        and role = 'owner'
+                    ) > 1
+                );
+                ",
+                &[&employer_id, &user_id, &role.to_string()],
+            )
+            .await?;
+        if n == 0 {
+            return Ok(false);
+        }
+
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::TeamMemberRoleUpdated,
+            actor_id: *actor_id,
+            details: Some(AuditLogDetails {
+                role: Some(*role),
+                ..Default::default()
+            }),
+            employer_id: Some(*employer_id),
+            target_id: Some(*user_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
+        // Commit transaction
+        tx.commit().await?;
+
+        Ok(true)
+    }
+}
+
+/// Inserts a new job for an employer using the transaction provided, recording
+/// the action in the audit log.
+#[allow(clippy::too_many_lines)]
+async fn insert_job(tx: &Transaction<'_>, employer_id: &Uuid, job: &Job, actor_id: &Uuid) -> Result<()> {
+    // Insert job
+    let row = tx
+        .query_one(
+            "
+            insert into job (
+                employer_id,
+                kind,
+                status,
+                location_id,
+                workplace,
+                title,
+                description,
+                apply_instructions,
+                apply_url,
+                benefits,
+                open_source,
+                qualifications,
+                responsibilities,
+                salary,
+                salary_usd_year,
+                salary_currency,
+                salary_min,
+                salary_min_usd_year,
+                salary_max,
+                salary_max_usd_year,
+                salary_period,
+                seniority,
+                skills,
+                tz_end,
+                tz_start,
+                upstream_commitment,
+                screening_questions,
+                publish_date,
+                closing_date
+            )
+            select
+                $1::uuid,
+                $2::text,
+                $3::text,
+                $4::uuid,
+                $5::text,
+                $6::text,
+                $7::text,
+                $8::text,
+                $9::text,
+                $10::text[],
+                $11::int,
+                $12::text,
+                $13::text,
+                $14::bigint,
+                $15::bigint,
+                $16::text,
+                $17::bigint,
+                $18::bigint,
+                $19::bigint,
+                $20::bigint,
+                $21::text,
+                $22::text,
+                $23::text[],
+                $24::text,
+                $25::text,
+                $26::int,
+                $27::jsonb,
+                $28::date,
+                $29::date
+            returning job_id;
+            ",
+            &[
+                &employer_id,
+                &job.kind.to_string(),
+                &job.status.to_string(),
+                &job.location.as_ref().map(|l| l.location_id),
+                &job.workplace.to_string(),
+                &job.title,
+                &job.description,
+                &job.apply_instructions,
+                &job.apply_url,
+                &job.benefits,
+                &job.open_source,
+                &job.qualifications,
+                &job.responsibilities,
+                &job.salary,
+                &job.salary_usd_year,
+                &job.salary_currency,
+                &job.salary_min,
+                &job.salary_min_usd_year,
+                &job.salary_max,
+                &job.salary_max_usd_year,
+                &job.salary_period,
+                &job.seniority.as_ref().map(ToString::to_string),
+                &job.skills,
+                &job.tz_end,
+                &job.tz_start,
+                &job.upstream_commitment,
+                &job.screening_questions.as_ref().map(Json),
+                &job.publish_date,
+                &job.closing_date,
+            ],
+        )
+        .await?;
+    let job_id: Uuid = row.get("job_id");
+
+    // Insert job projects
+    if let Some(projects) = &job.projects {
+        for project in projects {
+            tx.execute(
+                "
+                insert into job_project (job_id, project_id)
+                values ($1::uuid, $2::uuid);
+                ",
+                &[&job_id, &project.project_id],
+            )
+            .await?;
+        }
+    }
+
+    // Insert job certifications
+    if let Some(certifications) = &job.certifications {
+        for certification in certifications {
+            tx.execute(
+                "
+                insert into job_certification (job_id, certification_id)
+                values ($1::uuid, $2::uuid);
+                ",
+                &[&job_id, &certification.certification_id],
+            )
+            .await?;
+        }
+    }
+
+    // Record action in the audit log
+    let entry = NewAuditLogEntry {
+        action: AuditAction::JobAdded,
+        actor_id: *actor_id,
+        details: None,
+        employer_id: Some(*employer_id),
+        target_id: Some(job_id),
+    };
+    add_audit_log_entry(tx, &entry).await?;
+
+    Ok(())
+}
+
+/// Retrieves a job's details for the dashboard using the client provided, so
+/// that it can be used within a transaction.
+#[allow(clippy::too_many_lines)]
+async fn select_job_dashboard(client: &impl GenericClient, job_id: &Uuid) -> Result<Job> {
+    let row = client
+        .query_one(
+            "
+            select
+                j.description,
+                j.status,
+                j.title,
+                j.kind,
+                j.workplace,
+                j.apply_instructions,
+                j.apply_url,
+                j.benefits,
+                j.closing_date,
+                j.job_id,
+                j.location_id,
+                j.open_source,
+                j.publish_date,
+                j.published_at,
+                j.qualifications,
+                j.responsibilities,
+                j.review_notes,
+                j.salary,
+                j.salary_currency,
+                j.salary_min,
+                j.salary_max,
+                j.salary_period,
+                j.screening_questions,
+                j.seniority,
+                j.skills,
+                j.tz_end,
+                j.tz_start,
+                j.updated_at,
+                j.upstream_commitment,
+                (
+                    select nullif(jsonb_strip_nulls(jsonb_build_object(
+                        'location_id', l.location_id,
+                        'city', l.city,
+                        'country', l.country,
+                        'state', l.state
+                    )), '{}'::jsonb)
+                ) as location,
+                (
+                    select json_agg(json_build_object(
+                        'project_id', p.project_id,
+                        'foundation', p.foundation,
+                        'logo_url', p.logo_url,
+                        'maturity', p.maturity,
+                        'name', p.name
+                    ))
+                    from project p
+                    left join job_project jp using (project_id)
+                    left join job j using (job_id)
+                    where j.job_id = $1::uuid
+                ) as projects,
+                (
+                    select json_agg(json_build_object(
+                        'certification_id', c.certification_id,
+                        'name', c.name,
+                        'provider', c.provider,
+                        'short_name', c.short_name,
+                        'description', c.description,
+                        'url', c.url,
+                        'logo_url', c.logo_url
+                    ))
+                    from certification c
+                    left join job_certification jc using (certification_id)
+                    left join job j using (job_id)
+                    where j.job_id = $1::uuid
+                ) as certifications
+            from job j
+            left join location l using (location_id)
+            where job_id = $1::uuid
+            and status <> 'deleted';
+            ",
+            &[&job_id],
+        )
+        .await?;
+
+    let job = Job {
+        description: row.get("description"),
+        status: row.get::<_, String>("status").parse().expect("valid job status"),
+        title: row.get("title"),
+        kind: row.get::<_, String>("kind").parse().expect("valid job kind"),
+        workplace: row.get::<_, String>("workplace").parse().expect("valid workplace"),
+        apply_instructions: row.get("apply_instructions"),
+        apply_url: row.get("apply_url"),
+        benefits: row.get("benefits"),
+        certifications: row
+            .get::<_, Option<serde_json::Value>>("certifications")
+            .map(|v| serde_json::from_value(v).expect("certifications should be valid json")),
+        closing_date: row.get("closing_date"),
+        job_id: row.get("job_id"),
+        location: row
+            .get::<_, Option<serde_json::Value>>("location")
+            .map(|v| serde_json::from_value(v).expect("location should be valid json")),
+        open_source: row.get("open_source"),
+        projects: row
+            .get::<_, Option<serde_json::Value>>("projects")
+            .map(|v| serde_json::from_value(v).expect("projects should be valid json")),
+        publish_date: row.get("publish_date"),
+        published_at: row.get("published_at"),
+        qualifications: row.get("qualifications"),
+        responsibilities: row.get("responsibilities"),
+        review_notes: row.get("review_notes"),
+        salary: row.get("salary"),
+        salary_usd_year: None,
+        salary_currency: row.get("salary_currency"),
+        salary_min: row.get("salary_min"),
+        salary_min_usd_year: None,
+        salary_max: row.get("salary_max"),
+        salary_max_usd_year: None,
+        salary_period: row.get("salary_period"),
+        screening_questions: row
+            .get::<_, Option<serde_json::Value>>("screening_questions")
+            .map(|v| serde_json::from_value(v).expect("screening questions should be valid json")),
+        seniority: row
+            .get::<_, Option<String>>("seniority")
+            .map(|s| s.parse().expect("valid seniority")),
+        skills: row.get("skills"),
+        tz_end: row.get("tz_end"),
+        tz_start: row.get("tz_start"),
+        updated_at: row.get("updated_at"),
+        upstream_commitment: row.get("upstream_commitment"),
+    };
+
+    Ok(job)
 }
 
 /// Applications search results.
@@ -7,27 +7,84 @@ use uuid::Uuid;
 
 use crate::{
     PgDB,
-    templates::dashboard::job_seeker::{applications::Application, profile::JobSeekerProfile},
+    templates::{
+        dashboard::job_seeker::{
+            applications::Application,
+            profile::JobSeekerProfile,
+            saved_searches::{NewSavedSearch, SavedSearch},
+        },
+        jobboard::jobs::Filters,
+    },
 };
 
 /// Trait for job seeker dashboard database operations.
 #[async_trait]
 pub(crate) trait DBDashBoardJobSeeker {
+    /// Adds a new saved search for the given user.
+    async fn add_saved_search(
+        &self,
+        user_id: &Uuid,
+        saved_search: &NewSavedSearch,
+        filters: &Filters,
+    ) -> Result<()>;
+
     /// Cancels a job application for the given user.
     async fn cancel_application(&self, application_id: &Uuid, user_id: &Uuid) -> Result<()>;
 
+    /// Deletes a saved search of the given user.
+    async fn delete_saved_search(&self, saved_search_id: &Uuid, user_id: &Uuid) -> Result<()>;
+
     /// Retrieves the job seeker profile for the given user.
     async fn get_job_seeker_profile(&self, user_id: &Uuid) -> Result<Option<JobSeekerProfile>>;
 
     /// Lists all job applications for the given user.
     async fn list_job_seeker_applications(&self, user_id: &Uuid) -> Result<Vec<Application>>;
 
+    /// Lists all saved searches for the given user.
+    async fn list_saved_searches(&self, user_id: &Uuid) -> Result<Vec<SavedSearch>>;
+
     /// Updates the job seeker profile for the given user.
     async fn update_job_seeker_profile(&self, user_id: &Uuid, profile: &JobSeekerProfile) -> Result<()>;
 }
 
 #[async_trait]
 impl DBDashBoardJobSeeker for PgDB {
+    #[instrument(skip(self), err)]
+    async fn add_saved_search(
+        &self,
+        user_id: &Uuid,
+        saved_search: &NewSavedSearch,
+        filters: &Filters,
+    ) -> Result<()> {
+        trace!("db: add saved search");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            insert into saved_search (
+                user_id,
+                name,
+                filters,
+                frequency
+            ) values (
+                $1::uuid,
+                $2::text,
+                $3::jsonb,
+                $4::text
+            );
+            ",
+            &[
+                &user_id,
+                &saved_search.name,
+                &serde_json::to_value(filters).expect("filters should be valid json"),
+                &saved_search.frequency.to_string(),
+            ],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self), err)]
     async fn cancel_application(&self, application_id: &Uuid, user_id: &Uuid) -> Result<()> {
         trace!("db: cancel application");
@@ -51,6 +108,24 @@ impl DBDashBoardJobSeeker for PgDB {
         Ok(())
     }
 
+    #[instrument(skip(self), err)]
+    async fn delete_saved_search(&self, saved_search_id: &Uuid, user_id: &Uuid) -> Result<()> {
+        trace!("db: delete saved search");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            delete from saved_search
+            where saved_search_id = $1::uuid
+            and user_id = $2::uuid;
+            ",
+            &[&saved_search_id, &user_id],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self), err)]
     async fn get_job_seeker_profile(&self, user_id: &Uuid) -> Result<Option<JobSeekerProfile>> {
         trace!("db: get job seeker profile");
@@ -77,6 +152,7 @@ impl DBDashBoardJobSeeker for PgDB {
                     p.phone,
                     p.photo_id,
                     p.projects,
+                    p.resume_id,
                     p.skills,
                     p.twitter_url,
                     p.website_url,
@@ -123,6 +199,7 @@ impl DBDashBoardJobSeeker for PgDB {
                 projects: row
                     .get::<_, Option<serde_json::Value>>("projects")
                     .map(|v| serde_json::from_value(v).expect("projects should be valid json")),
+                resume_id: row.get("resume_id"),
                 skills: row.get("skills"),
                 twitter_url: row.get("twitter_url"),
                 website_url: row.get("website_url"),
@@ -153,7 +230,8 @@ impl DBDashBoardJobSeeker for PgDB {
                         )), '{}'::jsonb)
                     ) as job_location,
                     j.status as job_status,
-                    j.workplace as job_workplace
+                    j.workplace as job_workplace,
+                    a.status
                 from application a
                 join job j on a.job_id = j.job_id
                 join job_seeker_profile p on a.job_seeker_profile_id = p.job_seeker_profile_id
@@ -178,18 +256,61 @@ impl DBDashBoardJobSeeker for PgDB {
                     .get::<_, String>("job_workplace")
                     .parse()
                     .expect("valid job workplace"),
+                status: row
+                    .get::<_, String>("status")
+                    .parse()
+                    .expect("valid application status"),
             })
             .collect();
 
         Ok(applications)
     }
 
+    #[instrument(skip(self), err)]
+    async fn list_saved_searches(&self, user_id: &Uuid) -> Result<Vec<SavedSearch>> {
+        trace!("db: list saved searches");
+
+        let db = self.pool.get().await?;
+        let saved_searches = db
+            .query(
+                "
+                select
+                    saved_search_id,
+                    created_at,
+                    filters,
+                    frequency,
+                    last_run_at,
+                    name
+                from saved_search
+                where user_id = $1::uuid
+                order by created_at desc;
+                ",
+                &[&user_id],
+            )
+            .await?
+            .iter()
+            .map(|row| SavedSearch {
+                created_at: row.get("created_at"),
+                filters: serde_json::from_value(row.get("filters")).expect("filters should be valid json"),
+                frequency: row.get::<_, String>("frequency").parse().expect("valid frequency"),
+                last_run_at: row.get("last_run_at"),
+                name: row.get("name"),
+                saved_search_id: row.get("saved_search_id"),
+            })
+            .collect();
+
+        Ok(saved_searches)
+    }
+
     #[instrument(skip(self), err)]
     async fn update_job_seeker_profile(&self, user_id: &Uuid, profile: &JobSeekerProfile) -> Result<()> {
         trace!("db: update job seeker profile");
 
-        let db = self.pool.get().await?;
-        db.execute(
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Update profile
+        tx.execute(
             "
             insert into job_seeker_profile (
                 user_id,
@@ -212,7 +333,8 @@ impl DBDashBoardJobSeeker for PgDB {
                 projects,
                 skills,
                 twitter_url,
-                website_url
+                website_url,
+                resume_id
             ) values (
                 $1::uuid,
                 $2::text,
@@ -234,7 +356,13 @@ impl DBDashBoardJobSeeker for PgDB {
                 nullif($18::jsonb, 'null'::jsonb),
                 $19::text[],
                 $20::text,
-                $21::text
+                $21::text,
+                (
+                    select resume_id
+                    from resume
+                    where resume_id = $22::uuid
+                    and created_by = $1::uuid
+                )
             )
             on conflict (user_id) do update set
                 email = excluded.email,
@@ -256,7 +384,8 @@ impl DBDashBoardJobSeeker for PgDB {
                 projects = excluded.projects,
                 skills = excluded.skills,
                 twitter_url = excluded.twitter_url,
-                website_url = excluded.website_url;
+                website_url = excluded.website_url,
+                resume_id = excluded.resume_id;
             ",
             &[
                 &user_id,
@@ -280,10 +409,26 @@ impl DBDashBoardJobSeeker for PgDB {
                 &profile.skills,
                 &profile.twitter_url,
                 &profile.website_url,
+                &profile.resume_id,
             ],
         )
         .await?;
 
+        // Delete the user's resumes no longer attached to the profile
+        tx.execute(
+            "
+            delete from resume r
+            where r.created_by = $1::uuid
+            and not exists (
+                select 1 from job_seeker_profile p where p.resume_id = r.resume_id
+            );
+            ",
+            &[&user_id],
+        )
+        .await?;
+
+        tx.commit().await?;
+
         Ok(())
     }
 }
@@ -1,12 +1,14 @@
 //! This module defines some database functionality for the dashboards.
 
 use async_trait::async_trait;
+use audit::DBDashBoardAudit;
 use employer::DBDashBoardEmployer;
 use job_seeker::DBDashBoardJobSeeker;
 use moderator::DBDashBoardModerator;
 
 use crate::PgDB;
 
+pub(crate) mod audit;
 pub(crate) mod employer;
 pub(crate) mod job_seeker;
 pub(crate) mod moderator;
@@ -14,7 +16,7 @@ pub(crate) mod moderator;
 /// Trait that defines database operations used in the dashboards.
 #[async_trait]
 pub(crate) trait DBDashBoard:
-    DBDashBoardEmployer + DBDashBoardJobSeeker + DBDashBoardModerator
+    DBDashBoardAudit + DBDashBoardEmployer + DBDashBoardJobSeeker + DBDashBoardModerator
 {
 }
 
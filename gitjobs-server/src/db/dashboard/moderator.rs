@@ -2,20 +2,38 @@
 
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
-use tracing::{instrument, trace};
+use chrono::{DateTime, NaiveDate, Utc};
+use tracing::{instrument, trace, warn};
 use uuid::Uuid;
 
 use crate::{
     PgDB,
-    templates::dashboard::{employer::jobs::JobStatus, moderator::jobs::JobSummary},
+    db::dashboard::audit::{NewAuditLogEntry, add_audit_log_entry},
+    templates::dashboard::{
+        audit::{AuditAction, AuditLogDetails},
+        employer::jobs::JobStatus,
+        moderator::jobs::{JobSummary, ReviewedJob},
+    },
 };
 
 /// Trait for moderator dashboard database operations.
 #[async_trait]
 pub(crate) trait DBDashBoardModerator {
-    /// Approves a job and updates its status and review metadata.
-    async fn approve_job(&self, job_id: &Uuid, reviewer: &Uuid) -> Result<Option<DateTime<Utc>>>;
+    /// Approves a job and updates its status and review metadata. Jobs with a
+    /// publish date in the future are scheduled instead of published.
+    async fn approve_job(&self, job_id: &Uuid, reviewer: &Uuid) -> Result<Option<ApprovedJob>>;
+
+    /// Retrieves the information needed to notify the employer's team about the
+    /// review of a job.
+    async fn get_job_review_notification_data(
+        &self,
+        job_id: &Uuid,
+    ) -> Result<Option<JobReviewNotificationData>>;
+
+    /// Retrieves the version of a job that was last reviewed by a moderator,
+    /// if it has been updated since then. Revisions that can't be read anymore
+    /// (e.g. stored before a change in the job fields) are ignored.
+    async fn get_last_reviewed_job(&self, job_id: &Uuid) -> Result<Option<ReviewedJob>>;
 
     /// Lists jobs for moderation filtered by the given status.
     async fn list_jobs_for_moderation(&self, status: JobStatus) -> Result<Vec<JobSummary>>;
@@ -27,32 +45,145 @@ pub(crate) trait DBDashBoardModerator {
 #[async_trait]
 impl DBDashBoardModerator for PgDB {
     #[instrument(skip(self), err)]
-    async fn approve_job(&self, job_id: &Uuid, reviewer: &Uuid) -> Result<Option<DateTime<Utc>>> {
+    async fn approve_job(&self, job_id: &Uuid, reviewer: &Uuid) -> Result<Option<ApprovedJob>> {
         trace!("db: approve job");
 
-        let db = self.pool.get().await?;
-        let first_published_at = db
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Approve job
+        let Some(row) = tx
             .query_opt(
                 "
                 with old as (
-                    select first_published_at from job where job_id = $1
+                    select
+                        first_published_at,
+                        coalesce(publish_date > current_date, false) as scheduled
+                    from job
+                    where job_id = $1
                 )
                 update job
                 set
-                    status = 'published',
-                    first_published_at = coalesce(first_published_at, current_timestamp),
-                    published_at = current_timestamp,
+                    status = case when (select scheduled from old) then 'scheduled' else 'published' end,
+                    first_published_at = case
+                        when (select scheduled from old) then first_published_at
+                        else coalesce(first_published_at, current_timestamp)
+                    end,
+                    published_at = case
+                        when (select scheduled from old) then published_at
+                        else current_timestamp
+                    end,
+                    expiry_reminder_sent_at = null,
                     reviewed_at = current_timestamp,
                     reviewed_by = $2
                 where job_id = $1
-                returning (select first_published_at from old);
+                returning
+                    employer_id,
+                    publish_date,
+                    (select first_published_at from old),
+                    (select scheduled from old);
                 ",
                 &[job_id, reviewer],
             )
             .await?
-            .and_then(|row| row.get::<_, Option<DateTime<Utc>>>("first_published_at"));
+        else {
+            return Ok(None);
+        };
+        let scheduled: bool = row.get("scheduled");
+        let approved_job = ApprovedJob {
+            first_published: !scheduled
+                && row.get::<_, Option<DateTime<Utc>>>("first_published_at").is_none(),
+            scheduled_for: if scheduled { row.get("publish_date") } else { None },
+        };
 
-        Ok(first_published_at)
+        // Record action in the audit log
+        let entry = NewAuditLogEntry {
+            action: AuditAction::JobApproved,
+            actor_id: *reviewer,
+            details: None,
+            employer_id: Some(row.get("employer_id")),
+            target_id: Some(*job_id),
+        };
+        add_audit_log_entry(&tx, &entry).await?;
+
+        // Commit transaction
+        tx.commit().await?;
+
+        Ok(Some(approved_job))
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_job_review_notification_data(
+        &self,
+        job_id: &Uuid,
+    ) -> Result<Option<JobReviewNotificationData>> {
+        trace!("db: get job review notification data");
+
+        let db = self.pool.get().await?;
+        let data = db
+            .query_opt(
+                "
+                select
+                    j.title as job_title,
+                    array(
+                        select user_id
+                        from employer_team
+                        where employer_id = j.employer_id
+                        and approved = true
+                    ) as recipients
+                from job j
+                where j.job_id = $1::uuid;
+                ",
+                &[&job_id],
+            )
+            .await?
+            .map(|row| JobReviewNotificationData {
+                job_title: row.get("job_title"),
+                recipients: row.get("recipients"),
+            });
+
+        Ok(data)
+    }
+
+    /// The first revision stored after the last review holds the version of
+    /// the job that was reviewed, as jobs are not modified by the review.
+    #[instrument(skip(self), err)]
+    async fn get_last_reviewed_job(&self, job_id: &Uuid) -> Result<Option<ReviewedJob>> {
+        trace!("db: get last reviewed job");
+
+        let db = self.pool.get().await?;
+        let reviewed_job = db
+            .query_opt(
+                "
+                select
+                    r.data,
+                    j.reviewed_at
+                from job_revision r
+                join job j using (job_id)
+                where r.job_id = $1::uuid
+                and j.reviewed_at is not null
+                and r.created_at > j.reviewed_at
+                order by r.created_at asc
+                limit 1;
+                ",
+                &[&job_id],
+            )
+            .await?
+            .and_then(
+                |row| match serde_json::from_value(row.get::<_, serde_json::Value>("data")) {
+                    Ok(job) => Some(ReviewedJob {
+                        job,
+                        reviewed_at: row.get("reviewed_at"),
+                    }),
+                    Err(err) => {
+                        warn!(%job_id, "invalid job revision: {err}");
+                        None
+                    }
+                },
+            );
+
+        Ok(reviewed_job)
     }
 
     #[instrument(skip(self), err)]
@@ -110,21 +241,64 @@ impl DBDashBoardModerator for PgDB {
     async fn reject_job(&self, job_id: &Uuid, reviewer: &Uuid, review_notes: Option<&String>) -> Result<()> {
         trace!("db: reject job");
 
-        let db = self.pool.get().await?;
-        db.execute(
-            "
-            update job
-            set
-                status = 'rejected',
-                review_notes = $3,
-                reviewed_at = current_timestamp,
-                reviewed_by = $2
-            where job_id = $1;
-            ",
-            &[job_id, reviewer, &review_notes],
-        )
-        .await?;
+        // Begin transaction
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Reject job
+        let employer_id: Option<Uuid> = tx
+            .query_opt(
+                "
+                update job
+                set
+                    status = 'rejected',
+                    review_notes = $3,
+                    reviewed_at = current_timestamp,
+                    reviewed_by = $2
+                where job_id = $1
+                returning employer_id;
+                ",
+                &[job_id, reviewer, &review_notes],
+            )
+            .await?
+            .map(|row| row.get("employer_id"));
+
+        // Record action in the audit log
+        if employer_id.is_some() {
+            let entry = NewAuditLogEntry {
+                action: AuditAction::JobRejected,
+                actor_id: *reviewer,
+                details: Some(AuditLogDetails {
+                    review_notes: review_notes.filter(|notes| !notes.trim().is_empty()).cloned(),
+                    ..Default::default()
+                }),
+                employer_id,
+                target_id: Some(*job_id),
+            };
+            add_audit_log_entry(&tx, &entry).await?;
+        }
+
+        // Commit transaction
+        tx.commit().await?;
 
         Ok(())
     }
 }
+
+/// Outcome of the approval of a job.
+#[derive(Debug, Clone)]
+pub(crate) struct ApprovedJob {
+    /// Whether the job has been published for the first time.
+    pub first_published: bool,
+    /// Date when the job will be published, if it has been scheduled.
+    pub scheduled_for: Option<NaiveDate>,
+}
+
+/// Information needed to notify the employer's team about the review of a job.
+#[derive(Debug, Clone)]
+pub(crate) struct JobReviewNotificationData {
+    /// Title of the job reviewed.
+    pub job_title: String,
+    /// Identifiers of the users to notify.
+    pub recipients: Vec<Uuid>,
+}
@@ -11,18 +11,29 @@ use uuid::Uuid;
 
 use crate::{
     PgDB,
-    templates::jobboard::{
-        jobs::{Filters, FiltersOptions, Job, JobSummary},
-        stats::Stats,
+    templates::{
+        dashboard::employer::applications::ScreeningAnswer,
+        jobboard::{
+            jobs::{Filters, FiltersOptions, Job, JobSummary},
+            seo::SitemapJob,
+            stats::Stats,
+        },
     },
 };
 
 /// Trait for database operations used by the job board, such as applying and searching jobs.
 #[async_trait]
 pub(crate) trait DBJobBoard {
-    /// Records a user's application to a job. Returns `true` if the
+    /// Records a user's application to a job, including the cover letter and
+    /// the answers to the screening questions, if any. Returns `true` if the
     /// application was successfully recorded or `false` otherwise.
-    async fn apply_to_job(&self, job_id: &Uuid, user_id: &Uuid) -> Result<bool>;
+    async fn apply_to_job(
+        &self,
+        job_id: &Uuid,
+        user_id: &Uuid,
+        cover_letter: Option<&str>,
+        screening_answers: Option<&[ScreeningAnswer]>,
+    ) -> Result<bool>;
 
     /// Fetches a job for the job board by its unique identifier.
     async fn get_job_jobboard(&self, job_id: &Uuid) -> Result<Option<Job>>;
@@ -30,9 +41,20 @@ pub(crate) trait DBJobBoard {
     /// Retrieves available filter options for job searches.
     async fn get_jobs_filters_options(&self) -> Result<FiltersOptions>;
 
+    /// Retrieves the information needed to notify the employer's team about a
+    /// new application to a job from the given user.
+    async fn get_new_application_notification_data(
+        &self,
+        job_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<NewApplicationNotificationData>>;
+
     /// Retrieves statistics about the job board.
     async fn get_stats(&self) -> Result<Stats>;
 
+    /// Lists all the published jobs to include in the sitemap.
+    async fn list_sitemap_jobs(&self) -> Result<Vec<SitemapJob>>;
+
     /// Searches for jobs using the provided filter criteria.
     async fn search_jobs(&self, filters: &Filters) -> Result<JobsSearchOutput>;
 }
@@ -40,27 +62,43 @@ pub(crate) trait DBJobBoard {
 /// Implementation of DBJobBoard for the PostgreSQL database backend.
 #[async_trait]
 impl DBJobBoard for PgDB {
-    #[instrument(skip(self), err)]
-    async fn apply_to_job(&self, job_id: &Uuid, user_id: &Uuid) -> Result<bool> {
+    #[instrument(skip(self, cover_letter, screening_answers), err)]
+    async fn apply_to_job(
+        &self,
+        job_id: &Uuid,
+        user_id: &Uuid,
+        cover_letter: Option<&str>,
+        screening_answers: Option<&[ScreeningAnswer]>,
+    ) -> Result<bool> {
         trace!("db: apply to job");
 
         let db = self.pool.get().await?;
         let num_rows_inserted = db
             .execute(
                 "
-                insert into application (
-                    job_id,
-                    job_seeker_profile_id
+                with new_application as (
+                    insert into application (
+                        job_id,
+                        job_seeker_profile_id,
+                        cover_letter,
+                        screening_answers
+                    )
+                    select
+                        job_id,
+                        (select job_seeker_profile_id from job_seeker_profile where user_id = $2::uuid),
+                        $3::text,
+                        $4::jsonb
+                    from job
+                    where job_id = $1::uuid
+                    and status = 'published'
+                    on conflict (job_seeker_profile_id, job_id) do nothing
+                    returning application_id, status
                 )
-                select
-                    job_id,
-                    (select job_seeker_profile_id from job_seeker_profile where user_id = $2::uuid)
-                from job
-                where job_id = $1::uuid
-                and status = 'published'
-                on conflict (job_seeker_profile_id, job_id) do nothing;
+                insert into application_status_change (application_id, status)
+                select application_id, status
+                from new_application;
                 ",
-                &[&job_id, &user_id],
+                &[&job_id, &user_id, &cover_letter, &screening_answers.map(Json)],
             )
             .await?;
 
@@ -94,6 +132,7 @@ impl DBJobBoard for PgDB {
                     j.salary_min,
                     j.salary_max,
                     j.salary_period,
+                    j.screening_questions,
                     j.seniority,
                     j.skills,
                     j.tz_end,
@@ -195,6 +234,9 @@ impl DBJobBoard for PgDB {
                 salary_min: row.get("salary_min"),
                 salary_max: row.get("salary_max"),
                 salary_period: row.get("salary_period"),
+                screening_questions: row
+                    .get::<_, Option<serde_json::Value>>("screening_questions")
+                    .map(|v| serde_json::from_value(v).expect("screening questions should be valid json")),
                 seniority: row
                     .get::<_, Option<String>>("seniority")
                     .map(|s| s.parse().expect("valid seniority")),
@@ -251,6 +293,43 @@ impl DBJobBoard for PgDB {
         inner(db).await
     }
 
+    #[instrument(skip(self), err)]
+    async fn get_new_application_notification_data(
+        &self,
+        job_id: &Uuid,
+        user_id: &Uuid,
+    ) -> Result<Option<NewApplicationNotificationData>> {
+        trace!("db: get new application notification data");
+
+        let db = self.pool.get().await?;
+        let data = db
+            .query_opt(
+                "
+                select
+                    j.title as job_title,
+                    p.job_seeker_profile_id,
+                    array(
+                        select et.user_id
+                        from employer_team et
+                        where et.employer_id = j.employer_id
+                        and et.approved = true
+                    ) as recipients
+                from job j
+                join job_seeker_profile p on p.user_id = $2::uuid
+                where j.job_id = $1::uuid;
+                ",
+                &[&job_id, &user_id],
+            )
+            .await?
+            .map(|row| NewApplicationNotificationData {
+                job_seeker_profile_id: row.get("job_seeker_profile_id"),
+                job_title: row.get("job_title"),
+                recipients: row.get("recipients"),
+            });
+
+        Ok(data)
+    }
+
     #[instrument(skip(self))]
     async fn get_stats(&self) -> Result<Stats> {
         trace!("db: get stats");
@@ -266,16 +345,57 @@ impl DBJobBoard for PgDB {
         Ok(stats)
     }
 
+    #[instrument(skip(self), err)]
+    async fn list_sitemap_jobs(&self) -> Result<Vec<SitemapJob>> {
+        trace!("db: list sitemap jobs");
+
+        let db = self.pool.get().await?;
+        let jobs = db
+            .query(
+                "
+                select
+                    job_id,
+                    title,
+                    coalesce(updated_at, published_at) as updated_at
+                from job
+                where status = 'published'
+                order by published_at desc
+                limit 50000;
+                ",
+                &[],
+            )
+            .await?
+            .into_iter()
+            .map(|row| SitemapJob {
+                job_id: row.get("job_id"),
+                title: row.get("title"),
+                updated_at: row.get("updated_at"),
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+
     #[instrument(skip(self))]
     async fn search_jobs(&self, filters: &Filters) -> Result<JobsSearchOutput> {
         trace!("db: search jobs");
 
+        // Prepare filters, including the published bounds (they are not
+        // serialized with the filters, as users can't set them)
+        let mut filters_json = serde_json::to_value(filters)?;
+        if let Some(published_after) = filters.published_after {
+            filters_json["published_after"] = serde_json::to_value(published_after)?;
+        }
+        if let Some(published_before) = filters.published_before {
+            filters_json["published_before"] = serde_json::to_value(published_before)?;
+        }
+
         // Query database
         let db = self.pool.get().await?;
         let row = db
             .query_one(
                 "select jobs::text, total from search_jobs($1::jsonb)",
-                &[&Json(filters)],
+                &[&Json(filters_json)],
             )
             .await?;
 
@@ -298,3 +418,14 @@ pub(crate) struct JobsSearchOutput {
     /// Total number of jobs matching the search criteria.
     pub total: usize,
 }
+
+/// Information needed to notify the employer's team about a new application.
+#[derive(Debug, Clone)]
+pub(crate) struct NewApplicationNotificationData {
+    /// Identifier of the applicant's job seeker profile.
+    pub job_seeker_profile_id: Uuid,
+    /// Title of the job applied to.
+    pub job_title: String,
+    /// Identifiers of the users to notify.
+    pub recipients: Vec<Uuid>,
+}
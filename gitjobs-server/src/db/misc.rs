@@ -12,6 +12,9 @@ use crate::{
 /// Trait that defines common database operations used across the site.
 #[async_trait]
 pub(crate) trait DBMisc {
+    /// Lists all the projects in a foundation.
+    async fn list_projects(&self, foundation: &str) -> Result<Vec<Project>>;
+
     /// Searches for locations matching the provided query string.
     async fn search_locations(&self, ts_query: &str) -> Result<Vec<Location>>;
 
@@ -24,6 +27,40 @@ pub(crate) trait DBMisc {
 
 #[async_trait]
 impl DBMisc for PgDB {
+    #[instrument(skip(self), err)]
+    async fn list_projects(&self, foundation: &str) -> Result<Vec<Project>> {
+        trace!("db: list projects");
+
+        let db = self.pool.get().await?;
+        let projects = db
+            .query(
+                "
+                select
+                    project_id,
+                    foundation,
+                    logo_url,
+                    maturity,
+                    name
+                from project
+                where foundation = $1::text
+                order by name asc;
+                ",
+                &[&foundation],
+            )
+            .await?
+            .into_iter()
+            .map(|row| Project {
+                project_id: row.get("project_id"),
+                foundation: row.get("foundation"),
+                logo_url: row.get("logo_url"),
+                maturity: row.get("maturity"),
+                name: row.get("name"),
+            })
+            .collect();
+
+        Ok(projects)
+    }
+
     #[instrument(skip(self), err)]
     async fn search_locations(&self, ts_query: &str) -> Result<Vec<Location>> {
         trace!("db: search locations");
@@ -0,0 +1,358 @@
+//! This module defines a mock implementation of the database, to be used in
+//! tests.
+
+// The expectations generated for the methods taking `Option<&T>` arguments
+// hold references to them.
+#![allow(clippy::ref_option_ref)]
+
+use anyhow::Result;
+use async_trait::async_trait;
+use axum_login::tower_sessions::session;
+use chrono::NaiveDate;
+use mockall::mock;
+use uuid::Uuid;
+
+use crate::{
+    auth, db, event_tracker, img, notifications,
+    templates::{
+        self,
+        dashboard::{
+            employer::{self, team},
+            job_seeker, moderator,
+        },
+        jobboard, misc,
+    },
+};
+
+use super::{
+    DB,
+    auth::DBAuth,
+    dashboard::{
+        DBDashBoard, audit::DBDashBoardAudit, employer::DBDashBoardEmployer,
+        job_seeker::DBDashBoardJobSeeker, moderator::DBDashBoardModerator,
+    },
+    event_tracker::DBEventTracker,
+    img::DBImage,
+    jobboard::DBJobBoard,
+    misc::DBMisc,
+    notifications::DBNotifications,
+    resume::DBResume,
+    workers::DBWorkers,
+};
+
+mock! {
+    /// Mock implementation of the database.
+    pub(crate) DB {}
+
+    #[async_trait]
+    impl DB for DB {
+        async fn tx_begin(&self) -> Result<Uuid>;
+        async fn tx_commit(&self, client_id: Uuid) -> Result<()>;
+        async fn tx_rollback(&self, client_id: Uuid) -> Result<()>;
+    }
+
+    impl DBDashBoard for DB {}
+
+    #[async_trait]
+    impl DBAuth for DB {
+        async fn create_session(&self, record: &session::Record) -> Result<()>;
+        async fn delete_session(&self, session_id: &session::Id) -> Result<()>;
+        async fn get_session(&self, session_id: &session::Id) -> Result<Option<session::Record>>;
+        async fn get_user_by_email(&self, email: &str) -> Result<Option<auth::User>>;
+        async fn get_user_by_id(&self, user_id: &Uuid) -> Result<Option<auth::User>>;
+        async fn get_user_by_username(&self, username: &str) -> Result<Option<auth::User>>;
+        async fn get_user_employer_role(
+            &self,
+            user_id: &Uuid,
+            employer_id: &Uuid,
+        ) -> Result<Option<team::EmployerRole>>;
+        async fn get_user_job_role(
+            &self,
+            user_id: &Uuid,
+            job_id: &Uuid,
+        ) -> Result<Option<team::EmployerRole>>;
+        async fn get_user_notifications_settings(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<templates::auth::UserNotificationsSettings>;
+        async fn get_user_password(&self, user_id: &Uuid) -> Result<Option<String>>;
+        async fn is_image_public(&self, image_id: &Uuid) -> Result<bool>;
+        async fn sign_up_user(
+            &self,
+            user_summary: &auth::UserSummary,
+            email_verified: bool,
+        ) -> Result<(auth::User, Option<db::auth::VerificationCode>)>;
+        async fn update_session(&self, record: &session::Record) -> Result<()>;
+        async fn update_user_details(&self, user_id: &Uuid, user_summary: &auth::UserSummary) -> Result<()>;
+        async fn update_user_notifications_settings(
+            &self,
+            user_id: &Uuid,
+            settings: &templates::auth::UserNotificationsSettings,
+        ) -> Result<()>;
+        async fn update_user_password(&self, user_id: &Uuid, new_password: &str) -> Result<()>;
+        async fn user_has_image_access(&self, user_id: &Uuid, image_id: &Uuid) -> Result<bool>;
+        async fn user_has_profile_access(&self, user_id: &Uuid, job_seeker_profile_id: &Uuid) -> Result<bool>;
+        async fn verify_email(&self, code: &Uuid) -> Result<()>;
+    }
+
+    #[async_trait]
+    impl DBDashBoardAudit for DB {
+        async fn search_audit_log<'a, 'b, 'c>(
+            &'a self,
+            employer_id: Option<&'b Uuid>,
+            filters: &'c templates::dashboard::audit::Filters,
+        ) -> Result<db::dashboard::audit::AuditLogSearchOutput>;
+    }
+
+    #[async_trait]
+    impl DBDashBoardEmployer for DB {
+        async fn accept_team_member_invitation(&self, employer_id: &Uuid, user_id: &Uuid) -> Result<()>;
+        async fn add_application_note(
+            &self,
+            employer_id: &Uuid,
+            profile_id: &Uuid,
+            user_id: &Uuid,
+            note: &employer::applications::NewApplicationNote,
+        ) -> Result<()>;
+        async fn add_employer(
+            &self,
+            user_id: &Uuid,
+            employer: &employer::employers::Employer,
+        ) -> Result<Uuid>;
+        async fn add_job(&self, employer_id: &Uuid, job: &employer::jobs::Job, actor_id: &Uuid) -> Result<()>;
+        async fn add_jobs(&self, employer_id: &Uuid, jobs: &[employer::jobs::Job], actor_id: &Uuid)
+        -> Result<()>;
+        async fn add_team_member(
+            &self,
+            employer_id: &Uuid,
+            email: &str,
+            role: &team::EmployerRole,
+            actor_id: &Uuid,
+        ) -> Result<Option<Uuid>>;
+        async fn archive_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()>;
+        async fn delete_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()>;
+        async fn delete_team_member(
+            &self,
+            employer_id: &Uuid,
+            user_id: &Uuid,
+            actor_id: &Uuid,
+        ) -> Result<bool>;
+        async fn duplicate_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<Option<Uuid>>;
+        async fn get_applications_filters_options(
+            &self,
+            employer_id: &Uuid,
+        ) -> Result<employer::applications::FiltersOptions>;
+        async fn get_employer(&self, employer_id: &Uuid) -> Result<employer::employers::Employer>;
+        async fn get_job_dashboard(&self, job_id: &Uuid) -> Result<employer::jobs::Job>;
+        async fn get_job_seeker_user_id(&self, job_seeker_profile_id: &Uuid) -> Result<Option<Uuid>>;
+        async fn get_job_stats(&self, job_id: &Uuid) -> Result<employer::jobs::JobStats>;
+        async fn get_team_member_role(
+            &self,
+            employer_id: &Uuid,
+            user_id: &Uuid,
+        ) -> Result<Option<team::EmployerRole>>;
+        async fn get_user_invitations_count(&self, user_id: &Uuid) -> Result<usize>;
+        async fn list_applications_feedback(
+            &self,
+            employer_id: &Uuid,
+            profile_id: &Uuid,
+        ) -> Result<Vec<employer::applications::ApplicationFeedback>>;
+        async fn list_employer_jobs(&self, employer_id: &Uuid) -> Result<Vec<employer::jobs::JobSummary>>;
+        async fn list_employers(&self, user_id: &Uuid) -> Result<Vec<employer::employers::EmployerSummary>>;
+        async fn list_certifications(&self) -> Result<Vec<misc::Certification>>;
+        async fn list_foundations(&self) -> Result<Vec<misc::Foundation>>;
+        async fn list_team_members(&self, employer_id: &Uuid) -> Result<Vec<team::TeamMember>>;
+        async fn list_user_invitations(&self, user_id: &Uuid) -> Result<Vec<team::TeamInvitation>>;
+        async fn publish_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<()>;
+        async fn renew_job(&self, job_id: &Uuid, actor_id: &Uuid) -> Result<Option<NaiveDate>>;
+        async fn search_applications(
+            &self,
+            employer_id: &Uuid,
+            filters: &employer::applications::Filters,
+        ) -> Result<db::dashboard::employer::ApplicationsSearchOutput>;
+        async fn update_application_rating(
+            &self,
+            employer_id: &Uuid,
+            profile_id: &Uuid,
+            rating: &employer::applications::ApplicationRatingInput,
+        ) -> Result<()>;
+        async fn update_application_status(
+            &self,
+            employer_id: &Uuid,
+            application_id: &Uuid,
+            status: &employer::applications::ApplicationStatus,
+            user_id: &Uuid,
+        ) -> Result<bool>;
+        async fn update_employer(
+            &self,
+            employer_id: &Uuid,
+            employer: &employer::employers::Employer,
+            actor_id: &Uuid,
+        ) -> Result<()>;
+        async fn update_job(&self, job_id: &Uuid, job: &employer::jobs::Job, actor_id: &Uuid) -> Result<()>;
+        async fn update_team_member_role(
+            &self,
+            employer_id: &Uuid,
+            user_id: &Uuid,
+            role: &team::EmployerRole,
+            actor_id: &Uuid,
+        ) -> Result<bool>;
+    }
+
+    #[async_trait]
+    impl DBDashBoardJobSeeker for DB {
+        async fn add_saved_search(
+            &self,
+            user_id: &Uuid,
+            saved_search: &job_seeker::saved_searches::NewSavedSearch,
+            filters: &jobboard::jobs::Filters,
+        ) -> Result<()>;
+        async fn cancel_application(&self, application_id: &Uuid, user_id: &Uuid) -> Result<()>;
+        async fn delete_saved_search(&self, saved_search_id: &Uuid, user_id: &Uuid) -> Result<()>;
+        async fn get_job_seeker_profile(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Option<job_seeker::profile::JobSeekerProfile>>;
+        async fn list_job_seeker_applications(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Vec<job_seeker::applications::Application>>;
+        async fn list_saved_searches(
+            &self,
+            user_id: &Uuid,
+        ) -> Result<Vec<job_seeker::saved_searches::SavedSearch>>;
+        async fn update_job_seeker_profile(
+            &self,
+            user_id: &Uuid,
+            profile: &job_seeker::profile::JobSeekerProfile,
+        ) -> Result<()>;
+    }
+
+    #[async_trait]
+    impl DBDashBoardModerator for DB {
+        async fn approve_job(
+            &self,
+            job_id: &Uuid,
+            reviewer: &Uuid,
+        ) -> Result<Option<db::dashboard::moderator::ApprovedJob>>;
+        async fn get_job_review_notification_data(
+            &self,
+            job_id: &Uuid,
+        ) -> Result<Option<db::dashboard::moderator::JobReviewNotificationData>>;
+        async fn get_last_reviewed_job(&self, job_id: &Uuid) -> Result<Option<moderator::jobs::ReviewedJob>>;
+        async fn list_jobs_for_moderation(
+            &self,
+            status: employer::jobs::JobStatus,
+        ) -> Result<Vec<moderator::jobs::JobSummary>>;
+        async fn reject_job<'a, 'b, 'c, 'd>(
+            &'a self,
+            job_id: &'b Uuid,
+            reviewer: &'c Uuid,
+            review_notes: Option<&'d String>,
+        ) -> Result<()>;
+    }
+
+    #[async_trait]
+    impl DBEventTracker for DB {
+        async fn update_jobs_views(
+            &self,
+            data: Vec<(event_tracker::JobId, event_tracker::Day, event_tracker::Total)>,
+        ) -> Result<()>;
+        async fn update_search_appearances(
+            &self,
+            data: Vec<(event_tracker::JobId, event_tracker::Day, event_tracker::Total)>,
+        ) -> Result<()>;
+    }
+
+    #[async_trait]
+    impl DBImage for DB {
+        async fn get_image_version(
+            &self,
+            image_id: Uuid,
+            version: &str,
+        ) -> Result<Option<(Vec<u8>, img::ImageFormat)>>;
+        async fn save_image_versions(&self, user_id: &Uuid, versions: Vec<img::ImageVersion>) -> Result<Uuid>;
+    }
+
+    #[async_trait]
+    impl DBJobBoard for DB {
+        async fn apply_to_job<'a, 'b, 'c, 'd, 'e>(
+            &'a self,
+            job_id: &'b Uuid,
+            user_id: &'c Uuid,
+            cover_letter: Option<&'d str>,
+            screening_answers: Option<&'e [employer::applications::ScreeningAnswer]>,
+        ) -> Result<bool>;
+        async fn get_job_jobboard(&self, job_id: &Uuid) -> Result<Option<jobboard::jobs::Job>>;
+        async fn get_jobs_filters_options(&self) -> Result<jobboard::jobs::FiltersOptions>;
+        async fn get_new_application_notification_data(
+            &self,
+            job_id: &Uuid,
+            user_id: &Uuid,
+        ) -> Result<Option<db::jobboard::NewApplicationNotificationData>>;
+        async fn get_stats(&self) -> Result<jobboard::stats::Stats>;
+        async fn list_sitemap_jobs(&self) -> Result<Vec<jobboard::seo::SitemapJob>>;
+        async fn search_jobs(
+            &self,
+            filters: &jobboard::jobs::Filters,
+        ) -> Result<db::jobboard::JobsSearchOutput>;
+    }
+
+    #[async_trait]
+    impl DBMisc for DB {
+        async fn list_projects(&self, foundation: &str) -> Result<Vec<misc::Project>>;
+        async fn search_locations(&self, ts_query: &str) -> Result<Vec<misc::Location>>;
+        async fn search_members(&self, foundation: &str, member: &str) -> Result<Vec<misc::Member>>;
+        async fn search_projects(&self, foundation: &str, project: &str) -> Result<Vec<misc::Project>>;
+    }
+
+    #[async_trait]
+    impl DBNotifications for DB {
+        async fn claim_pending_notifications(&self, limit: i64) -> Result<Vec<notifications::Notification>>;
+        async fn disable_notifications(
+            &self,
+            user_id: &Uuid,
+            kind: &notifications::NotificationKind,
+        ) -> Result<()>;
+        async fn enqueue_notification(&self, notification: &notifications::NewNotification) -> Result<()>;
+        async fn is_notification_enabled(
+            &self,
+            user_id: &Uuid,
+            kind: &notifications::NotificationKind,
+        ) -> Result<bool>;
+        async fn list_failed_notifications(
+            &self,
+        ) -> Result<Vec<moderator::notifications::FailedNotification>>;
+        async fn retry_notification(&self, notification_id: &Uuid) -> Result<()>;
+        async fn update_notification(
+            &self,
+            client_id: Uuid,
+            notification: &notifications::Notification,
+            error: Option<String>,
+        ) -> Result<()>;
+    }
+
+    #[async_trait]
+    impl DBResume for DB {
+        async fn get_resume(&self, resume_id: &Uuid) -> Result<Option<db::resume::Resume>>;
+        async fn save_resume(&self, user_id: &Uuid, file_name: &str, data: &[u8]) -> Result<Uuid>;
+    }
+
+    #[async_trait]
+    impl DBWorkers for DB {
+        async fn archive_expired_jobs(&self) -> Result<()>;
+        async fn complete_expiry_reminder(
+            &self,
+            job: &db::workers::ExpiringJob,
+            notifications: &[notifications::NewNotification],
+        ) -> Result<()>;
+        async fn complete_saved_search_run<'a, 'b, 'c>(
+            &'a self,
+            saved_search: &'b db::workers::DueSavedSearch,
+            notification: Option<&'c notifications::NewNotification>,
+        ) -> Result<()>;
+        async fn get_due_saved_searches(&self) -> Result<Vec<db::workers::DueSavedSearch>>;
+        async fn get_expiring_jobs(&self) -> Result<Vec<db::workers::ExpiringJob>>;
+        async fn publish_scheduled_jobs(&self) -> Result<Vec<db::workers::ScheduledJobPublished>>;
+    }
+}
@@ -13,6 +13,7 @@ use img::DBImage;
 use jobboard::DBJobBoard;
 use misc::DBMisc;
 use notifications::DBNotifications;
+use resume::DBResume;
 use tokio::sync::RwLock;
 use tokio::{select, time::sleep};
 use tokio_util::sync::CancellationToken;
@@ -26,7 +27,10 @@ pub(crate) mod event_tracker;
 pub(crate) mod img;
 pub(crate) mod jobboard;
 pub(crate) mod misc;
+#[cfg(test)]
+pub(crate) mod mock;
 pub(crate) mod notifications;
+pub(crate) mod resume;
 pub(crate) mod workers;
 
 /// Error message when a transaction client is not found.
@@ -41,7 +45,7 @@ const TXS_CLIENT_TIMEOUT: TimeDelta = TimeDelta::seconds(10);
 /// Abstraction layer over the database. Defines required operations for a DB implementation.
 #[async_trait]
 pub(crate) trait DB:
-    DBJobBoard + DBDashBoard + DBAuth + DBImage + DBNotifications + DBWorkers + DBEventTracker + DBMisc
+    DBJobBoard + DBDashBoard + DBAuth + DBImage + DBResume + DBNotifications + DBWorkers + DBEventTracker + DBMisc
 {
     /// Begins a new transaction and returns a unique transaction identifier.
     async fn tx_begin(&self) -> Result<Uuid>;
@@ -1,5 +1,5 @@
 //! This module defines database functionality used to manage notifications, including
-//! enqueueing, retrieving, and updating notification records.
+//! enqueueing, retrieving, retrying and updating notification records.
 
 use anyhow::{Result, bail};
 use async_trait::async_trait;
@@ -9,19 +9,38 @@ use uuid::Uuid;
 use crate::{
     PgDB,
     db::TX_CLIENT_NOT_FOUND,
-    notifications::{NewNotification, Notification},
+    notifications::{
+        CLAIM_TIMEOUT_SECS, MAX_DELIVERY_ATTEMPTS, NewNotification, Notification, NotificationKind,
+        RETRY_BACKOFF_SECS,
+    },
+    templates::dashboard::moderator::notifications::FailedNotification,
 };
 
 /// Trait that defines database operations used to manage notifications.
 #[async_trait]
 pub(crate) trait DBNotifications {
+    /// Claims up to `limit` pending notifications for delivery. Claimed
+    /// notifications are not returned again until their claim is released
+    /// when the delivery attempt is recorded (or it expires). Notifications
+    /// that failed are retried once their backoff period has elapsed.
+    async fn claim_pending_notifications(&self, limit: i64) -> Result<Vec<Notification>>;
+
+    /// Disables the notifications of the kind provided for the user.
+    async fn disable_notifications(&self, user_id: &Uuid, kind: &NotificationKind) -> Result<()>;
+
     /// Enqueues a notification to be delivered.
     async fn enqueue_notification(&self, notification: &NewNotification) -> Result<()>;
 
-    /// Retrieves a pending notification for delivery.
-    async fn get_pending_notification(&self, client_id: Uuid) -> Result<Option<Notification>>;
+    /// Checks if the user has the notifications of the kind provided enabled.
+    async fn is_notification_enabled(&self, user_id: &Uuid, kind: &NotificationKind) -> Result<bool>;
+
+    /// Lists the notifications that could not be delivered (dead-lettered).
+    async fn list_failed_notifications(&self) -> Result<Vec<FailedNotification>>;
 
-    /// Updates a notification after a delivery attempt.
+    /// Schedules a failed notification to be delivered again.
+    async fn retry_notification(&self, notification_id: &Uuid) -> Result<()>;
+
+    /// Updates a notification after a delivery attempt, releasing its claim.
     async fn update_notification(
         &self,
         client_id: Uuid,
@@ -32,6 +51,87 @@ pub(crate) trait DBNotifications {
 
 #[async_trait]
 impl DBNotifications for PgDB {
+    #[instrument(skip(self), err)]
+    async fn claim_pending_notifications(&self, limit: i64) -> Result<Vec<Notification>> {
+        trace!("db: claim pending notifications");
+
+        // Claim pending notifications (if any)
+        let db = self.pool.get().await?;
+        let notifications = db
+            .query(
+                r#"
+                with pending_notification as (
+                    select notification_id
+                    from notification
+                    where processed = false
+                    and (
+                        claimed_at is null
+                        or claimed_at + make_interval(secs => $2::bigint) <= current_timestamp
+                    )
+                    and (
+                        last_attempt_at is null
+                        or last_attempt_at
+                            + make_interval(secs => $1::bigint * power(2, attempts - 1))
+                            <= current_timestamp
+                    )
+                    order by notification_id asc
+                    limit $3::bigint
+                    for update skip locked
+                )
+                update notification n
+                set claimed_at = current_timestamp
+                from pending_notification p, "user" u
+                where n.notification_id = p.notification_id
+                and n.user_id = u.user_id
+                returning
+                    n.attempts,
+                    n.kind,
+                    n.notification_id,
+                    n.template_data,
+                    n.user_id,
+                    u.email;
+                "#,
+                &[&RETRY_BACKOFF_SECS, &CLAIM_TIMEOUT_SECS, &limit],
+            )
+            .await?
+            .into_iter()
+            .map(|row| Notification {
+                attempts: row.get("attempts"),
+                email: row.get("email"),
+                kind: row
+                    .get::<_, String>("kind")
+                    .as_str()
+                    .try_into()
+                    .expect("kind to be valid"),
+                notification_id: row.get("notification_id"),
+                template_data: row.get("template_data"),
+                user_id: row.get("user_id"),
+            })
+            .collect();
+
+        Ok(notifications)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn disable_notifications(&self, user_id: &Uuid, kind: &NotificationKind) -> Result<()> {
+        trace!("db: disable notifications");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            insert into notification_preference (user_id, kind, enabled)
+            values ($1::uuid, $2::text, false)
+            on conflict (user_id, kind) do update set
+                enabled = false,
+                updated_at = current_timestamp;
+            ",
+            &[&user_id, &kind.to_string()],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self, notification), err)]
     async fn enqueue_notification(&self, notification: &NewNotification) -> Result<()> {
         trace!("db: enqueue notification");
@@ -54,48 +154,98 @@ impl DBNotifications for PgDB {
     }
 
     #[instrument(skip(self), err)]
-    async fn get_pending_notification(&self, client_id: Uuid) -> Result<Option<Notification>> {
-        // Get transaction client
-        let clients = self.txs_clients.read().await;
-        let Some((tx, _)) = clients.get(&client_id) else {
-            bail!(TX_CLIENT_NOT_FOUND);
-        };
+    async fn is_notification_enabled(&self, user_id: &Uuid, kind: &NotificationKind) -> Result<bool> {
+        trace!("db: check if notification is enabled");
 
-        // Get pending notification (if any)
-        let notification = tx
+        let db = self.pool.get().await?;
+        let enabled = db
             .query_opt(
+                "
+                select enabled
+                from notification_preference
+                where user_id = $1::uuid
+                and kind = $2::text;
+                ",
+                &[&user_id, &kind.to_string()],
+            )
+            .await?
+            .is_none_or(|row| row.get("enabled"));
+
+        Ok(enabled)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn list_failed_notifications(&self) -> Result<Vec<FailedNotification>> {
+        trace!("db: list failed notifications");
+
+        let db = self.pool.get().await?;
+        let notifications = db
+            .query(
                 r#"
                 select
+                    n.attempts,
+                    n.created_at,
+                    n.error,
                     n.kind,
                     n.notification_id,
-                    n.template_data,
+                    n.processed_at,
                     u.email
                 from notification n
                 join "user" u using (user_id)
-                where processed = false
-                order by notification_id asc
-                limit 1
-                for update of n skip locked;
+                where n.processed = true
+                and n.error is not null
+                order by n.processed_at desc nulls last
+                limit 100;
                 "#,
                 &[],
             )
             .await?
-            .map(|row| Notification {
+            .into_iter()
+            .map(|row| FailedNotification {
+                attempts: row.get("attempts"),
+                created_at: row.get("created_at"),
                 email: row.get("email"),
+                error: row.get("error"),
                 kind: row
                     .get::<_, String>("kind")
                     .as_str()
                     .try_into()
                     .expect("kind to be valid"),
                 notification_id: row.get("notification_id"),
-                template_data: row.get("template_data"),
-            });
+                processed_at: row.get("processed_at"),
+            })
+            .collect();
 
-        Ok(notification)
+        Ok(notifications)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn retry_notification(&self, notification_id: &Uuid) -> Result<()> {
+        trace!("db: retry notification");
+
+        let db = self.pool.get().await?;
+        db.execute(
+            "
+            update notification set
+                attempts = 0,
+                error = null,
+                last_attempt_at = null,
+                processed = false,
+                processed_at = null
+            where notification_id = $1::uuid
+            and processed = true
+            and error is not null;
+            ",
+            &[&notification_id],
+        )
+        .await?;
+
+        Ok(())
     }
 
-    /// Updates the notification record after processing, marking it as processed and
-    /// recording any error.
+    /// Updates the notification record after a delivery attempt, recording any error.
+    /// Failed notifications are kept pending to be retried until the maximum number
+    /// of attempts is reached, when they are marked as processed (dead-lettered).
     #[instrument(skip(self, notification), err)]
     async fn update_notification(
         &self,
@@ -115,12 +265,17 @@ impl DBNotifications for PgDB {
         tx.execute(
             "
             update notification set
-                processed = true,
-                processed_at = current_timestamp,
+                attempts = attempts + 1,
+                claimed_at = null,
+                last_attempt_at = current_timestamp,
+                processed = ($2::text is null or attempts + 1 >= $3::int),
+                processed_at = case
+                    when $2::text is null or attempts + 1 >= $3::int then current_timestamp
+                end,
                 error = $2::text
             where notification_id = $1::uuid;
             ",
-            &[&notification.notification_id, &error],
+            &[&notification.notification_id, &error, &MAX_DELIVERY_ATTEMPTS],
         )
         .await?;
 
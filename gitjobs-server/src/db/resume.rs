@@ -0,0 +1,90 @@
+//! This module defines database operations for managing resumes.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use tracing::{instrument, trace};
+use uuid::Uuid;
+
+use crate::PgDB;
+
+/// Trait for database operations related to resume management.
+#[async_trait]
+pub(crate) trait DBResume {
+    /// Retrieves a resume from the database.
+    async fn get_resume(&self, resume_id: &Uuid) -> Result<Option<Resume>>;
+
+    /// Saves a resume in the database and returns its unique identifier.
+    async fn save_resume(&self, user_id: &Uuid, file_name: &str, data: &[u8]) -> Result<Uuid>;
+}
+
+/// Implementation of `DBResume` for the `PgDB` database backend.
+#[async_trait]
+impl DBResume for PgDB {
+    #[instrument(skip(self), err)]
+    async fn get_resume(&self, resume_id: &Uuid) -> Result<Option<Resume>> {
+        trace!("db: get resume");
+
+        let db = self.pool.get().await?;
+        let resume = db
+            .query_opt(
+                "
+                select
+                    r.created_by,
+                    r.data,
+                    r.file_name,
+                    p.job_seeker_profile_id
+                from resume r
+                left join job_seeker_profile p on r.resume_id = p.resume_id
+                where r.resume_id = $1::uuid;
+                ",
+                &[&resume_id],
+            )
+            .await?
+            .map(|row| Resume {
+                created_by: row.get("created_by"),
+                data: row.get("data"),
+                file_name: row.get("file_name"),
+                job_seeker_profile_id: row.get("job_seeker_profile_id"),
+            });
+
+        Ok(resume)
+    }
+
+    #[instrument(skip(self, data), err)]
+    async fn save_resume(&self, user_id: &Uuid, file_name: &str, data: &[u8]) -> Result<Uuid> {
+        trace!("db: save resume");
+
+        let db = self.pool.get().await?;
+        let row = db
+            .query_one(
+                "
+                insert into resume (
+                    created_by,
+                    data,
+                    file_name
+                ) values (
+                    $1::uuid,
+                    $2::bytea,
+                    $3::text
+                ) returning resume_id;
+                ",
+                &[&user_id, &data, &file_name],
+            )
+            .await?;
+
+        Ok(row.get("resume_id"))
+    }
+}
+
+/// Resume document stored in the database.
+#[derive(Debug, Clone)]
+pub(crate) struct Resume {
+    /// User who uploaded the resume, if still available.
+    pub created_by: Option<Uuid>,
+    /// Raw PDF document data.
+    pub data: Vec<u8>,
+    /// Original name of the uploaded file.
+    pub file_name: String,
+    /// Job seeker profile the resume is attached to, if any.
+    pub job_seeker_profile_id: Option<Uuid>,
+}
@@ -1,17 +1,50 @@
 //! This module defines database operations used by background task workers, such as
-//! archiving expired jobs.
+//! publishing scheduled jobs, archiving expired jobs or preparing job alerts.
 
 use anyhow::Result;
 use async_trait::async_trait;
-use tracing::instrument;
+use chrono::{DateTime, NaiveDate, Utc};
+use tracing::{error, instrument, trace};
+use uuid::Uuid;
 
-use crate::db::PgDB;
+use crate::{db::PgDB, notifications::NewNotification, templates::jobboard::jobs::Filters};
 
 /// Trait for database operations required by background tasks workers.
 #[async_trait]
 pub(crate) trait DBWorkers {
-    /// Archives jobs that have expired based on their published date.
+    /// Archives jobs that have expired, either because their closing date has
+    /// passed or, when they don't have one, based on their published date.
     async fn archive_expired_jobs(&self) -> Result<()>;
+
+    /// Marks the expiry reminder of the job provided as sent, enqueuing the
+    /// job expiring notifications provided in the same transaction. Nothing
+    /// is done if the reminder has been sent since the job was retrieved.
+    async fn complete_expiry_reminder(
+        &self,
+        job: &ExpiringJob,
+        notifications: &[NewNotification],
+    ) -> Result<()>;
+
+    /// Marks the saved search provided as run, enqueuing the job alert digest
+    /// notification provided (if any) in the same transaction. Nothing is done
+    /// if the saved search has been run since it was retrieved.
+    async fn complete_saved_search_run(
+        &self,
+        saved_search: &DueSavedSearch,
+        notification: Option<&NewNotification>,
+    ) -> Result<()>;
+
+    /// Returns the saved searches whose job alerts are due. The job alerts
+    /// frequency set by the user takes precedence over the one of the saved
+    /// search.
+    async fn get_due_saved_searches(&self) -> Result<Vec<DueSavedSearch>>;
+
+    /// Returns the published jobs that will expire soon and haven't been
+    /// reminded yet.
+    async fn get_expiring_jobs(&self) -> Result<Vec<ExpiringJob>>;
+
+    /// Publishes the scheduled jobs whose publish date has arrived.
+    async fn publish_scheduled_jobs(&self) -> Result<Vec<ScheduledJobPublished>>;
 }
 
 #[async_trait]
@@ -27,7 +60,10 @@ impl DBWorkers for PgDB {
                 archived_at = current_timestamp,
                 updated_at = current_timestamp
             where status = 'published'
-            and published_at + '30 days'::interval < current_timestamp;
+            and (
+                (closing_date is not null and closing_date < current_date)
+                or (closing_date is null and published_at + '30 days'::interval < current_timestamp)
+            );
             ",
             &[],
         )
@@ -35,4 +71,293 @@ impl DBWorkers for PgDB {
 
         Ok(())
     }
+
+    #[instrument(skip(self, notifications), err)]
+    async fn complete_expiry_reminder(
+        &self,
+        job: &ExpiringJob,
+        notifications: &[NewNotification],
+    ) -> Result<()> {
+        trace!("db: complete expiry reminder");
+
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Mark expiry reminder as sent, unless it has been sent since the job
+        // was retrieved
+        let num_rows_updated = tx
+            .execute(
+                "
+                update job set expiry_reminder_sent_at = current_timestamp
+                where job_id = $1::uuid
+                and status = 'published'
+                and expiry_reminder_sent_at is null;
+                ",
+                &[&job.job_id],
+            )
+            .await?;
+        if num_rows_updated == 0 {
+            return Ok(());
+        }
+
+        // Enqueue job expiring notifications
+        for notification in notifications {
+            tx.execute(
+                "
+                insert into notification (kind, user_id, template_data)
+                values ($1::text, $2::uuid, $3::jsonb);
+                ",
+                &[
+                    &notification.kind.to_string(),
+                    &notification.user_id,
+                    &notification.template_data,
+                ],
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, notification), err)]
+    async fn complete_saved_search_run(
+        &self,
+        saved_search: &DueSavedSearch,
+        notification: Option<&NewNotification>,
+    ) -> Result<()> {
+        trace!("db: complete saved search run");
+
+        let mut db = self.pool.get().await?;
+        let tx = db.transaction().await?;
+
+        // Mark saved search as run, unless it has been run since it was retrieved
+        let num_rows_updated = tx
+            .execute(
+                "
+                update saved_search set last_run_at = $2::timestamptz
+                where saved_search_id = $1::uuid
+                and coalesce(last_run_at, created_at) = $3::timestamptz;
+                ",
+                &[
+                    &saved_search.saved_search_id,
+                    &saved_search.until,
+                    &saved_search.since,
+                ],
+            )
+            .await?;
+        if num_rows_updated == 0 {
+            return Ok(());
+        }
+
+        // Enqueue job alert digest notification
+        if let Some(notification) = notification {
+            tx.execute(
+                "
+                insert into notification (kind, user_id, template_data)
+                values ($1::text, $2::uuid, $3::jsonb);
+                ",
+                &[
+                    &notification.kind.to_string(),
+                    &notification.user_id,
+                    &notification.template_data,
+                ],
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_due_saved_searches(&self) -> Result<Vec<DueSavedSearch>> {
+        trace!("db: get due saved searches");
+
+        let db = self.pool.get().await?;
+        let saved_searches = db
+            .query(
+                r#"
+                select
+                    s.saved_search_id,
+                    s.user_id,
+                    s.name,
+                    s.filters,
+                    coalesce(s.last_run_at, s.created_at) as since,
+                    current_timestamp as until,
+                    not exists (
+                        select 1
+                        from notification_preference p
+                        where p.user_id = s.user_id
+                        and p.kind = 'job-alert-digest'
+                        and p.enabled = false
+                    ) as notify
+                from saved_search s
+                join "user" u using (user_id)
+                where coalesce(s.last_run_at, s.created_at) <= current_timestamp - (
+                    case coalesce(u.job_alert_digest_frequency, s.frequency)
+                        when 'daily' then '1 day'::interval
+                        when 'weekly' then '7 days'::interval
+                    end
+                );
+                "#,
+                &[],
+            )
+            .await?
+            .iter()
+            .filter_map(|row| {
+                let saved_search_id: Uuid = row.get("saved_search_id");
+                let filters = match serde_json::from_value(row.get("filters")) {
+                    Ok(filters) => filters,
+                    Err(err) => {
+                        error!(%saved_search_id, "invalid saved search filters: {err}");
+                        return None;
+                    }
+                };
+                Some(DueSavedSearch {
+                    filters,
+                    name: row.get("name"),
+                    notify: row.get("notify"),
+                    saved_search_id,
+                    since: row.get("since"),
+                    until: row.get("until"),
+                    user_id: row.get("user_id"),
+                })
+            })
+            .collect();
+
+        Ok(saved_searches)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn get_expiring_jobs(&self) -> Result<Vec<ExpiringJob>> {
+        trace!("db: get expiring jobs");
+
+        let db = self.pool.get().await?;
+        let jobs = db
+            .query(
+                "
+                select
+                    j.job_id,
+                    j.title,
+                    coalesce(j.closing_date, (j.published_at + '30 days'::interval)::date) as closing_date,
+                    array(
+                        select t.user_id
+                        from employer_team t
+                        where t.employer_id = j.employer_id
+                        and t.approved = true
+                        and not exists (
+                            select 1
+                            from notification_preference p
+                            where p.user_id = t.user_id
+                            and p.kind = 'job-expiring'
+                            and p.enabled = false
+                        )
+                    ) as recipients
+                from job j
+                where j.status = 'published'
+                and j.expiry_reminder_sent_at is null
+                and coalesce(
+                    (j.closing_date + 1)::timestamptz,
+                    j.published_at + '30 days'::interval
+                ) <= current_timestamp + '3 days'::interval;
+                ",
+                &[],
+            )
+            .await?
+            .iter()
+            .map(|row| ExpiringJob {
+                closing_date: row.get("closing_date"),
+                job_id: row.get("job_id"),
+                recipients: row.get("recipients"),
+                title: row.get("title"),
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+
+    #[instrument(skip(self), err)]
+    async fn publish_scheduled_jobs(&self) -> Result<Vec<ScheduledJobPublished>> {
+        trace!("db: publish scheduled jobs");
+
+        let db = self.pool.get().await?;
+        let jobs = db
+            .query(
+                "
+                with due_job as (
+                    select job_id, first_published_at
+                    from job
+                    where status = 'scheduled'
+                    and publish_date <= current_date
+                    for update skip locked
+                )
+                update job j
+                set
+                    status = 'published',
+                    first_published_at = coalesce(j.first_published_at, current_timestamp),
+                    published_at = current_timestamp,
+                    expiry_reminder_sent_at = null
+                from due_job d
+                where j.job_id = d.job_id
+                returning
+                    j.job_id,
+                    d.first_published_at is null as first_published;
+                ",
+                &[],
+            )
+            .await?
+            .iter()
+            .map(|row| ScheduledJobPublished {
+                first_published: row.get("first_published"),
+                job_id: row.get("job_id"),
+            })
+            .collect();
+
+        Ok(jobs)
+    }
+}
+
+/// Saved search whose job alerts are due.
+#[derive(Debug, Clone)]
+pub(crate) struct DueSavedSearch {
+    /// Filters applied to the job search.
+    pub filters: Filters,
+    /// Name of the saved search.
+    pub name: String,
+    /// Whether the user wants to be notified (job alerts may be disabled).
+    pub notify: bool,
+    /// Unique identifier for the saved search.
+    pub saved_search_id: Uuid,
+    /// Only jobs published after this timestamp are new to the user.
+    pub since: DateTime<Utc>,
+    /// Timestamp when the saved search was retrieved.
+    pub until: DateTime<Utc>,
+    /// Identifier of the user who saved the search.
+    pub user_id: Uuid,
+}
+
+/// Published job that will expire soon.
+#[derive(Debug, Clone)]
+pub(crate) struct ExpiringJob {
+    /// Date when the job closes.
+    pub closing_date: NaiveDate,
+    /// Unique identifier for the job.
+    pub job_id: Uuid,
+    /// Identifiers of the users to remind (users who disabled these
+    /// reminders are not included).
+    pub recipients: Vec<Uuid>,
+    /// Job title.
+    pub title: String,
+}
+
+/// Scheduled job that has just been published.
+#[derive(Debug, Clone)]
+pub(crate) struct ScheduledJobPublished {
+    /// Whether the job has been published for the first time.
+    pub first_published: bool,
+    /// Unique identifier for the job.
+    pub job_id: Uuid,
 }
@@ -0,0 +1,3 @@
+//! This module defines the HTTP handlers for the public API.
+
+pub(crate) mod v1;
@@ -0,0 +1,572 @@
+//! HTTP handlers for the public jobs API.
+//
+// The payloads returned by these handlers are part of the public API contract, so they
+// must remain stable regardless of any changes made to the templates. They are defined
+// below and built from the templates types, which are never serialized directly.
+
+use anyhow::Result;
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_qs::axum::QsQuery;
+use serde_with::skip_serializing_none;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::{DynDB, jobboard::JobsSearchOutput},
+    handlers::{error::HandlerError, prepare_headers},
+    templates::{jobboard::jobs, misc},
+};
+
+/// Default number of jobs returned per page.
+const DEFAULT_LIMIT: usize = 20;
+
+/// Maximum number of jobs that can be requested per page.
+const MAX_LIMIT: usize = 100;
+
+/// Returns the published jobs that match the filters provided.
+#[instrument(skip_all, err)]
+pub(crate) async fn search(
+    State(db): State<DynDB>,
+    QsQuery(mut filters): QsQuery<jobs::Filters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Make sure the pagination values used are always explicit and bounded
+    let limit = filters.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let offset = filters.offset.unwrap_or(0);
+    filters.limit = Some(limit);
+    filters.offset = Some(offset);
+
+    // Get jobs that match the query
+    let JobsSearchOutput { jobs, total } = db.search_jobs(&filters).await?;
+
+    // Prepare response payload
+    let output = JobsPage {
+        jobs: jobs.into_iter().map(JobSummary::from).collect(),
+        limit,
+        offset,
+        total,
+        next_offset: (offset + limit < total).then_some(offset + limit),
+        prev_offset: (offset > 0).then(|| offset.saturating_sub(limit)),
+    };
+
+    // Prepare response headers
+    let headers = prepare_headers(Duration::minutes(10), &[])?;
+
+    Ok((headers, Json(output)))
+}
+
+/// Returns the details of a published job.
+#[instrument(skip_all, err)]
+pub(crate) async fn get(
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get job information
+    let Some(job) = db.get_job_jobboard(&job_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    // Prepare response headers
+    let headers = prepare_headers(Duration::minutes(10), &[])?;
+
+    Ok((headers, Json(Job::from(job))).into_response())
+}
+
+// Types.
+
+/// Page of jobs returned by the search endpoint, including pagination metadata.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JobsPage {
+    /// Jobs in the current page.
+    pub jobs: Vec<JobSummary>,
+    /// Maximum number of jobs in the page.
+    pub limit: usize,
+    /// Offset of the first job in the page.
+    pub offset: usize,
+    /// Total number of jobs matching the filters.
+    pub total: usize,
+
+    /// Offset of the next page, if any.
+    pub next_offset: Option<usize>,
+    /// Offset of the previous page, if any.
+    pub prev_offset: Option<usize>,
+}
+
+/// Summary of a published job.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct JobSummary {
+    /// Employer offering the job.
+    pub employer: Employer,
+    /// Unique identifier for the job.
+    pub job_id: Uuid,
+    /// Kind of job.
+    pub kind: String,
+    /// Timestamp when the job was published.
+    pub published_at: DateTime<Utc>,
+    /// Title of the job.
+    pub title: String,
+    /// Workplace type for the job.
+    pub workplace: String,
+
+    /// Location of the job, if specified.
+    pub location: Option<Location>,
+    /// Open source time percentage, if specified.
+    pub open_source: Option<i32>,
+    /// Projects related to the job, if any.
+    pub projects: Option<Vec<Project>>,
+    /// Salary value, if specified.
+    pub salary: Option<i64>,
+    /// Salary currency, if specified.
+    pub salary_currency: Option<String>,
+    /// Minimum salary, if specified.
+    pub salary_min: Option<i64>,
+    /// Maximum salary, if specified.
+    pub salary_max: Option<i64>,
+    /// Salary period, if specified.
+    pub salary_period: Option<String>,
+    /// Seniority level, if specified.
+    pub seniority: Option<String>,
+    /// Required skills, if any.
+    pub skills: Option<Vec<String>>,
+    /// Timestamp when the job was last updated, if any.
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Upstream commitment time percentage, if specified.
+    pub upstream_commitment: Option<i32>,
+}
+
+impl From<jobs::JobSummary> for JobSummary {
+    fn from(job: jobs::JobSummary) -> Self {
+        Self {
+            employer: job.employer.into(),
+            job_id: job.job_id,
+            kind: job.kind.to_string(),
+            published_at: job.published_at,
+            title: job.title,
+            workplace: job.workplace.to_string(),
+            location: job.location.map(Location::from),
+            open_source: job.open_source,
+            projects: job
+                .projects
+                .map(|projects| projects.into_iter().map(Project::from).collect()),
+            salary: job.salary,
+            salary_currency: job.salary_currency,
+            salary_min: job.salary_min,
+            salary_max: job.salary_max,
+            salary_period: job.salary_period,
+            seniority: job.seniority.map(|seniority| seniority.to_string()),
+            skills: job.skills,
+            updated_at: job.updated_at,
+            upstream_commitment: job.upstream_commitment,
+        }
+    }
+}
+
+/// Details of a published job.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Job {
+    /// Job description (markdown).
+    pub description: String,
+    /// Employer offering the job.
+    pub employer: Employer,
+    /// Unique identifier for the job.
+    pub job_id: Uuid,
+    /// Kind of job.
+    pub kind: String,
+    /// Title of the job.
+    pub title: String,
+    /// Workplace type for the job.
+    pub workplace: String,
+
+    /// Application instructions, if any.
+    pub apply_instructions: Option<String>,
+    /// Application URL, if any.
+    pub apply_url: Option<String>,
+    /// Benefits offered, if any.
+    pub benefits: Option<Vec<String>>,
+    /// Desired certifications, if any.
+    pub certifications: Option<Vec<Certification>>,
+    /// Location of the job, if specified.
+    pub location: Option<Location>,
+    /// Open source time percentage, if specified.
+    pub open_source: Option<i32>,
+    /// Projects related to the job, if any.
+    pub projects: Option<Vec<Project>>,
+    /// Timestamp when the job was published, if any.
+    pub published_at: Option<DateTime<Utc>>,
+    /// Required qualifications (markdown), if any.
+    pub qualifications: Option<String>,
+    /// Responsibilities (markdown), if any.
+    pub responsibilities: Option<String>,
+    /// Salary value, if specified.
+    pub salary: Option<i64>,
+    /// Salary currency, if specified.
+    pub salary_currency: Option<String>,
+    /// Minimum salary, if specified.
+    pub salary_min: Option<i64>,
+    /// Maximum salary, if specified.
+    pub salary_max: Option<i64>,
+    /// Salary period, if specified.
+    pub salary_period: Option<String>,
+    /// Seniority level, if specified.
+    pub seniority: Option<String>,
+    /// Required skills, if any.
+    pub skills: Option<Vec<String>>,
+    /// Timezone end, if any.
+    pub tz_end: Option<String>,
+    /// Timezone start, if any.
+    pub tz_start: Option<String>,
+    /// Timestamp when the job was last updated, if any.
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Upstream commitment time percentage, if specified.
+    pub upstream_commitment: Option<i32>,
+}
+
+impl From<jobs::Job> for Job {
+    fn from(job: jobs::Job) -> Self {
+        Self {
+            description: job.description,
+            employer: job.employer.into(),
+            job_id: job.job_id,
+            kind: job.kind.to_string(),
+            title: job.title,
+            workplace: job.workplace.to_string(),
+            apply_instructions: job.apply_instructions,
+            apply_url: job.apply_url,
+            benefits: job.benefits,
+            certifications: job
+                .certifications
+                .map(|certifications| certifications.into_iter().map(Certification::from).collect()),
+            location: job.location.map(Location::from),
+            open_source: job.open_source,
+            projects: job
+                .projects
+                .map(|projects| projects.into_iter().map(Project::from).collect()),
+            published_at: job.published_at,
+            qualifications: job.qualifications,
+            responsibilities: job.responsibilities,
+            salary: job.salary,
+            salary_currency: job.salary_currency,
+            salary_min: job.salary_min,
+            salary_max: job.salary_max,
+            salary_period: job.salary_period,
+            seniority: job.seniority.map(|seniority| seniority.to_string()),
+            skills: job.skills,
+            tz_end: job.tz_end,
+            tz_start: job.tz_start,
+            updated_at: job.updated_at,
+            upstream_commitment: job.upstream_commitment,
+        }
+    }
+}
+
+/// Employer offering a job.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Employer {
+    /// Name of the company.
+    pub company: String,
+    /// Unique identifier for the employer.
+    pub employer_id: Uuid,
+
+    /// Description of the employer, if any.
+    pub description: Option<String>,
+    /// Foundation membership of the employer, if any.
+    pub member: Option<Member>,
+    /// Website URL, if any.
+    pub website_url: Option<String>,
+}
+
+impl From<jobs::Employer> for Employer {
+    fn from(employer: jobs::Employer) -> Self {
+        Self {
+            company: employer.company,
+            employer_id: employer.employer_id,
+            description: employer.description,
+            member: employer.member.map(Member::from),
+            website_url: employer.website_url,
+        }
+    }
+}
+
+/// Certification desired for a job.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Certification {
+    /// Full name of the certification.
+    pub name: String,
+    /// Provider of the certification.
+    pub provider: String,
+    /// Short name or abbreviation.
+    pub short_name: String,
+
+    /// URL to the certification information, if any.
+    pub url: Option<String>,
+}
+
+impl From<misc::Certification> for Certification {
+    fn from(certification: misc::Certification) -> Self {
+        Self {
+            name: certification.name,
+            provider: certification.provider,
+            short_name: certification.short_name,
+            url: certification.url,
+        }
+    }
+}
+
+/// Location of a job.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Location {
+    /// City name.
+    pub city: String,
+    /// Country name.
+    pub country: String,
+
+    /// State or region, if any.
+    pub state: Option<String>,
+}
+
+impl From<misc::Location> for Location {
+    fn from(location: misc::Location) -> Self {
+        Self {
+            city: location.city,
+            country: location.country,
+            state: location.state,
+        }
+    }
+}
+
+/// Foundation membership of an employer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Member {
+    /// Foundation name.
+    pub foundation: String,
+    /// Membership level.
+    pub level: String,
+    /// Name of the member.
+    pub name: String,
+}
+
+impl From<misc::Member> for Member {
+    fn from(member: misc::Member) -> Self {
+        Self {
+            foundation: member.foundation,
+            level: member.level,
+            name: member.name,
+        }
+    }
+}
+
+/// Project related to a job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Project {
+    /// Foundation the project belongs to.
+    pub foundation: String,
+    /// Maturity level of the project.
+    pub maturity: String,
+    /// Name of the project.
+    pub name: String,
+}
+
+impl From<misc::Project> for Project {
+    fn from(project: misc::Project) -> Self {
+        Self {
+            foundation: project.foundation,
+            maturity: project.maturity,
+            name: project.name,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Router,
+        body::{Body, to_bytes},
+        http::{Request, StatusCode, header::CACHE_CONTROL},
+        routing::get,
+    };
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        db::{DynDB, jobboard::JobsSearchOutput, mock::MockDB},
+        templates::{
+            misc,
+            test_helpers::{create_base_job, create_job_summary},
+        },
+    };
+
+    const JOB_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    /// Send a GET request to the jobs API router, backed by the mock database
+    /// provided, returning the response status and its JSON body (if any).
+    async fn send_request(db: MockDB, uri: &str) -> (StatusCode, Option<Value>) {
+        let db: DynDB = Arc::new(db);
+        let router = Router::new()
+            .route("/jobs", get(super::search))
+            .route("/jobs/{job_id}", get(super::get))
+            .with_state(db);
+
+        let response = router
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        if status == StatusCode::OK {
+            assert!(response.headers().contains_key(CACHE_CONTROL));
+        }
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (status, serde_json::from_slice(&body).ok())
+    }
+
+    /// Setup a mock database that expects a jobs search with the pagination
+    /// values provided, returning one job and the total provided.
+    fn setup_search(limit: usize, offset: usize, total: usize) -> MockDB {
+        let mut db = MockDB::new();
+        db.expect_search_jobs()
+            .withf(move |filters| filters.limit == Some(limit) && filters.offset == Some(offset))
+            .times(1)
+            .returning(move |_| {
+                Ok(JobsSearchOutput {
+                    jobs: vec![create_job_summary(JOB_ID, "Software Engineer")],
+                    total,
+                })
+            });
+        db
+    }
+
+    #[tokio::test]
+    async fn test_search_response() {
+        let (status, body) = send_request(setup_search(20, 0, 25), "/jobs").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body.unwrap(),
+            json!({
+                "jobs": [{
+                    "employer": {
+                        "company": "ACME Corp",
+                        "employer_id": "550e8400-e29b-41d4-a716-446655440001"
+                    },
+                    "job_id": JOB_ID,
+                    "kind": "full-time",
+                    "published_at": "2025-01-15T10:00:00Z",
+                    "title": "Software Engineer",
+                    "workplace": "remote"
+                }],
+                "limit": 20,
+                "offset": 0,
+                "total": 25,
+                "next_offset": 20
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_last_page() {
+        let (status, body) = send_request(setup_search(10, 30, 35), "/jobs?limit=10&offset=30").await;
+
+        assert_eq!(status, StatusCode::OK);
+        let body = body.unwrap();
+        assert_eq!(body["limit"], 10);
+        assert_eq!(body["offset"], 30);
+        assert_eq!(body["prev_offset"], 20);
+        assert!(body.get("next_offset").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_search_limit_is_bounded() {
+        let (status, body) = send_request(setup_search(100, 0, 1), "/jobs?limit=1000").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.unwrap()["limit"], 100);
+
+        let (status, body) = send_request(setup_search(1, 0, 1), "/jobs?limit=0").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body.unwrap()["limit"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_invalid_query() {
+        for uri in ["/jobs?limit=abc", "/jobs?offset=-1", "/jobs?kind[0]=invalid"] {
+            let mut db = MockDB::new();
+            db.expect_search_jobs().never();
+
+            let (status, _) = send_request(db, uri).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_response() {
+        let mut job = create_base_job();
+        job.location = Some(misc::Location {
+            location_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002").unwrap(),
+            city: "San Francisco".to_string(),
+            country: "USA".to_string(),
+            state: Some("CA".to_string()),
+        });
+        job.skills = Some(vec!["rust".to_string()]);
+
+        let mut db = MockDB::new();
+        db.expect_get_job_jobboard()
+            .withf(|job_id| job_id.to_string() == JOB_ID)
+            .times(1)
+            .returning(move |_| Ok(Some(job.clone())));
+
+        let (status, body) = send_request(db, &format!("/jobs/{JOB_ID}")).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body.unwrap(),
+            json!({
+                "description": "Job description",
+                "employer": {
+                    "company": "ACME Corp",
+                    "employer_id": "550e8400-e29b-41d4-a716-446655440001"
+                },
+                "job_id": JOB_ID,
+                "kind": "full-time",
+                "title": "Software Engineer",
+                "workplace": "on-site",
+                "location": {
+                    "city": "San Francisco",
+                    "country": "USA",
+                    "state": "CA"
+                },
+                "skills": ["rust"]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_not_found() {
+        let mut db = MockDB::new();
+        db.expect_get_job_jobboard().times(1).returning(|_| Ok(None));
+
+        let (status, _) = send_request(db, &format!("/jobs/{JOB_ID}")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_invalid_job_id() {
+        let mut db = MockDB::new();
+        db.expect_get_job_jobboard().never();
+
+        let (status, _) = send_request(db, "/jobs/invalid").await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,3 @@
+//! This module defines the HTTP handlers for the version 1 of the public API.
+
+pub(crate) mod jobs;
@@ -7,7 +7,7 @@ use axum::{
     extract::{Path, Query, Request, State},
     http::StatusCode,
     middleware::Next,
-    response::{Html, IntoResponse, Redirect},
+    response::{Html, IntoResponse, Redirect, Response},
 };
 use axum_extra::extract::Form;
 use axum_messages::Messages;
@@ -24,10 +24,12 @@ use crate::{
     db::DynDB,
     handlers::{
         error::HandlerError,
-        extractors::{OAuth2, Oidc},
+        extractors::{OAuth2, Oidc, SelectedEmployerIdRequired},
     },
     notifications::{DynNotificationsManager, NewNotification, NotificationKind},
-    templates::{self, PageId, auth::User, notifications::EmailVerification},
+    templates::{
+        self, PageId, auth::User, dashboard::employer::team::EmployerRole, notifications::EmailVerification,
+    },
 };
 
 /// Key used to store the authentication provider in the session.
@@ -418,6 +420,27 @@ pub(crate) async fn update_user_details(
     Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
 }
 
+/// Handler that updates the user's email notifications settings.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_user_notifications_settings(
+    auth_session: AuthSession,
+    messages: Messages,
+    State(db): State<DynDB>,
+    Form(settings): Form<templates::auth::UserNotificationsSettings>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Update notifications settings in database
+    db.update_user_notifications_settings(&user.user_id, &settings)
+        .await?;
+    messages.success("Notifications settings updated successfully.");
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-body")]).into_response())
+}
+
 /// Handler that updates the user's password.
 #[instrument(skip_all, err)]
 pub(crate) async fn update_user_password(
@@ -513,6 +536,32 @@ pub(crate) async fn image_is_public(
     next.run(request).await.into_response()
 }
 
+/// Check if the user can delete or archive the job provided (admin role or
+/// higher in the employer's team).
+#[instrument(skip_all)]
+pub(crate) async fn user_can_delete_job(
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+    auth_session: AuthSession,
+    request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    check_job_role(&db, &job_id, auth_session, EmployerRole::Admin, request, next).await
+}
+
+/// Check if the user can edit or publish the job provided (recruiter role or
+/// higher in the employer's team).
+#[instrument(skip_all)]
+pub(crate) async fn user_can_edit_job(
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+    auth_session: AuthSession,
+    request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    check_job_role(&db, &job_id, auth_session, EmployerRole::Recruiter, request, next).await
+}
+
 /// Check if the user has access to the image provided.
 #[instrument(skip_all)]
 pub(crate) async fn user_has_image_access(
@@ -563,6 +612,48 @@ pub(crate) async fn user_has_profile_access(
     next.run(request).await.into_response()
 }
 
+/// Check if the user has the admin role or higher in the team of the selected
+/// employer.
+#[instrument(skip_all)]
+pub(crate) async fn user_is_employer_admin(
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    auth_session: AuthSession,
+    request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    check_employer_role(
+        &db,
+        &employer_id,
+        auth_session,
+        EmployerRole::Admin,
+        request,
+        next,
+    )
+    .await
+}
+
+/// Check if the user has the recruiter role or higher in the team of the
+/// selected employer.
+#[instrument(skip_all)]
+pub(crate) async fn user_is_employer_recruiter(
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    auth_session: AuthSession,
+    request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    check_employer_role(
+        &db,
+        &employer_id,
+        auth_session,
+        EmployerRole::Recruiter,
+        request,
+        next,
+    )
+    .await
+}
+
 /// Check if the user is a moderator.
 #[instrument(skip_all)]
 pub(crate) async fn user_is_moderator(
@@ -583,7 +674,7 @@ pub(crate) async fn user_is_moderator(
     next.run(request).await.into_response()
 }
 
-/// Check if the user owns the employer provided.
+/// Check if the user is an approved member of the employer's team.
 #[instrument(skip_all)]
 pub(crate) async fn user_owns_employer(
     State(db): State<DynDB>,
@@ -592,41 +683,76 @@ pub(crate) async fn user_owns_employer(
     request: Request,
     next: Next,
 ) -> impl IntoResponse {
+    check_employer_role(
+        &db,
+        &employer_id,
+        auth_session,
+        EmployerRole::Viewer,
+        request,
+        next,
+    )
+    .await
+}
+
+/// Check if the user is an approved member of the team of the employer that
+/// owns the job provided.
+#[instrument(skip_all)]
+pub(crate) async fn user_owns_job(
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+    auth_session: AuthSession,
+    request: Request,
+    next: Next,
+) -> impl IntoResponse {
+    check_job_role(&db, &job_id, auth_session, EmployerRole::Viewer, request, next).await
+}
+
+/// Check if the user has, at least, the role provided in the team of the
+/// employer.
+async fn check_employer_role(
+    db: &DynDB,
+    employer_id: &Uuid,
+    auth_session: AuthSession,
+    min_role: EmployerRole,
+    request: Request,
+    next: Next,
+) -> Response {
     // Check if user is logged in
     let Some(user) = auth_session.user else {
         return StatusCode::FORBIDDEN.into_response();
     };
 
-    // Check if the user owns the employer
-    let Ok(user_owns_employer) = db.user_owns_employer(&user.user_id, &employer_id).await else {
+    // Check if the user has the role required in the employer's team
+    let Ok(role) = db.get_user_employer_role(&user.user_id, employer_id).await else {
         return StatusCode::INTERNAL_SERVER_ERROR.into_response();
     };
-    if !user_owns_employer {
+    if role.is_none_or(|role| role < min_role) {
         return StatusCode::FORBIDDEN.into_response();
     }
 
     next.run(request).await.into_response()
 }
 
-/// Check if the user owns the job provided.
-#[instrument(skip_all)]
-pub(crate) async fn user_owns_job(
-    State(db): State<DynDB>,
-    Path(job_id): Path<Uuid>,
+/// Check if the user has, at least, the role provided in the team of the
+/// employer that owns the job.
+async fn check_job_role(
+    db: &DynDB,
+    job_id: &Uuid,
     auth_session: AuthSession,
+    min_role: EmployerRole,
     request: Request,
     next: Next,
-) -> impl IntoResponse {
+) -> Response {
     // Check if user is logged in
     let Some(user) = auth_session.user else {
         return StatusCode::FORBIDDEN.into_response();
     };
 
-    // Check if the user owns the job
-    let Ok(user_owns_job) = db.user_owns_job(&user.user_id, &job_id).await else {
+    // Check if the user has the role required in the job employer's team
+    let Ok(role) = db.get_user_job_role(&user.user_id, job_id).await else {
         return StatusCode::INTERNAL_SERVER_ERROR.into_response();
     };
-    if !user_owns_job {
+    if role.is_none_or(|role| role < min_role) {
         return StatusCode::FORBIDDEN.into_response();
     }
 
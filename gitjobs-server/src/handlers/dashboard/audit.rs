@@ -0,0 +1,99 @@
+//! HTTP handlers for the audit log pages in the employer and moderator dashboards.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse},
+};
+use serde_qs::axum::QsQuery;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::{DynDB, dashboard::audit::AuditLogSearchOutput},
+    handlers::{error::HandlerError, extractors::SelectedEmployerIdRequired},
+    templates::{
+        dashboard::audit::{AuditLogPage, Filters, Scope},
+        pagination::NavigationLinks,
+    },
+};
+
+// Pages handlers.
+
+/// Renders the audit log page for the selected employer.
+#[instrument(skip_all, err)]
+pub(crate) async fn employer_list_page(
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    QsQuery(filters): QsQuery<Filters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_page(&db, Some(employer_id), filters, Scope::Employer).await?;
+
+    Ok(Html(template.render()?))
+}
+
+/// Renders the audit log page for moderators, including all employers.
+#[instrument(skip_all, err)]
+pub(crate) async fn moderator_list_page(
+    State(db): State<DynDB>,
+    QsQuery(filters): QsQuery<Filters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = prepare_page(&db, None, filters, Scope::Moderator).await?;
+
+    Ok(Html(template.render()?))
+}
+
+// Helpers.
+
+/// Prepares the audit log page template for the scope provided.
+pub(crate) async fn prepare_page(
+    db: &DynDB,
+    employer_id: Option<Uuid>,
+    mut filters: Filters,
+    scope: Scope,
+) -> Result<AuditLogPage> {
+    filters.scope = scope;
+    let AuditLogSearchOutput { entries, total } = db.search_audit_log(employer_id.as_ref(), &filters).await?;
+    let navigation_links = NavigationLinks::from_filters(&filters, total)?;
+
+    Ok(AuditLogPage {
+        entries,
+        filters,
+        navigation_links,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+
+    use crate::{
+        db::{dashboard::audit::AuditLogSearchOutput, mock::MockDB},
+        handlers::test_helpers::{EMPLOYER_ID, build_request, send_request, setup_session},
+        templates::dashboard::audit::Scope,
+    };
+
+    #[tokio::test]
+    async fn test_employer_list_page_is_scoped_to_selected_employer() {
+        let mut db = MockDB::new();
+        let cookie = setup_session(&mut db);
+        db.expect_search_audit_log()
+            .withf(|employer_id, filters| {
+                employer_id.is_some_and(|id| id.to_string() == EMPLOYER_ID)
+                    && filters.scope == Scope::Employer
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(AuditLogSearchOutput {
+                    entries: vec![],
+                    total: 0,
+                })
+            });
+
+        let request = build_request(Method::GET, "/dashboard/employer/audit-log", &cookie, "");
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -3,26 +3,38 @@
 use anyhow::Result;
 use askama::Template;
 use axum::{
+    body::Body,
     extract::{Path, State},
+    http::header::{CONTENT_DISPOSITION, CONTENT_TYPE},
     response::{Html, IntoResponse},
 };
+use axum_extra::extract::Form;
+use futures::{StreamExt, TryStreamExt, future, stream};
 use reqwest::StatusCode;
 use serde_qs::axum::QsQuery;
-use tracing::instrument;
+use tracing::{error, instrument};
 use uuid::Uuid;
 
 use crate::{
+    auth::AuthSession,
+    config::HttpServerConfig,
     db::{DynDB, dashboard::employer::ApplicationsSearchOutput},
     handlers::{error::HandlerError, extractors::SelectedEmployerIdRequired},
     templates::{
         dashboard::{
-            employer::applications::{ApplicationsPage, Filters},
+            employer::applications::{
+                ApplicationRatingInput, ApplicationStatusInput, ApplicationsCsv, ApplicationsPage,
+                FeedbackSection, Filters, MAX_NOTE_LEN, NewApplicationNote,
+            },
             job_seeker,
         },
         pagination::NavigationLinks,
     },
 };
 
+/// Number of applications fetched from the database on each export batch.
+const EXPORT_BATCH_SIZE: usize = 500;
+
 // Pages handlers.
 
 /// Renders the applications list page for the selected employer.
@@ -50,10 +62,106 @@ pub(crate) async fn list_page(
     Ok(Html(template.render()?))
 }
 
+/// Exports all the applications received by the selected employer that match
+/// the filters provided as CSV. Applications are fetched and streamed in batches.
+#[instrument(skip_all, err)]
+pub(crate) async fn export_csv(
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    QsQuery(mut filters): QsQuery<Filters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // The export includes all matching applications, so the pagination
+    // options provided are ignored. Batches are paginated with a keyset, so
+    // that applications added during the export don't shift them
+    filters.limit = Some(EXPORT_BATCH_SIZE);
+    filters.offset = None;
+
+    // Get the first batch before starting the response, so that errors can
+    // still be reported with the appropriate status code
+    let base_url = cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string();
+    let (first_batch, next_filters) =
+        export_csv_batch(db.clone(), employer_id, base_url.clone(), filters).await?;
+
+    // Prepare stream that writes the remaining applications in batches. Errors
+    // are passed to the body, aborting the response, so that the download
+    // fails instead of producing a truncated file
+    let next_batches = stream::try_unfold(next_filters, move |filters| {
+        let base_url = base_url.clone();
+        let db = db.clone();
+        async move {
+            let Some(filters) = filters else {
+                return Ok(None);
+            };
+            export_csv_batch(db, employer_id, base_url, filters).await.map(Some)
+        }
+    })
+    .inspect_err(|err| error!(?err, "error exporting applications"));
+    let batches = stream::once(future::ready(Ok(first_batch))).chain(next_batches);
+
+    // Prepare response headers
+    let headers = [
+        (CONTENT_TYPE, "text/csv; charset=utf-8"),
+        (CONTENT_DISPOSITION, r#"attachment; filename="applications.csv""#),
+    ];
+
+    Ok((headers, Body::from_stream(batches)))
+}
+
+/// Writes the batch of applications selected by the filters provided as CSV.
+/// Returns the filters to get the next batch, if any.
+async fn export_csv_batch(
+    db: DynDB,
+    employer_id: Uuid,
+    base_url: String,
+    filters: Filters,
+) -> Result<(Vec<u8>, Option<Filters>)> {
+    // Get batch of applications
+    let ApplicationsSearchOutput { applications, .. } =
+        db.search_applications(&employer_id, &filters).await?;
+
+    // Prepare filters for the next batch, if any
+    let include_header = filters.after.is_none();
+    let next_filters = match applications.last() {
+        Some(last) if applications.len() == EXPORT_BATCH_SIZE => Some(Filters {
+            after: Some(last.keyset()),
+            ..filters
+        }),
+        _ => None,
+    };
+
+    // Write batch
+    let batch = ApplicationsCsv {
+        applications,
+        base_url,
+        include_header,
+    }
+    .write()?;
+
+    Ok((batch, next_filters))
+}
+
+/// Renders the team feedback section of a job seeker's profile preview.
+#[instrument(skip_all, err)]
+pub(crate) async fn feedback_section(
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    Path(profile_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let applications = db.list_applications_feedback(&employer_id, &profile_id).await?;
+    let template = FeedbackSection {
+        applications,
+        profile_id,
+    };
+
+    Ok(Html(template.render()?))
+}
+
 /// Renders the page to preview a job seeker's profile for employers.
 #[instrument(skip_all, err)]
 pub(crate) async fn profile_preview_page(
     State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
     Path(profile_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, HandlerError> {
     let Some(user_id) = db.get_job_seeker_user_id(&profile_id).await? else {
@@ -62,7 +170,494 @@ pub(crate) async fn profile_preview_page(
     let Some(profile) = db.get_job_seeker_profile(&user_id).await? else {
         return Ok(StatusCode::NOT_FOUND.into_response());
     };
-    let template = job_seeker::profile::PreviewPage { profile };
+    let applications = db.list_applications_feedback(&employer_id, &profile_id).await?;
+    let template = job_seeker::profile::PreviewPage {
+        profile,
+        feedback: Some(FeedbackSection {
+            applications,
+            profile_id,
+        }),
+    };
 
     Ok(Html(template.render()?).into_response())
 }
+
+// Actions handlers.
+
+/// Adds a team note to an application received by the selected employer.
+#[instrument(skip_all, err)]
+pub(crate) async fn add_note(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    Path(profile_id): Path<Uuid>,
+    Form(mut note): Form<NewApplicationNote>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Validate input
+    note.content = note.content.trim().to_string();
+    if note.content.is_empty() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "note content is required").into_response());
+    }
+    if note.content.chars().count() > MAX_NOTE_LEN {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("note content must be at most {MAX_NOTE_LEN} characters"),
+        )
+            .into_response());
+    }
+
+    // Add note to database
+    db.add_application_note(&employer_id, &profile_id, &user.user_id, &note)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [("HX-Trigger", "refresh-applicant-feedback")],
+    )
+        .into_response())
+}
+
+/// Updates the team rating of an application received by the selected employer.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_rating(
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    Path(profile_id): Path<Uuid>,
+    Form(rating): Form<ApplicationRatingInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Validate input
+    if rating.rating.is_some_and(|rating| !(1..=5).contains(&rating)) {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "rating must be between 1 and 5").into_response());
+    }
+
+    // Update rating in database
+    db.update_application_rating(&employer_id, &profile_id, &rating)
+        .await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-applicant-feedback")],
+    )
+        .into_response())
+}
+
+/// Updates the status of an application received by the selected employer.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_status(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    Path(application_id): Path<Uuid>,
+    Form(input): Form<ApplicationStatusInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Update application status
+    if !db
+        .update_application_status(&employer_id, &application_id, &input.status, &user.user_id)
+        .await?
+    {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-applications-list")],
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        http::{Method, StatusCode},
+        response::Response,
+    };
+    use chrono::{Duration, TimeZone, Utc};
+    use mockall::{Sequence, predicate::eq};
+    use uuid::Uuid;
+
+    use crate::{
+        db::{dashboard::employer::ApplicationsSearchOutput, mock::MockDB},
+        handlers::test_helpers::{
+            EMPLOYER_ID, USER_ID, build_request, read_body, send_request, setup_session,
+        },
+        templates::{
+            dashboard::employer::{
+                applications::{ApplicationStatus, MAX_NOTE_LEN},
+                team::EmployerRole,
+            },
+            test_helpers::create_application,
+        },
+    };
+
+    use super::EXPORT_BATCH_SIZE;
+
+    const APPLICATION_ID: &str = "550e8400-e29b-41d4-a716-446655440030";
+    const PROFILE_ID: &str = "550e8400-e29b-41d4-a716-446655440040";
+
+    /// Setup a mock database with a session for the test user, who has the
+    /// role provided in the employer's team. Returns the session cookie too.
+    fn setup_db(role: EmployerRole) -> (MockDB, String) {
+        let mut db = MockDB::new();
+        let cookie = setup_session(&mut db);
+        db.expect_get_user_employer_role()
+            .with(
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+                eq(Uuid::parse_str(EMPLOYER_ID).unwrap()),
+            )
+            .returning(move |_, _| Ok(Some(role)));
+        (db, cookie)
+    }
+
+    /// Setup a mock database like `setup_db`, where the test user has access
+    /// to the test job seeker profile.
+    fn setup_db_with_profile_access(role: EmployerRole) -> (MockDB, String) {
+        let (mut db, cookie) = setup_db(role);
+        db.expect_user_has_profile_access()
+            .with(
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+                eq(Uuid::parse_str(PROFILE_ID).unwrap()),
+            )
+            .returning(|_, _| Ok(true));
+        (db, cookie)
+    }
+
+    /// Send a request to add a note with the content provided.
+    async fn send_add_note_request(db: MockDB, cookie: &str, content: &str) -> Response {
+        let form =
+            serde_html_form::to_string([("application_id", APPLICATION_ID), ("content", content)]).unwrap();
+        let request = build_request(
+            Method::POST,
+            &format!("/dashboard/employer/applications/profile/{PROFILE_ID}/notes"),
+            cookie,
+            &form,
+        );
+        send_request(db, request).await
+    }
+
+    /// Send a request to update the rating with the value provided.
+    async fn send_update_rating_request(db: MockDB, cookie: &str, rating: &str) -> Response {
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/applications/profile/{PROFILE_ID}/rating"),
+            cookie,
+            &format!("application_id={APPLICATION_ID}&rating={rating}"),
+        );
+        send_request(db, request).await
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_pages_through_batches() {
+        let mut db = MockDB::new();
+        let cookie = setup_session(&mut db);
+
+        // Prepare applications, one more than fits in a batch
+        let applied_at = Utc.with_ymd_and_hms(2025, 1, 15, 10, 0, 0).unwrap();
+        let applications: Vec<_> = (0..)
+            .take(EXPORT_BATCH_SIZE + 1)
+            .map(|i| create_application(Uuid::new_v4(), applied_at - Duration::minutes(i)))
+            .collect();
+        let last_in_first_batch = applications[EXPORT_BATCH_SIZE - 1].keyset();
+
+        // Setup the expected searches, both scoped to the selected employer
+        let mut seq = Sequence::new();
+        let first_batch = applications[..EXPORT_BATCH_SIZE].to_vec();
+        db.expect_search_applications()
+            .withf(|employer_id, filters| {
+                employer_id.to_string() == EMPLOYER_ID
+                    && filters.after.is_none()
+                    && filters.limit == Some(EXPORT_BATCH_SIZE)
+                    && filters.offset.is_none()
+                    && filters.status == Some(ApplicationStatus::New)
+            })
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |_, _| {
+                Ok(ApplicationsSearchOutput {
+                    applications: first_batch.clone(),
+                    total: EXPORT_BATCH_SIZE + 1,
+                })
+            });
+        let second_batch = applications[EXPORT_BATCH_SIZE..].to_vec();
+        db.expect_search_applications()
+            .withf(move |employer_id, filters| {
+                employer_id.to_string() == EMPLOYER_ID
+                    && filters.after.as_ref() == Some(&last_in_first_batch)
+                    && filters.limit == Some(EXPORT_BATCH_SIZE)
+                    && filters.offset.is_none()
+                    && filters.status == Some(ApplicationStatus::New)
+            })
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(move |_, _| {
+                Ok(ApplicationsSearchOutput {
+                    applications: second_batch.clone(),
+                    total: EXPORT_BATCH_SIZE + 1,
+                })
+            });
+
+        let request = build_request(
+            Method::GET,
+            "/dashboard/employer/applications/export.csv?status=new&limit=10&offset=20",
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = read_body(response).await;
+        let lines: Vec<_> = body.lines().collect();
+        assert_eq!(lines.len(), EXPORT_BATCH_SIZE + 2);
+        assert!(lines[0].starts_with("name,email,"));
+        assert_eq!(lines.iter().filter(|l| l.starts_with("name,email,")).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_single_batch() {
+        let mut db = MockDB::new();
+        let cookie = setup_session(&mut db);
+        db.expect_search_applications()
+            .withf(|employer_id, filters| employer_id.to_string() == EMPLOYER_ID && filters.after.is_none())
+            .times(1)
+            .returning(|_, _| {
+                Ok(ApplicationsSearchOutput {
+                    applications: vec![create_application(Uuid::new_v4(), Utc::now())],
+                    total: 1,
+                })
+            });
+
+        let request = build_request(
+            Method::GET,
+            "/dashboard/employer/applications/export.csv",
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(read_body(response).await.lines().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_add_note_requires_recruiter() {
+        let (mut db, cookie) = setup_db(EmployerRole::Viewer);
+        db.expect_add_application_note().never();
+
+        let request = build_request(
+            Method::POST,
+            &format!("/dashboard/employer/applications/profile/{PROFILE_ID}/notes"),
+            &cookie,
+            "content=note",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_update_rating_requires_recruiter() {
+        let (mut db, cookie) = setup_db(EmployerRole::Viewer);
+        db.expect_update_application_rating().never();
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/applications/profile/{PROFILE_ID}/rating"),
+            &cookie,
+            "rating=3",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_update_status_requires_recruiter() {
+        let (mut db, cookie) = setup_db(EmployerRole::Viewer);
+        db.expect_update_application_status().never();
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/applications/{APPLICATION_ID}/status"),
+            &cookie,
+            "status=shortlisted",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_update_status() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_update_application_status()
+            .with(
+                eq(Uuid::parse_str(EMPLOYER_ID).unwrap()),
+                eq(Uuid::parse_str(APPLICATION_ID).unwrap()),
+                eq(ApplicationStatus::Interviewing),
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(true));
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/applications/{APPLICATION_ID}/status"),
+            &cookie,
+            "status=interviewing",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            response.headers().get("HX-Trigger").unwrap(),
+            "refresh-applications-list"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_status_unknown_status() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_update_application_status().never();
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/applications/{APPLICATION_ID}/status"),
+            &cookie,
+            "status=archived",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_update_status_application_not_found() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_update_application_status()
+            .times(1)
+            .returning(|_, _, _, _| Ok(false));
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/applications/{APPLICATION_ID}/status"),
+            &cookie,
+            "status=rejected",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert!(response.headers().get("HX-Trigger").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_add_note() {
+        let (mut db, cookie) = setup_db_with_profile_access(EmployerRole::Recruiter);
+        db.expect_add_application_note()
+            .withf(|employer_id, profile_id, user_id, note| {
+                employer_id.to_string() == EMPLOYER_ID
+                    && profile_id.to_string() == PROFILE_ID
+                    && user_id.to_string() == USER_ID
+                    && note.application_id.to_string() == APPLICATION_ID
+                    && note.content == "Great candidate"
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let response = send_add_note_request(db, &cookie, "  Great candidate ").await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            response.headers().get("HX-Trigger").unwrap(),
+            "refresh-applicant-feedback"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_note_max_length() {
+        let (mut db, cookie) = setup_db_with_profile_access(EmployerRole::Recruiter);
+        db.expect_add_application_note()
+            .withf(|_, _, _, note| note.content.chars().count() == MAX_NOTE_LEN)
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let response = send_add_note_request(db, &cookie, &"é".repeat(MAX_NOTE_LEN)).await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_add_note_empty() {
+        let (mut db, cookie) = setup_db_with_profile_access(EmployerRole::Recruiter);
+        db.expect_add_application_note().never();
+
+        let response = send_add_note_request(db, &cookie, "   ").await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_add_note_too_long() {
+        let (mut db, cookie) = setup_db_with_profile_access(EmployerRole::Recruiter);
+        db.expect_add_application_note().never();
+
+        let response = send_add_note_request(db, &cookie, &"a".repeat(MAX_NOTE_LEN + 1)).await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_update_rating() {
+        let (mut db, cookie) = setup_db_with_profile_access(EmployerRole::Recruiter);
+        db.expect_update_application_rating()
+            .withf(|employer_id, profile_id, rating| {
+                employer_id.to_string() == EMPLOYER_ID
+                    && profile_id.to_string() == PROFILE_ID
+                    && rating.application_id.to_string() == APPLICATION_ID
+                    && rating.rating == Some(5)
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let response = send_update_rating_request(db, &cookie, "5").await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            response.headers().get("HX-Trigger").unwrap(),
+            "refresh-applicant-feedback"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_rating_cleared() {
+        let (mut db, cookie) = setup_db_with_profile_access(EmployerRole::Recruiter);
+        db.expect_update_application_rating()
+            .withf(|_, _, rating| rating.rating.is_none())
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let response = send_update_rating_request(db, &cookie, "").await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_update_rating_out_of_range() {
+        for rating in ["0", "6"] {
+            let (mut db, cookie) = setup_db_with_profile_access(EmployerRole::Recruiter);
+            db.expect_update_application_rating().never();
+
+            let response = send_update_rating_request(db, &cookie, rating).await;
+
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY, "{rating}");
+        }
+    }
+}
@@ -108,12 +108,18 @@ pub(crate) async fn select(
 /// Updates an existing employer's information in the database.
 #[instrument(skip_all, err)]
 pub(crate) async fn update(
+    auth_session: AuthSession,
     messages: Messages,
     State(db): State<DynDB>,
     State(serde_qs_de): State<serde_qs::Config>,
     SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
     body: String,
 ) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
     // Get employer information from body
     let employer: Employer = match serde_qs_de.deserialize_str(&body).map_err(anyhow::Error::new) {
         Ok(profile) => profile,
@@ -121,7 +127,7 @@ pub(crate) async fn update(
     };
 
     // Update employer in database
-    db.update_employer(&employer_id, &employer).await?;
+    db.update_employer(&employer_id, &employer, &user.user_id).await?;
     messages.success("Employer updated successfully.");
 
     Ok((
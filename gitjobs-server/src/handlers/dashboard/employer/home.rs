@@ -18,13 +18,19 @@ use crate::{
     auth::AuthSession,
     config::HttpServerConfig,
     db::{DynDB, dashboard::employer::ApplicationsSearchOutput},
-    handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError, extractors::SelectedEmployerIdOptional},
+    handlers::{
+        auth::AUTH_PROVIDER_KEY, dashboard::audit::prepare_page, error::HandlerError,
+        extractors::SelectedEmployerIdOptional,
+    },
     templates::{
         PageId, auth,
-        dashboard::employer::{
-            applications, employers,
-            home::{self, Content, Tab},
-            jobs, team,
+        dashboard::{
+            audit::{self, Scope},
+            employer::{
+                applications, employers,
+                home::{self, Content, Tab},
+                jobs, team,
+            },
         },
         pagination::NavigationLinks,
     },
@@ -47,6 +53,7 @@ pub(crate) async fn page(
     State(cfg): State<HttpServerConfig>,
     Query(query): Query<HashMap<String, String>>,
     QsQuery(filters): QsQuery<applications::Filters>,
+    QsQuery(audit_filters): QsQuery<audit::Filters>,
     SelectedEmployerIdOptional(employer_id): SelectedEmployerIdOptional,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get user from session
@@ -73,8 +80,12 @@ pub(crate) async fn page(
     // Prepare content for the selected tab
     let content = match tab {
         Tab::Account => {
+            let notifications_settings = db.get_user_notifications_settings(&user.user_id).await?;
             let user_summary = user.clone().into();
-            Content::Account(auth::UpdateUserPage { user_summary })
+            Content::Account(auth::UpdateUserPage {
+                notifications_settings,
+                user_summary,
+            })
         }
         Tab::Applications => {
             let employer_id = employer_id.expect("to be some");
@@ -90,6 +101,16 @@ pub(crate) async fn page(
                 navigation_links,
             })
         }
+        Tab::AuditLog => {
+            let template = prepare_page(
+                &db,
+                Some(employer_id.expect("to be some")),
+                audit_filters,
+                Scope::Employer,
+            )
+            .await?;
+            Content::AuditLog(template)
+        }
         Tab::EmployerInitialSetup => Content::EmployerInitialSetup(employers::InitialSetupPage {}),
         Tab::Invitations => {
             let invitations = db.list_user_invitations(&user.user_id).await?;
@@ -108,11 +129,15 @@ pub(crate) async fn page(
             })
         }
         Tab::Team => {
-            let members = db.list_team_members(&employer_id.expect("to be some")).await?;
-            Content::Team(team::MembersListPage {
-                approved_members_count: members.iter().filter(|m| m.approved).count(),
-                members,
-            })
+            let employer_id = employer_id.expect("to be some");
+            let (members, user_role) = tokio::try_join!(
+                db.list_team_members(&employer_id),
+                db.get_user_employer_role(&user.user_id, &employer_id)
+            )?;
+            let Some(user_role) = user_role else {
+                return Ok(StatusCode::FORBIDDEN.into_response());
+            };
+            Content::Team(team::MembersListPage::new(members, user.user_id, user_role))
         }
     };
 
@@ -1,26 +1,43 @@
 //! HTTP handlers for employer job management pages and actions.
 //
-// This module provides handlers for adding, listing, previewing, updating, archiving,
-// deleting, and publishing jobs for employers in the dashboard. It also renders the
-// corresponding Askama templates for each page.
+// This module provides handlers for adding, importing, listing, previewing, updating,
+// archiving, deleting, duplicating, publishing, and renewing jobs for employers in the
+// dashboard.
+// It also renders the corresponding Askama templates for each page.
 
 use anyhow::Result;
 use askama::Template;
 use axum::{
-    extract::{Path, State},
+    extract::{Multipart, Path, State},
     http::StatusCode,
     response::{Html, IntoResponse, Json},
 };
+use axum_messages::Messages;
 use chrono::{Duration, Utc};
+use tower_sessions::Session;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
+    auth::AuthSession,
+    config::HttpServerConfig,
     db::DynDB,
-    handlers::{error::HandlerError, extractors::SelectedEmployerIdRequired, prepare_headers},
-    templates::dashboard::employer::jobs::{self, Job, JobStatus},
+    handlers::{
+        auth::AUTH_PROVIDER_KEY, error::HandlerError, extractors::SelectedEmployerIdRequired, prepare_headers,
+    },
+    templates::{
+        PageId,
+        dashboard::employer::jobs::{self, ImportRowError, ImportedJob, Job, JobStatus},
+        helpers::DATE_FORMAT,
+    },
 };
 
+/// Maximum size allowed for job import files, in bytes (1MB).
+pub(crate) const MAX_IMPORT_FILE_SIZE: usize = 1024 * 1024;
+
+/// Maximum number of jobs that can be imported at once.
+const MAX_IMPORT_ROWS: usize = 100;
+
 // Pages handlers.
 
 /// Renders the page to add a new job for an employer.
@@ -35,6 +52,15 @@ pub(crate) async fn add_page(State(db): State<DynDB>) -> Result<impl IntoRespons
     Ok(Html(template.render()?))
 }
 
+/// Renders the page to import jobs from a file for an employer.
+#[instrument(skip_all, err)]
+pub(crate) async fn import_page(State(db): State<DynDB>) -> Result<impl IntoResponse, HandlerError> {
+    let foundations = db.list_foundations().await?;
+    let template = jobs::ImportPage { foundations };
+
+    Ok(Html(template.render()?))
+}
+
 /// Renders the jobs list page for the selected employer.
 #[instrument(skip_all, err)]
 pub(crate) async fn list_page(
@@ -84,6 +110,27 @@ pub(crate) async fn preview_page_wo_job(
     Ok(Html(template.render()?).into_response())
 }
 
+/// Renders the page to confirm the renewal of a job. It's linked from the
+/// expiry reminder emails.
+#[instrument(skip_all, err)]
+pub(crate) async fn renew_page(
+    auth_session: AuthSession,
+    session: Session,
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    let template = jobs::RenewPage {
+        auth_provider: session.get(AUTH_PROVIDER_KEY).await?,
+        cfg: cfg.into(),
+        job: db.get_job_dashboard(&job_id).await?,
+        page_id: PageId::EmployerDashboard,
+        user: auth_session.into(),
+    };
+
+    Ok(Html(template.render()?).into_response())
+}
+
 /// Renders the page to update an existing job.
 #[instrument(skip_all, err)]
 pub(crate) async fn update_page(
@@ -109,11 +156,17 @@ pub(crate) async fn update_page(
 /// Adds a new job for the selected employer.
 #[instrument(skip_all, err)]
 pub(crate) async fn add(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
     State(serde_qs_de): State<serde_qs::Config>,
     SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
     body: String,
 ) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
     // Get job information from body
     let mut job: Job = match serde_qs_de.deserialize_str(&body).map_err(anyhow::Error::new) {
         Ok(profile) => profile,
@@ -126,43 +179,237 @@ pub(crate) async fn add(
         return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid status").into_response());
     }
 
+    // Make sure the screening questions provided are valid
+    if !job.screening_questions_are_valid() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid screening questions").into_response());
+    }
+
+    // Make sure the publish and closing dates provided are valid
+    if !job.dates_are_valid() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid dates").into_response());
+    }
+
     // Add job to database
-    db.add_job(&employer_id, &job).await?;
+    db.add_job(&employer_id, &job, &user.user_id).await?;
 
     Ok((StatusCode::CREATED, [("HX-Trigger", "refresh-jobs-table")]).into_response())
 }
 
+/// Imports jobs from a JSON or CSV file for the selected employer. Every row is
+/// validated, the valid ones are added and the errors found in the rest are
+/// reported back in the results page.
+#[instrument(skip_all, err)]
+pub(crate) async fn import(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Get file, status and foundation from the multipart form data
+    let (mut file, mut status, mut foundation) = (None, None, None);
+    while let Ok(Some(field)) = multipart.next_field().await {
+        let name = field.name().unwrap_or_default().to_string();
+        let file_name = field.file_name().unwrap_or_default().to_string();
+        let Ok(data) = field.bytes().await else {
+            return Ok(StatusCode::BAD_REQUEST.into_response());
+        };
+        match name.as_str() {
+            "file" => file = Some((file_name, data)),
+            "foundation" => foundation = Some(String::from_utf8_lossy(&data).to_string()),
+            "status" => status = String::from_utf8_lossy(&data).parse::<JobStatus>().ok(),
+            _ => {}
+        }
+    }
+    let Some((file_name, data)) = file else {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "file is required").into_response());
+    };
+
+    // Validate file size and status
+    if data.len() > MAX_IMPORT_FILE_SIZE {
+        return Ok((StatusCode::PAYLOAD_TOO_LARGE, "file must not exceed 1MB").into_response());
+    }
+    let Some(status @ (JobStatus::Draft | JobStatus::PendingApproval)) = status else {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid status").into_response());
+    };
+
+    // Parse jobs from file
+    let rows = match ImportedJob::parse_file(&file_name, &data) {
+        Ok(rows) => rows,
+        Err(err) => return Ok((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()).into_response()),
+    };
+    if rows.is_empty() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "file does not contain any jobs").into_response());
+    }
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("file must not contain more than {MAX_IMPORT_ROWS} jobs"),
+        )
+            .into_response());
+    }
+
+    // Get the projects and certifications jobs can refer to
+    let projects = match foundation.filter(|foundation| !foundation.is_empty()) {
+        Some(foundation) => db.list_projects(&foundation).await?,
+        None => vec![],
+    };
+    let certifications = db.list_certifications().await?;
+
+    // Validate rows, collecting the valid jobs
+    let mut valid_jobs = vec![];
+    let mut errors = vec![];
+    for (i, row) in rows.into_iter().enumerate() {
+        let row_error = |title: Option<String>, errors: Vec<String>| ImportRowError {
+            errors,
+            row: i + 1,
+            title,
+        };
+        let imported_job = match row {
+            Ok(imported_job) => imported_job,
+            Err(err) => {
+                errors.push(row_error(None, vec![err]));
+                continue;
+            }
+        };
+        let title = imported_job.title.clone();
+        match imported_job.into_job(status.clone(), &projects, &certifications) {
+            Ok(mut job) => {
+                job.normalize().await;
+                valid_jobs.push(job);
+            }
+            Err(row_errors) => errors.push(row_error(Some(title), row_errors)),
+        }
+    }
+
+    // Add the valid jobs to the database (all of them or none)
+    db.add_jobs(&employer_id, &valid_jobs, &user.user_id).await?;
+
+    // Prepare template
+    let template = jobs::ImportResultsPage {
+        created: valid_jobs.len(),
+        errors,
+        status,
+    };
+
+    Ok(Html(template.render()?).into_response())
+}
+
 /// Archives a job, making it inactive but not deleting it.
 #[instrument(skip_all, err)]
 pub(crate) async fn archive(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
     Path(job_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    db.archive_job(&job_id).await?;
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    db.archive_job(&job_id, &user.user_id).await?;
 
-    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-jobs-table")]))
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-jobs-table")]).into_response())
 }
 
 /// Permanently deletes a job from the database.
 #[instrument(skip_all, err)]
 pub(crate) async fn delete(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    db.delete_job(&job_id, &user.user_id).await?;
+
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-jobs-table")]).into_response())
+}
+
+/// Creates a new draft job from a copy of an existing one, and takes the user
+/// to the update page of the copy.
+#[instrument(skip_all, err)]
+pub(crate) async fn duplicate(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
     Path(job_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    db.delete_job(&job_id).await?;
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Duplicate job
+    let Some(new_job_id) = db.duplicate_job(&job_id, &user.user_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
 
-    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-jobs-table")]))
+    Ok((
+        StatusCode::CREATED,
+        [(
+            "HX-Location",
+            format!(r##"{{"path":"/dashboard/employer/jobs/{new_job_id}/update", "target":"#dashboard-content"}}"##),
+        )],
+    )
+        .into_response())
 }
 
 /// Publishes a job. It'll be visible to users once it's approved.
 #[instrument(skip_all, err)]
 pub(crate) async fn publish(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
     Path(job_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    db.publish_job(&job_id).await?;
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    db.publish_job(&job_id, &user.user_id).await?;
 
-    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-jobs-table")]))
+    Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-jobs-table")]).into_response())
+}
+
+/// Renews a published job, extending its closing date. It's requested from
+/// the renewal confirmation page, so it redirects to the jobs list once done.
+#[instrument(skip_all, err)]
+pub(crate) async fn renew(
+    auth_session: AuthSession,
+    messages: Messages,
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Renew job
+    if let Some(closing_date) = db.renew_job(&job_id, &user.user_id).await? {
+        messages.success(format!(
+            "Job renewed successfully. It'll close on {}.",
+            closing_date.format(DATE_FORMAT)
+        ));
+    } else {
+        messages.error("Only published jobs can be renewed.");
+    }
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [(
+            "HX-Location",
+            r#"{"path":"/dashboard/employer?tab=jobs", "target":"body"}"#,
+        )],
+    )
+        .into_response())
 }
 
 /// Returns statistics for a specific job.
@@ -183,11 +430,17 @@ pub(crate) async fn stats(
 /// Updates an existing job with new data.
 #[instrument(skip_all, err)]
 pub(crate) async fn update(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
     State(serde_qs_de): State<serde_qs::Config>,
     Path(job_id): Path<Uuid>,
     body: String,
 ) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
     // Get job information from body
     let mut job: Job = match serde_qs_de.deserialize_str(&body).map_err(anyhow::Error::new) {
         Ok(profile) => profile,
@@ -203,8 +456,171 @@ pub(crate) async fn update(
         return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid status").into_response());
     }
 
+    // Make sure the screening questions provided are valid
+    if !job.screening_questions_are_valid() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid screening questions").into_response());
+    }
+
+    // Make sure the publish and closing dates provided are valid
+    if !job.dates_are_valid() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid dates").into_response());
+    }
+
     // Update job in database
-    db.update_job(&job_id, &job).await?;
+    db.update_job(&job_id, &job, &user.user_id).await?;
 
     Ok((StatusCode::NO_CONTENT, [("HX-Trigger", "refresh-jobs-table")]).into_response())
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::test_helpers::{USER_ID, build_request, send_request, setup_session},
+        templates::dashboard::employer::team::EmployerRole,
+    };
+
+    const JOB_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    /// Setup a mock database with a session for the test user, who has the
+    /// role provided in the team of the employer that owns the job. Returns
+    /// the session cookie too.
+    fn setup_db(role: EmployerRole) -> (MockDB, String) {
+        let mut db = MockDB::new();
+        let cookie = setup_session(&mut db);
+        db.expect_get_user_job_role()
+            .with(
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+                eq(Uuid::parse_str(JOB_ID).unwrap()),
+            )
+            .returning(move |_, _| Ok(Some(role)));
+        (db, cookie)
+    }
+
+    #[tokio::test]
+    async fn test_archive_requires_admin() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_archive_job().never();
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/jobs/{JOB_ID}/archive"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_archive() {
+        let (mut db, cookie) = setup_db(EmployerRole::Admin);
+        db.expect_archive_job()
+            .with(
+                eq(Uuid::parse_str(JOB_ID).unwrap()),
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/jobs/{JOB_ID}/archive"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_delete_requires_admin() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_delete_job().never();
+
+        let request = build_request(
+            Method::DELETE,
+            &format!("/dashboard/employer/jobs/{JOB_ID}/delete"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_delete_requires_team_member() {
+        let mut db = MockDB::new();
+        let cookie = setup_session(&mut db);
+        db.expect_get_user_job_role().returning(|_, _| Ok(None));
+        db.expect_delete_job().never();
+
+        let request = build_request(
+            Method::DELETE,
+            &format!("/dashboard/employer/jobs/{JOB_ID}/delete"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_duplicate_job()
+            .with(
+                eq(Uuid::parse_str(JOB_ID).unwrap()),
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _| {
+                Ok(Some(
+                    Uuid::parse_str("550e8400-e29b-41d4-a716-446655440050").unwrap(),
+                ))
+            });
+
+        let request = build_request(
+            Method::POST,
+            &format!("/dashboard/employer/jobs/{JOB_ID}/duplicate"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert!(
+            response
+                .headers()
+                .get("HX-Location")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .contains("/dashboard/employer/jobs/550e8400-e29b-41d4-a716-446655440050/update")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_job_not_found() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_duplicate_job().times(1).returning(|_, _| Ok(None));
+
+        let request = build_request(
+            Method::POST,
+            &format!("/dashboard/employer/jobs/{JOB_ID}/duplicate"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
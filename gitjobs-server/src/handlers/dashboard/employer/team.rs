@@ -19,7 +19,7 @@ use crate::{
     handlers::{auth::SELECTED_EMPLOYER_ID_KEY, error::HandlerError, extractors::SelectedEmployerIdRequired},
     notifications::{DynNotificationsManager, NewNotification, NotificationKind},
     templates::{
-        dashboard::employer::team::{self, NewTeamMember},
+        dashboard::employer::team::{self, NewTeamMember, TeamMemberRoleInput},
         notifications::TeamInvitation,
     },
 };
@@ -29,16 +29,25 @@ use crate::{
 /// Returns the team members list page for the employer dashboard.
 #[instrument(skip_all, err)]
 pub(crate) async fn members_list_page(
+    auth_session: AuthSession,
     State(db): State<DynDB>,
     SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
 ) -> Result<impl IntoResponse, HandlerError> {
-    let members = db.list_team_members(&employer_id).await?;
-    let template = team::MembersListPage {
-        approved_members_count: members.iter().filter(|m| m.approved).count(),
-        members,
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
     };
 
-    Ok(Html(template.render()?))
+    // Get user role in the team
+    let Some(user_role) = db.get_user_employer_role(&user.user_id, &employer_id).await? else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Prepare template
+    let members = db.list_team_members(&employer_id).await?;
+    let template = team::MembersListPage::new(members, user.user_id, user_role);
+
+    Ok(Html(template.render()?).into_response())
 }
 
 /// Returns the user invitations list page for the authenticated user.
@@ -100,6 +109,7 @@ pub(crate) async fn accept_invitation(
 /// Adds a new team member to the employer's team and sends an invitation notification.
 #[instrument(skip_all, err)]
 pub(crate) async fn add_member(
+    auth_session: AuthSession,
     messages: Messages,
     State(cfg): State<HttpServerConfig>,
     State(db): State<DynDB>,
@@ -107,17 +117,32 @@ pub(crate) async fn add_member(
     SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
     Form(member): Form<NewTeamMember>,
 ) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Check the user can assign the role requested
+    let user_role = db.get_user_employer_role(&user.user_id, &employer_id).await?;
+    if !user_role.is_some_and(|role| role.can_manage(member.role)) {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
     // Add the new team member to the database
-    let user_id = db.add_team_member(&employer_id, &member.email).await?;
+    let user_id = db
+        .add_team_member(&employer_id, &member.email, &member.role, &user.user_id)
+        .await?;
     messages.success("New team member invited successfully.");
 
     // Enqueue team invitation notification (if member was added)
     if let Some(user_id) = user_id {
+        let employer = db.get_employer(&employer_id).await?;
         let template_data = TeamInvitation {
             link: format!(
                 "{}/dashboard/employer?tab=invitations",
                 cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url)
             ),
+            employer_name: Some(employer.company),
         };
         let notification = NewNotification {
             kind: NotificationKind::TeamInvitation,
@@ -152,8 +177,31 @@ pub(crate) async fn delete_member(
         return Ok((StatusCode::FORBIDDEN).into_response());
     };
 
+    // Check the user can delete the team member (members can always remove
+    // themselves from the team)
+    if user.user_id != member_user_id {
+        let user_role = db.get_user_employer_role(&user.user_id, &employer_id).await?;
+        let member_role = db.get_team_member_role(&employer_id, &member_user_id).await?;
+        let can_delete = match (user_role, member_role) {
+            (Some(user_role), Some(member_role)) => user_role.can_manage(member_role),
+            _ => false,
+        };
+        if !can_delete {
+            return Ok(StatusCode::FORBIDDEN.into_response());
+        }
+    }
+
     // Delete the team member from the database
-    db.delete_team_member(&employer_id, &member_user_id).await?;
+    if !db
+        .delete_team_member(&employer_id, &member_user_id, &user.user_id)
+        .await?
+    {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "the last owner of the team cannot be removed",
+        )
+            .into_response());
+    }
     messages.success("Team member deleted successfully.");
 
     // Update selected employer if the user deletes themself
@@ -199,7 +247,16 @@ pub(crate) async fn reject_invitation(
     };
 
     // Delete the team member from the database
-    db.delete_team_member(&employer_id, &user.user_id).await?;
+    if !db
+        .delete_team_member(&employer_id, &user.user_id, &user.user_id)
+        .await?
+    {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "the last owner of the team cannot be removed",
+        )
+            .into_response());
+    }
     messages.success("Team invitation rejected.");
 
     Ok((
@@ -211,3 +268,203 @@ pub(crate) async fn reject_invitation(
     )
         .into_response())
 }
+
+/// Updates the role of a team member.
+#[instrument(skip_all, err)]
+pub(crate) async fn update_member_role(
+    auth_session: AuthSession,
+    messages: Messages,
+    State(db): State<DynDB>,
+    SelectedEmployerIdRequired(employer_id): SelectedEmployerIdRequired,
+    Path(member_user_id): Path<Uuid>,
+    Form(input): Form<TeamMemberRoleInput>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Check the user can manage the team member and assign the role requested
+    let user_role = db.get_user_employer_role(&user.user_id, &employer_id).await?;
+    let member_role = db.get_team_member_role(&employer_id, &member_user_id).await?;
+    let can_update = match (user_role, member_role) {
+        (Some(user_role), Some(member_role)) => {
+            user_role.can_manage(member_role) && user_role.can_manage(input.role)
+        }
+        _ => false,
+    };
+    if !can_update {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
+    // Update the team member role in the database
+    if !db
+        .update_team_member_role(&employer_id, &member_user_id, &input.role, &user.user_id)
+        .await?
+    {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "the team must keep at least one owner",
+        )
+            .into_response());
+    }
+    messages.success("Team member role updated successfully.");
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [(
+            "HX-Location",
+            r#"{"path":"/dashboard/employer?tab=team", "target":"body"}"#,
+        )],
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{Method, StatusCode};
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    use crate::{
+        db::mock::MockDB,
+        handlers::test_helpers::{EMPLOYER_ID, USER_ID, build_request, send_request, setup_session},
+        templates::dashboard::employer::team::EmployerRole,
+    };
+
+    const MEMBER_ID: &str = "550e8400-e29b-41d4-a716-446655440020";
+
+    /// Setup a mock database with a session for the test user, who has the
+    /// role provided in the employer's team. Returns the session cookie too.
+    fn setup_db(role: EmployerRole) -> (MockDB, String) {
+        let mut db = MockDB::new();
+        let cookie = setup_session(&mut db);
+        db.expect_get_user_employer_role()
+            .with(
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+                eq(Uuid::parse_str(EMPLOYER_ID).unwrap()),
+            )
+            .returning(move |_, _| Ok(Some(role)));
+        (db, cookie)
+    }
+
+    #[tokio::test]
+    async fn test_add_member_requires_admin() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_add_team_member().never();
+
+        let request = build_request(
+            Method::POST,
+            "/dashboard/employer/team/members/add",
+            &cookie,
+            "email=member%40example.com&role=viewer",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_update_member_role_requires_admin() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_update_team_member_role().never();
+
+        let request = build_request(
+            Method::PUT,
+            &format!("/dashboard/employer/team/members/{MEMBER_ID}/role"),
+            &cookie,
+            "role=viewer",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_delete_member_requires_admin() {
+        let (mut db, cookie) = setup_db(EmployerRole::Recruiter);
+        db.expect_get_team_member_role()
+            .returning(|_, _| Ok(Some(EmployerRole::Viewer)));
+        db.expect_delete_team_member().never();
+
+        let request = build_request(
+            Method::DELETE,
+            &format!("/dashboard/employer/team/members/{MEMBER_ID}/delete"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_admin_cannot_delete_owner() {
+        let (mut db, cookie) = setup_db(EmployerRole::Admin);
+        db.expect_get_team_member_role()
+            .with(
+                eq(Uuid::parse_str(EMPLOYER_ID).unwrap()),
+                eq(Uuid::parse_str(MEMBER_ID).unwrap()),
+            )
+            .returning(|_, _| Ok(Some(EmployerRole::Owner)));
+        db.expect_delete_team_member().never();
+
+        let request = build_request(
+            Method::DELETE,
+            &format!("/dashboard/employer/team/members/{MEMBER_ID}/delete"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_admin_can_delete_recruiter() {
+        let (mut db, cookie) = setup_db(EmployerRole::Admin);
+        db.expect_get_team_member_role()
+            .returning(|_, _| Ok(Some(EmployerRole::Recruiter)));
+        db.expect_delete_team_member()
+            .with(
+                eq(Uuid::parse_str(EMPLOYER_ID).unwrap()),
+                eq(Uuid::parse_str(MEMBER_ID).unwrap()),
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+
+        let request = build_request(
+            Method::DELETE,
+            &format!("/dashboard/employer/team/members/{MEMBER_ID}/delete"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_last_owner_cannot_be_deleted() {
+        let (mut db, cookie) = setup_db(EmployerRole::Owner);
+        db.expect_delete_team_member()
+            .with(
+                eq(Uuid::parse_str(EMPLOYER_ID).unwrap()),
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+                eq(Uuid::parse_str(USER_ID).unwrap()),
+            )
+            .times(1)
+            .returning(|_, _, _| Ok(false));
+
+        let request = build_request(
+            Method::DELETE,
+            &format!("/dashboard/employer/team/members/{USER_ID}/delete"),
+            &cookie,
+            "",
+        );
+        let response = send_request(db, request).await;
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -23,7 +23,7 @@ use crate::{
         dashboard::job_seeker::{
             applications,
             home::{self, Content, Tab},
-            profile,
+            profile, saved_searches,
         },
     },
 };
@@ -49,8 +49,12 @@ pub(crate) async fn page(
     let tab: Tab = query.get("tab").unwrap_or(&String::new()).parse().unwrap_or_default();
     let content = match tab {
         Tab::Account => {
+            let notifications_settings = db.get_user_notifications_settings(&user.user_id).await?;
             let user_summary = user.clone().into();
-            Content::Account(auth::UpdateUserPage { user_summary })
+            Content::Account(auth::UpdateUserPage {
+                notifications_settings,
+                user_summary,
+            })
         }
         Tab::Applications => {
             let applications = db.list_job_seeker_applications(&user.user_id).await?;
@@ -60,6 +64,10 @@ pub(crate) async fn page(
             let profile = db.get_job_seeker_profile(&user.user_id).await?;
             Content::Profile(profile::UpdatePage { profile })
         }
+        Tab::SavedSearches => {
+            let saved_searches = db.list_saved_searches(&user.user_id).await?;
+            Content::SavedSearches(saved_searches::SavedSearchesPage { saved_searches })
+        }
     };
 
     // Prepare template
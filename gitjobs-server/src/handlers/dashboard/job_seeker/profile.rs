@@ -32,7 +32,10 @@ pub(crate) async fn preview_page(
     profile.normalize();
 
     // Prepare template
-    let template = profile::PreviewPage { profile };
+    let template = profile::PreviewPage {
+        profile,
+        feedback: None,
+    };
 
     Ok(Html(template.render()?).into_response())
 }
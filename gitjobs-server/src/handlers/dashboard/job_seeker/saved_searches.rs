@@ -0,0 +1,99 @@
+//! This module defines the HTTP handlers for the saved searches page.
+
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+};
+use axum_extra::extract::Form;
+use reqwest::StatusCode;
+use serde_qs::axum::QsQuery;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthSession,
+    db::DynDB,
+    handlers::error::HandlerError,
+    templates::{
+        dashboard::job_seeker::saved_searches::{NewSavedSearch, SavedSearchesPage},
+        jobboard::jobs::Filters,
+    },
+};
+
+// Pages handlers.
+
+/// Handler that returns the saved searches list page.
+#[instrument(skip_all, err)]
+pub(crate) async fn list_page(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Prepare template
+    let saved_searches = db.list_saved_searches(&user.user_id).await?;
+    let template = SavedSearchesPage { saved_searches };
+
+    Ok(Html(template.render()?).into_response())
+}
+
+// Actions handlers.
+
+/// Handler that saves the job board search provided in the query string.
+#[instrument(skip_all, err)]
+pub(crate) async fn add(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    QsQuery(mut filters): QsQuery<Filters>,
+    Form(saved_search): Form<NewSavedSearch>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Validate input
+    if saved_search.name.trim().is_empty() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "name is required").into_response());
+    }
+
+    // Job alerts only include the newest jobs, so pagination and sorting
+    // options are not saved
+    filters.limit = None;
+    filters.offset = None;
+    filters.sort = None;
+
+    // Add saved search to database
+    db.add_saved_search(&user.user_id, &saved_search, &filters).await?;
+
+    Ok(StatusCode::CREATED.into_response())
+}
+
+/// Handler that deletes a saved search.
+#[instrument(skip_all, err)]
+pub(crate) async fn delete(
+    auth_session: AuthSession,
+    Path(saved_search_id): Path<Uuid>,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok((StatusCode::FORBIDDEN).into_response());
+    };
+
+    // Delete saved search
+    db.delete_saved_search(&saved_search_id, &user.user_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [(
+            "HX-Location",
+            r#"{"path":"/dashboard/job-seeker?tab=saved-searches", "target":"body"}"#,
+        )],
+    )
+        .into_response())
+}
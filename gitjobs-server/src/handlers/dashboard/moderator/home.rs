@@ -10,6 +10,7 @@ use axum::{
     response::{Html, IntoResponse},
 };
 use axum_messages::Messages;
+use serde_qs::axum::QsQuery;
 use tower_sessions::Session;
 use tracing::instrument;
 
@@ -17,14 +18,15 @@ use crate::{
     auth::AuthSession,
     config::HttpServerConfig,
     db::DynDB,
-    handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError},
+    handlers::{auth::AUTH_PROVIDER_KEY, dashboard::audit::prepare_page, error::HandlerError},
     templates::{
         PageId,
         dashboard::{
+            audit::{self, Scope},
             employer::jobs::JobStatus,
             moderator::{
                 home::{self, Content, Tab},
-                jobs,
+                jobs, notifications,
             },
         },
     },
@@ -45,6 +47,7 @@ pub(crate) async fn page(
     State(db): State<DynDB>,
     State(cfg): State<HttpServerConfig>,
     Query(query): Query<HashMap<String, String>>,
+    QsQuery(audit_filters): QsQuery<audit::Filters>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get user from session
     let Some(_user) = auth_session.user.clone() else {
@@ -54,6 +57,14 @@ pub(crate) async fn page(
     // Prepare content for the selected tab
     let tab: Tab = query.get("tab").unwrap_or(&String::new()).parse().unwrap_or_default();
     let content = match tab {
+        Tab::AuditLog => {
+            let template = prepare_page(&db, None, audit_filters, Scope::Moderator).await?;
+            Content::AuditLog(template)
+        }
+        Tab::FailedNotifications => {
+            let notifications = db.list_failed_notifications().await?;
+            Content::FailedNotifications(notifications::FailedPage { notifications })
+        }
         Tab::LiveJobs => {
             let jobs = db.list_jobs_for_moderation(JobStatus::Published).await?;
             Content::LiveJobs(jobs::LivePage { jobs })
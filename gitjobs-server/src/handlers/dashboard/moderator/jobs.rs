@@ -9,8 +9,7 @@ use axum::{
 };
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
-use tracing::{instrument, warn};
+use tracing::{error, instrument, warn};
 use uuid::Uuid;
 
 use crate::{
@@ -18,13 +17,11 @@ use crate::{
     config::HttpServerConfig,
     db::DynDB,
     handlers::error::HandlerError,
+    notifications::{DynNotificationsManager, NotificationKind, announce_job_published, notify_recipients},
     templates::{
-        dashboard::{
-            employer::{self, jobs::JobStatus},
-            moderator::jobs,
-        },
+        dashboard::{employer::jobs::JobStatus, moderator::jobs},
         helpers::option_is_none_or_default,
-        notifications::JobPublished,
+        notifications::{JobApproved, JobRejected},
     },
 };
 
@@ -48,14 +45,19 @@ pub(crate) async fn pending_page(State(db): State<DynDB>) -> Result<impl IntoRes
     Ok(Html(template.render()?))
 }
 
-/// Returns the preview page for a specific job and its employer.
+/// Returns the preview page for a specific job and its employer, including the
+/// changes made to the job since it was last reviewed.
 #[instrument(skip_all, err)]
 pub(crate) async fn preview_page(
     State(db): State<DynDB>,
     Path((employer_id, job_id)): Path<(Uuid, Uuid)>,
 ) -> Result<impl IntoResponse, HandlerError> {
-    let (employer, job) = tokio::try_join!(db.get_employer(&employer_id), db.get_job_dashboard(&job_id))?;
-    let template = employer::jobs::PreviewPage { employer, job };
+    let (employer, job, reviewed_job) = tokio::try_join!(
+        db.get_employer(&employer_id),
+        db.get_job_dashboard(&job_id),
+        db.get_last_reviewed_job(&job_id)
+    )?;
+    let template = jobs::PreviewPage::new(employer, job, reviewed_job);
 
     Ok(Html(template.render()?).into_response())
 }
@@ -68,6 +70,7 @@ pub(crate) async fn approve(
     auth_session: AuthSession,
     State(cfg): State<HttpServerConfig>,
     State(db): State<DynDB>,
+    State(notifications_manager): State<DynNotificationsManager>,
     Path(job_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get user from session
@@ -76,23 +79,42 @@ pub(crate) async fn approve(
     };
 
     // Approve job
-    let previous_first_published_at = db.approve_job(&job_id, &user.user_id).await?;
-
-    // Post a Slack notification the first time a job is published
-    if previous_first_published_at.is_none() {
-        if let Some(webhook_url) = &cfg.slack_webhook_url {
-            if let Some(job) = db.get_job_jobboard(&job_id).await? {
-                let template = JobPublished {
-                    base_url: cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
-                    job,
-                };
-                let payload = json!({
-                    "text": template.render()?,
-                });
-                if let Err(err) = reqwest::Client::new().post(webhook_url).json(&payload).send().await {
-                    warn!("error posting slack notification: {}", err);
-                }
-            }
+    let Some(approved_job) = db.approve_job(&job_id, &user.user_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    // Enqueue job approved notification for the employer's team members
+    match db.get_job_review_notification_data(&job_id).await {
+        Ok(Some(data)) => {
+            let base_url = cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url);
+            let link = if approved_job.scheduled_for.is_some() {
+                format!("{base_url}/dashboard/employer?tab=jobs")
+            } else {
+                format!("{base_url}/?job_id={job_id}")
+            };
+            let template_data = JobApproved {
+                job_title: data.job_title,
+                link,
+                scheduled_for: approved_job.scheduled_for,
+                unsubscribe_link: None,
+            };
+            notify_recipients(
+                &notifications_manager,
+                NotificationKind::JobApproved,
+                &data.recipients,
+                &template_data,
+            )
+            .await;
+        }
+        Ok(None) => {}
+        Err(err) => error!("error getting job review notification data: {err}"),
+    }
+
+    // Announce the job the first time it is published (scheduled jobs are
+    // announced once they are published)
+    if approved_job.first_published {
+        if let Err(err) = announce_job_published(&db, &notifications_manager, &cfg.base_url, &job_id).await {
+            warn!("error announcing job published: {}", err);
         }
     }
 
@@ -108,7 +130,9 @@ pub(crate) async fn approve(
 #[instrument(skip_all, err)]
 pub(crate) async fn reject(
     auth_session: AuthSession,
+    State(cfg): State<HttpServerConfig>,
     State(db): State<DynDB>,
+    State(notifications_manager): State<DynNotificationsManager>,
     Path(job_id): Path<Uuid>,
     Form(input): Form<RejectInput>,
 ) -> Result<impl IntoResponse, HandlerError> {
@@ -121,6 +145,28 @@ pub(crate) async fn reject(
     db.reject_job(&job_id, &user.user_id, input.review_notes.as_ref())
         .await?;
 
+    // Enqueue job rejected notification for the employer's team members
+    match db.get_job_review_notification_data(&job_id).await {
+        Ok(Some(data)) => {
+            let base_url = cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url);
+            let template_data = JobRejected {
+                job_title: data.job_title,
+                link: format!("{base_url}/dashboard/employer/jobs/{job_id}/update"),
+                review_notes: input.review_notes.filter(|notes| !notes.trim().is_empty()),
+                unsubscribe_link: None,
+            };
+            notify_recipients(
+                &notifications_manager,
+                NotificationKind::JobRejected,
+                &data.recipients,
+                &template_data,
+            )
+            .await;
+        }
+        Ok(None) => {}
+        Err(err) => error!("error getting job review notification data: {err}"),
+    }
+
     Ok((
         StatusCode::NO_CONTENT,
         [("HX-Trigger", "refresh-moderator-table")],
@@ -0,0 +1,43 @@
+//! HTTP handlers for the notifications pages in the moderator dashboard.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{
+    db::DynDB, handlers::error::HandlerError, templates::dashboard::moderator::notifications::FailedPage,
+};
+
+// Pages handlers.
+
+/// Returns the page listing the notifications that could not be delivered.
+#[instrument(skip_all, err)]
+pub(crate) async fn failed_page(State(db): State<DynDB>) -> Result<impl IntoResponse, HandlerError> {
+    let notifications = db.list_failed_notifications().await?;
+    let template = FailedPage { notifications };
+
+    Ok(Html(template.render()?))
+}
+
+// Actions.
+
+/// Schedules a failed notification to be delivered again and triggers a table
+/// refresh in the UI.
+#[instrument(skip_all, err)]
+pub(crate) async fn retry(
+    State(db): State<DynDB>,
+    Path(notification_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    db.retry_notification(&notification_id).await?;
+
+    Ok((
+        StatusCode::NO_CONTENT,
+        [("HX-Trigger", "refresh-moderator-table")],
+    ))
+}
@@ -1,40 +1,59 @@
 //! HTTP handlers for job board embed endpoints, including jobs and job card embeds.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use askama::Template;
 use axum::{
     extract::{Path, State},
+    http::StatusCode,
     response::{Html, IntoResponse},
 };
-use chrono::Duration;
+use cached::proc_macro::cached;
+use chrono::{DateTime, Duration, Utc};
 use serde_qs::axum::QsQuery;
-use tracing::instrument;
+use tracing::{debug, instrument};
 use uuid::Uuid;
 
 use crate::{
     config::HttpServerConfig,
     db::{DynDB, jobboard::JobsSearchOutput},
     handlers::{error::HandlerError, prepare_headers},
+    img::svg_to_png,
     templates::jobboard::{
-        embed::{JobCard, JobsPage},
+        embed::{EmbedOptions, JobCard, JobsPage},
         jobs::Filters,
     },
 };
 
-/// Returns the jobs embed page for external integration.
+/// Number of jobs displayed in the jobs embed by default.
+const DEFAULT_EMBED_JOBS: usize = 20;
+
+/// Maximum number of jobs that can be displayed in the jobs embed.
+const MAX_EMBED_JOBS: usize = 50;
+
+/// Width of the job card preview image, in pixels.
+const JOB_CARD_PNG_WIDTH: u32 = 1200;
+
+/// Height of the job card preview image, in pixels.
+const JOB_CARD_PNG_HEIGHT: u32 = 630;
+
+/// Returns the jobs embed page for external integration. The look of the page
+/// can be customized using the embed options provided in the query string.
 #[instrument(skip_all, err)]
 pub(crate) async fn jobs_page(
     State(cfg): State<HttpServerConfig>,
     State(db): State<DynDB>,
-    QsQuery(filters): QsQuery<Filters>,
+    QsQuery(mut filters): QsQuery<Filters>,
+    QsQuery(options): QsQuery<EmbedOptions>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get jobs that match the query
+    filters.limit = Some(filters.limit.unwrap_or(DEFAULT_EMBED_JOBS).clamp(1, MAX_EMBED_JOBS));
     let JobsSearchOutput { jobs, total: _ } = db.search_jobs(&filters).await?;
 
     // Prepare template
     let template = JobsPage {
         base_url: cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
         jobs,
+        options,
     };
 
     // Prepare response headers
@@ -62,3 +81,47 @@ pub(crate) async fn job_card(
 
     Ok((headers, template.render()?))
 }
+
+/// Returns the job card as a PNG image, suitable to be used as the preview
+/// image of the job when shared on social networks (which don't support SVG).
+#[instrument(skip_all, err)]
+pub(crate) async fn job_card_png(
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+    Path(job_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get job information
+    let Some(job) = db.get_job_jobboard(&job_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+    let updated_at = job.updated_at.or(job.published_at);
+
+    // Render job card and rasterize it
+    let template = JobCard {
+        base_url: cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
+        job: Some(job),
+    };
+    let svg = template.render()?;
+    let png = tokio::task::spawn_blocking(move || render_job_card_png(job_id, updated_at, &svg))
+        .await
+        .context("error rendering job card")??;
+
+    // Prepare response headers
+    let extra_headers = [("content-type", "image/png")];
+    let headers = prepare_headers(Duration::hours(1), &extra_headers)?;
+
+    Ok((headers, png).into_response())
+}
+
+/// Rasterizes the job card provided. Images are cached per job and last update,
+/// so they are only rendered again when the job changes.
+#[cached(
+    size = 500,
+    key = "String",
+    convert = r#"{ format!("{job_id}:{}", updated_at.map(|ts| ts.timestamp()).unwrap_or_default()) }"#,
+    result = true
+)]
+fn render_job_card_png(job_id: Uuid, updated_at: Option<DateTime<Utc>>, svg: &str) -> Result<Vec<u8>> {
+    debug!(%job_id, ?updated_at, "rendering job card");
+    svg_to_png(svg, JOB_CARD_PNG_WIDTH, JOB_CARD_PNG_HEIGHT)
+}
@@ -0,0 +1,77 @@
+//! HTTP handlers for the job board feeds.
+
+use anyhow::Result;
+use axum::{extract::State, http::HeaderMap, response::IntoResponse};
+use chrono::Duration;
+use serde_qs::axum::QsQuery;
+use tracing::instrument;
+
+use crate::{
+    config::HttpServerConfig,
+    db::{DynDB, jobboard::JobsSearchOutput},
+    handlers::{error::HandlerError, prepare_headers},
+    templates::{
+        jobboard::{
+            feeds::{AtomFeed, JobsFeed, RssFeed},
+            jobs::Filters,
+        },
+        pagination::build_url,
+    },
+};
+
+/// Maximum number of jobs included in a feed.
+const MAX_ITEMS: usize = 50;
+
+/// Returns an Atom feed with the newest jobs that match the filters provided.
+#[instrument(skip_all, err)]
+pub(crate) async fn jobs_atom(
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+    QsQuery(filters): QsQuery<Filters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    render_feed::<AtomFeed>(&cfg, &db, &filters).await
+}
+
+/// Returns an RSS feed with the newest jobs that match the filters provided.
+#[instrument(skip_all, err)]
+pub(crate) async fn jobs_rss(
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+    QsQuery(filters): QsQuery<Filters>,
+) -> Result<impl IntoResponse, HandlerError> {
+    render_feed::<RssFeed>(&cfg, &db, &filters).await
+}
+
+/// Renders the feed provided with the newest jobs that match the filters.
+async fn render_feed<F: JobsFeed>(
+    cfg: &HttpServerConfig,
+    db: &DynDB,
+    filters: &Filters,
+) -> Result<(HeaderMap, String), HandlerError> {
+    // Get newest jobs that match the query
+    let JobsSearchOutput { jobs, total: _ } = db.search_jobs(&prepare_filters(filters)).await?;
+
+    // Prepare template
+    let template = F::new(
+        cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
+        build_url(F::PATH, filters)?,
+        build_url("/", filters)?,
+        jobs,
+    );
+
+    // Prepare response headers
+    let extra_headers = [("content-type", F::CONTENT_TYPE)];
+    let headers = prepare_headers(Duration::minutes(10), &extra_headers)?;
+
+    Ok((headers, template.render()?))
+}
+
+/// Prepares the filters used to get the jobs included in a feed. Feeds always
+/// contain the newest jobs, so pagination and sorting options are ignored.
+fn prepare_filters(filters: &Filters) -> Filters {
+    let mut filters = filters.clone();
+    filters.limit = Some(filters.limit.unwrap_or(MAX_ITEMS).min(MAX_ITEMS));
+    filters.offset = None;
+    filters.sort = None;
+    filters
+}
@@ -1,16 +1,18 @@
 //! HTTP handlers for the jobs pages.
 
+use std::collections::HashMap;
+
 use anyhow::Result;
 use askama::Template;
 use axum::{
-    extract::{Json, Path, State},
-    response::{Html, IntoResponse},
+    extract::{Json, Path, Query, State},
+    response::{Html, IntoResponse, Redirect},
 };
 use chrono::Duration;
 use reqwest::StatusCode;
 use serde_qs::axum::QsQuery;
 use tower_sessions::Session;
-use tracing::instrument;
+use tracing::{error, instrument};
 use uuid::Uuid;
 
 use crate::{
@@ -19,10 +21,14 @@ use crate::{
     db::{DynDB, jobboard::JobsSearchOutput},
     event_tracker::{DynEventTracker, Event},
     handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError, prepare_headers},
+    notifications::{DynNotificationsManager, NotificationKind, notify_recipients},
     templates::{
         PageId,
         auth::User,
-        jobboard::jobs::{ExploreSection, Filters, JobSection, JobsPage, ResultsSection},
+        jobboard::jobs::{
+            ExploreSection, Filters, JobApplication, JobPage, JobSection, JobsPage, ResultsSection,
+        },
+        notifications::NewApplication,
         pagination::{NavigationLinks, build_url},
     },
 };
@@ -36,11 +42,21 @@ pub(crate) async fn jobs_page(
     State(db): State<DynDB>,
     State(cfg): State<HttpServerConfig>,
     QsQuery(filters): QsQuery<Filters>,
+    Query(query): Query<HashMap<String, String>>,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get filter options and jobs that match the query
     let (filters_options, JobsSearchOutput { jobs, total }) =
         tokio::try_join!(db.get_jobs_filters_options(), db.search_jobs(&filters))?;
 
+    // Prepare the structured data of the job requested, if any
+    let job_posting = match query.get("job_id").and_then(|job_id| Uuid::parse_str(job_id).ok()) {
+        Some(job_id) => {
+            let base_url = cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url);
+            db.get_job_jobboard(&job_id).await?.map(|job| job.json_ld(base_url))
+        }
+        None => None,
+    };
+
     // Prepare template
     let template = JobsPage {
         auth_provider: session.get(AUTH_PROVIDER_KEY).await?,
@@ -57,6 +73,7 @@ pub(crate) async fn jobs_page(
         },
         page_id: PageId::JobBoard,
         user: User::default(),
+        job_posting,
     };
 
     // Prepare response headers
@@ -90,6 +107,42 @@ pub(crate) async fn results_section(
     Ok((headers, Html(template.render()?)))
 }
 
+/// Returns the standalone page of a specific job. Requests using an outdated
+/// slug are redirected to the canonical path of the job.
+#[instrument(skip_all, err)]
+pub(crate) async fn job_page(
+    session: Session,
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+    Path((job_id, slug)): Path<(Uuid, String)>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get job information
+    let Some(job) = db.get_job_jobboard(&job_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    // Redirect to the canonical path if the slug doesn't match
+    let path = job.path();
+    if !path.ends_with(&format!("/{slug}")) {
+        return Ok(Redirect::permanent(&path).into_response());
+    }
+
+    // Prepare template
+    let template = JobPage {
+        auth_provider: session.get(AUTH_PROVIDER_KEY).await?,
+        base_url: cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
+        cfg: cfg.into(),
+        job,
+        page_id: PageId::JobBoard,
+        user: User::default(),
+    };
+
+    // Prepare response headers
+    let headers = prepare_headers(Duration::hours(1), &[])?;
+
+    Ok((headers, Html(template.render()?)).into_response())
+}
+
 /// Returns the job details section for a specific job.
 #[instrument(skip_all, err)]
 pub(crate) async fn job_section(
@@ -119,22 +172,83 @@ pub(crate) async fn job_section(
 /// Allows an authenticated user to apply to a job.
 #[instrument(skip_all, err)]
 pub(crate) async fn apply(
+    State(cfg): State<HttpServerConfig>,
     State(db): State<DynDB>,
+    State(notifications_manager): State<DynNotificationsManager>,
+    State(serde_qs_de): State<serde_qs::Config>,
     Path(job_id): Path<Uuid>,
     auth_session: AuthSession,
+    body: String,
 ) -> Result<impl IntoResponse, HandlerError> {
     // Get user from session
     let Some(user) = auth_session.user else {
-        return Ok(StatusCode::FORBIDDEN);
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Get application information from body
+    let application: JobApplication = match serde_qs_de.deserialize_str(&body).map_err(anyhow::Error::new) {
+        Ok(application) => application,
+        Err(e) => return Ok((StatusCode::UNPROCESSABLE_ENTITY, e.to_string()).into_response()),
+    };
+
+    // Check the cover letter does not exceed the maximum length
+    if !application.cover_letter_is_valid() {
+        return Ok((StatusCode::UNPROCESSABLE_ENTITY, "cover letter is too long").into_response());
+    }
+
+    // Check the screening questions of the job have been answered
+    let Some(job) = db.get_job_jobboard(&job_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+    let screening_answers = match &job.screening_questions {
+        Some(questions) => match application.screening_answers(questions) {
+            Some(answers) => Some(answers),
+            None => {
+                return Ok((StatusCode::UNPROCESSABLE_ENTITY, "invalid screening answers").into_response());
+            }
+        },
+        None => None,
     };
 
     // Create job application entry in the database
-    let applied = db.apply_to_job(&job_id, &user.user_id).await?;
+    let applied = db
+        .apply_to_job(
+            &job_id,
+            &user.user_id,
+            application.cover_letter(),
+            screening_answers.as_deref(),
+        )
+        .await?;
     if !applied {
-        return Ok(StatusCode::CONFLICT);
+        return Ok(StatusCode::CONFLICT.into_response());
     }
 
-    Ok(StatusCode::NO_CONTENT)
+    // Enqueue new application notification for the employer's team members
+    match db.get_new_application_notification_data(&job_id, &user.user_id).await {
+        Ok(Some(data)) => {
+            let base_url = cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url);
+            let template_data = NewApplication {
+                job_title: data.job_title,
+                link: format!(
+                    "{base_url}/dashboard/employer/applications/profile/{}/preview",
+                    data.job_seeker_profile_id
+                ),
+                settings_link: format!("{base_url}/dashboard/employer?tab=account"),
+                unsubscribe_link: None,
+            };
+            notify_recipients(
+                &notifications_manager,
+                NotificationKind::NewApplication,
+                &data.recipients,
+                &template_data,
+            )
+            .await;
+        }
+        Ok(None) => {}
+        Err(err) => error!("error getting new application notification data: {err}"),
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
 }
 
 /// Tracks a view for a specific job in the job board.
@@ -2,5 +2,7 @@
 
 pub(crate) mod about;
 pub(crate) mod embed;
+pub(crate) mod feeds;
 pub(crate) mod jobs;
+pub(crate) mod seo;
 pub(crate) mod stats;
@@ -0,0 +1,53 @@
+//! HTTP handlers for the files used by search engines to crawl the job board.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{extract::State, response::IntoResponse};
+use chrono::Duration;
+use tracing::instrument;
+
+use crate::{
+    config::HttpServerConfig,
+    db::DynDB,
+    handlers::{error::HandlerError, prepare_headers},
+    templates::jobboard::seo::{RobotsTxt, Sitemap},
+};
+
+/// Returns the robots.txt file, pointing crawlers to the sitemap.
+#[instrument(skip_all, err)]
+pub(crate) async fn robots_txt(
+    State(cfg): State<HttpServerConfig>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Prepare template
+    let template = RobotsTxt {
+        base_url: cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
+    };
+
+    // Prepare response headers
+    let extra_headers = [("content-type", "text/plain; charset=utf-8")];
+    let headers = prepare_headers(Duration::hours(1), &extra_headers)?;
+
+    Ok((headers, template.render()?))
+}
+
+/// Returns the sitemap, listing the job board pages and all published jobs.
+#[instrument(skip_all, err)]
+pub(crate) async fn sitemap(
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get published jobs
+    let jobs = db.list_sitemap_jobs().await?;
+
+    // Prepare template
+    let template = Sitemap {
+        base_url: cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string(),
+        jobs,
+    };
+
+    // Prepare response headers
+    let extra_headers = [("content-type", "application/xml; charset=utf-8")];
+    let headers = prepare_headers(Duration::hours(1), &extra_headers)?;
+
+    Ok((headers, template.render()?))
+}
@@ -5,6 +5,8 @@ use axum::http::{HeaderMap, HeaderName, HeaderValue};
 use chrono::Duration;
 use reqwest::header::CACHE_CONTROL;
 
+/// Public API HTTP handlers.
+pub(crate) mod api;
 /// Authentication-related HTTP handlers.
 pub(crate) mod auth;
 /// Dashboard-related HTTP handlers.
@@ -19,6 +21,13 @@ pub(crate) mod img;
 pub(crate) mod jobboard;
 /// Miscellaneous HTTP handlers.
 pub(crate) mod misc;
+/// Notifications-related HTTP handlers.
+pub(crate) mod notifications;
+/// Resume-related HTTP handlers.
+pub(crate) mod resume;
+/// Helpers shared by the handlers tests.
+#[cfg(test)]
+pub(crate) mod test_helpers;
 
 /// Helper function to prepare headers for HTTP responses, including cache control and
 /// additional custom headers.
@@ -0,0 +1,80 @@
+//! This module defines the HTTP handlers used to unsubscribe from notifications
+//! through the signed links included in the emails.
+
+use anyhow::Result;
+use askama::Template;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+};
+use tower_sessions::Session;
+use tracing::instrument;
+
+use crate::{
+    auth::AuthSession,
+    config::HttpServerConfig,
+    db::DynDB,
+    handlers::{auth::AUTH_PROVIDER_KEY, error::HandlerError},
+    notifications::unsubscribe::UnsubscribeLink,
+    templates::{PageId, misc::UnsubscribePage},
+};
+
+/// Handler that returns the page to confirm unsubscribing from a notification
+/// kind.
+#[instrument(skip_all, err)]
+pub(crate) async fn unsubscribe_page(
+    auth_session: AuthSession,
+    session: Session,
+    State(cfg): State<HttpServerConfig>,
+    Query(link): Query<UnsubscribeLink>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Check the link is valid
+    if !link.kind.is_optional() || !link.verify(&cfg.unsubscribe_secret) {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
+    // Prepare template
+    let template = UnsubscribePage {
+        auth_provider: session.get(AUTH_PROVIDER_KEY).await?,
+        cfg: cfg.into(),
+        kind: link.kind,
+        page_id: PageId::Unsubscribe,
+        unsubscribed: false,
+        user: auth_session.into(),
+    };
+
+    Ok(Html(template.render()?).into_response())
+}
+
+/// Handler that unsubscribes the user from a notification kind. It's used by
+/// the confirmation page and by the email clients supporting one-click
+/// unsubscribe (RFC 8058).
+#[instrument(skip_all, err)]
+pub(crate) async fn unsubscribe(
+    auth_session: AuthSession,
+    session: Session,
+    State(cfg): State<HttpServerConfig>,
+    State(db): State<DynDB>,
+    Query(link): Query<UnsubscribeLink>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Check the link is valid
+    if !link.kind.is_optional() || !link.verify(&cfg.unsubscribe_secret) {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
+    // Disable notifications of the kind provided for the user
+    db.disable_notifications(&link.user_id, &link.kind).await?;
+
+    // Prepare template
+    let template = UnsubscribePage {
+        auth_provider: session.get(AUTH_PROVIDER_KEY).await?,
+        cfg: cfg.into(),
+        kind: link.kind,
+        page_id: PageId::Unsubscribe,
+        unsubscribed: true,
+        user: auth_session.into(),
+    };
+
+    Ok(Html(template.render()?).into_response())
+}
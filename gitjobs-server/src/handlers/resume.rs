@@ -0,0 +1,123 @@
+//! HTTP handlers for resume management, including upload and retrieval.
+
+use axum::{
+    extract::{Multipart, Path, State},
+    http::{HeaderMap, HeaderValue},
+    response::IntoResponse,
+};
+use reqwest::{
+    StatusCode,
+    header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_LENGTH, CONTENT_TYPE, X_CONTENT_TYPE_OPTIONS},
+};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::{auth::AuthSession, db::DynDB, handlers::error::HandlerError};
+
+/// Maximum size allowed for resumes, in bytes (5MB).
+pub(crate) const MAX_RESUME_SIZE: usize = 5 * 1024 * 1024;
+
+/// Signature found at the beginning of all PDF documents.
+const PDF_SIGNATURE: &[u8] = b"%PDF-";
+
+/// Returns a resume from the database as a PDF document.
+///
+/// Resumes are only available to the user who uploaded them and to the team
+/// members of the employers the profile they are attached to has applied to.
+#[instrument(skip_all, err)]
+pub(crate) async fn get(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    Path(resume_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Get resume from the database
+    let Some(resume) = db.get_resume(&resume_id).await? else {
+        return Ok(StatusCode::NOT_FOUND.into_response());
+    };
+
+    // Check if the user has access to the resume
+    let has_access = if resume.created_by == Some(user.user_id) {
+        true
+    } else if let Some(profile_id) = resume.job_seeker_profile_id {
+        db.user_has_profile_access(&user.user_id, &profile_id).await?
+    } else {
+        false
+    };
+    if !has_access {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    }
+
+    // Prepare response headers
+    let mut headers = HeaderMap::new();
+    let file_name = resume
+        .file_name
+        .replace(|c: char| !c.is_ascii_graphic() || c == '"', "_");
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("private, max-age=3600"));
+    headers.insert(
+        CONTENT_DISPOSITION,
+        HeaderValue::try_from(format!(r#"attachment; filename="{file_name}""#))
+            .map_err(anyhow::Error::new)?,
+    );
+    headers.insert(CONTENT_LENGTH, resume.data.len().into());
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/pdf"));
+    headers.insert(X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+
+    Ok((headers, resume.data).into_response())
+}
+
+/// Handles resume upload from authenticated users, saving the document to the
+/// database. Only PDF documents up to `MAX_RESUME_SIZE` are accepted.
+#[instrument(skip_all, err)]
+pub(crate) async fn upload(
+    auth_session: AuthSession,
+    State(db): State<DynDB>,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, HandlerError> {
+    // Get user from session
+    let Some(user) = auth_session.user else {
+        return Ok(StatusCode::FORBIDDEN.into_response());
+    };
+
+    // Get resume file name and data from the multipart form data
+    let (file_name, data) = if let Ok(Some(field)) = multipart.next_field().await {
+        let file_name = field.file_name().unwrap_or_default().to_string();
+        let Ok(data) = field.bytes().await else {
+            return Ok(StatusCode::BAD_REQUEST.into_response());
+        };
+        (file_name, data)
+    } else {
+        return Ok(StatusCode::BAD_REQUEST.into_response());
+    };
+
+    // Validate resume size and type
+    if data.len() > MAX_RESUME_SIZE {
+        return Ok((StatusCode::PAYLOAD_TOO_LARGE, "resume must not exceed 5MB").into_response());
+    }
+    if !is_pdf(&file_name, &data) {
+        return Ok((
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "resume must be a pdf document",
+        )
+            .into_response());
+    }
+
+    // Save resume to the database
+    let resume_id = db.save_resume(&user.user_id, &file_name, &data).await?;
+
+    Ok((StatusCode::OK, resume_id.to_string()).into_response())
+}
+
+/// Returns true if the file provided looks like a PDF document, checking both
+/// its extension and its signature.
+fn is_pdf(file_name: &str, data: &[u8]) -> bool {
+    let has_pdf_extension = file_name
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| extension.eq_ignore_ascii_case("pdf"));
+
+    has_pdf_extension && data.starts_with(PDF_SIGNATURE)
+}
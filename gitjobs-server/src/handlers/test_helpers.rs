@@ -0,0 +1,134 @@
+//! Helpers shared by the handlers tests.
+
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    body::{Body, to_bytes},
+    http::{
+        Method, Request,
+        header::{CONTENT_TYPE, COOKIE},
+    },
+    response::Response,
+};
+use axum_login::tower_sessions::session;
+use time::OffsetDateTime;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    auth::User,
+    config::{HttpServerConfig, LoginOptions},
+    db::mock::MockDB,
+    event_tracker::MockEventTracker,
+    handlers::auth::SELECTED_EMPLOYER_ID_KEY,
+    img::MockImageStore,
+    notifications::MockNotificationsManager,
+    router,
+};
+
+/// Identifier of the employer selected in the test sessions.
+pub(crate) const EMPLOYER_ID: &str = "550e8400-e29b-41d4-a716-446655440001";
+
+/// Identifier of the user logged in in the test sessions.
+pub(crate) const USER_ID: &str = "550e8400-e29b-41d4-a716-446655440010";
+
+/// Key used by `axum_login` to store the authentication data in the session.
+const AUTH_DATA_KEY: &str = "axum-login.data";
+
+/// Build a request with the method and uri provided, using the session cookie
+/// provided. The body, if not empty, is sent as an url encoded form.
+pub(crate) fn build_request(method: Method, uri: &str, cookie: &str, form: &str) -> Request<Body> {
+    let mut builder = Request::builder().method(method).uri(uri).header(COOKIE, cookie);
+    if !form.is_empty() {
+        builder = builder.header(CONTENT_TYPE, "application/x-www-form-urlencoded");
+    }
+    builder.body(Body::from(form.to_string())).unwrap()
+}
+
+/// Create the HTTP server configuration used in the tests.
+pub(crate) fn create_config() -> HttpServerConfig {
+    HttpServerConfig {
+        addr: "127.0.0.1:9000".to_string(),
+        base_url: "https://example.com".to_string(),
+        login: LoginOptions {
+            email: true,
+            github: false,
+            linuxfoundation: false,
+        },
+        oauth2: HashMap::new(),
+        oidc: HashMap::new(),
+        unsubscribe_secret: "0123456789abcdef0123456789abcdef".to_string(),
+        analytics: None,
+        basic_auth: None,
+        cookie: None,
+        slack_webhook_url: None,
+    }
+}
+
+/// Create the user logged in in the test sessions.
+pub(crate) fn create_user() -> User {
+    User {
+        user_id: Uuid::parse_str(USER_ID).unwrap(),
+        auth_hash: vec![1, 2, 3],
+        email: "user@example.com".to_string(),
+        email_verified: true,
+        has_profile: false,
+        moderator: false,
+        name: "User".to_string(),
+        username: "user".to_string(),
+        has_password: Some(true),
+        password: None,
+    }
+}
+
+/// Read the body of the response provided as a string.
+pub(crate) async fn read_body(response: Response) -> String {
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+/// Send the request provided to the application router, backed by the mock
+/// database provided.
+pub(crate) async fn send_request(db: MockDB, request: Request<Body>) -> Response {
+    let router = router::setup(
+        create_config(),
+        Arc::new(db),
+        Arc::new(MockImageStore::new()),
+        Arc::new(MockNotificationsManager::new()),
+        Arc::new(MockEventTracker::new()),
+    )
+    .await
+    .unwrap();
+
+    router.oneshot(request).await.unwrap()
+}
+
+/// Setup the mock database provided to load a session for the test user, with
+/// the test employer selected. Returns the cookie to send with the requests.
+pub(crate) fn setup_session(db: &mut MockDB) -> String {
+    let user = create_user();
+    let record = session::Record {
+        id: session::Id::default(),
+        data: HashMap::from([
+            (
+                AUTH_DATA_KEY.to_string(),
+                serde_json::json!({
+                    "user_id": user.user_id,
+                    "auth_hash": user.auth_hash,
+                }),
+            ),
+            (
+                SELECTED_EMPLOYER_ID_KEY.to_string(),
+                serde_json::json!(EMPLOYER_ID),
+            ),
+        ]),
+        expiry_date: OffsetDateTime::now_utc() + time::Duration::days(1),
+    };
+    let cookie = format!("id={}", record.id);
+
+    db.expect_get_session().returning(move |_| Ok(Some(record.clone())));
+    db.expect_get_user_by_id().returning(move |_| Ok(Some(user.clone())));
+    db.expect_update_session().returning(|_| Ok(()));
+
+    cookie
+}
@@ -1,15 +1,25 @@
 //! Types and functionality for managing image storage, formats, and processing.
 
-use std::{io::Cursor, sync::Arc};
+use std::{
+    io::Cursor,
+    sync::{Arc, LazyLock},
+};
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use async_trait::async_trait;
+#[cfg(test)]
+use mockall::automock;
+use resvg::{
+    tiny_skia::{Color, Pixmap, Transform},
+    usvg::{self, fontdb},
+};
 use uuid::Uuid;
 
 pub(crate) mod db;
 
 /// Trait for image storage backends supporting get and save operations.
 #[async_trait]
+#[cfg_attr(test, automock)]
 pub(crate) trait ImageStore {
     /// Retrieve an image version from the store.
     async fn get(&self, image_id: Uuid, version: &str) -> Result<Option<(Vec<u8>, ImageFormat)>>;
@@ -47,6 +57,41 @@ pub(crate) fn generate_versions(data: &[u8]) -> Result<Vec<ImageVersion>> {
     Ok(versions)
 }
 
+/// Fonts database used to render the text in SVG images, loaded from the
+/// fonts installed in the system.
+static FONTS_DB: LazyLock<Arc<fontdb::Database>> = LazyLock::new(|| {
+    let mut db = fontdb::Database::new();
+    db.load_system_fonts();
+    Arc::new(db)
+});
+
+/// Rasterize an SVG image into a PNG image of the size provided. The SVG image
+/// is scaled to fit and centered over a white background.
+pub(crate) fn svg_to_png(svg: &str, width: u32, height: u32) -> Result<Vec<u8>> {
+    // Parse SVG image
+    let options = usvg::Options {
+        font_family: "Inter".to_string(),
+        fontdb: FONTS_DB.clone(),
+        ..Default::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &options)?;
+
+    // Render it centered on a white background
+    let mut pixmap = Pixmap::new(width, height).ok_or_else(|| anyhow!("invalid image size"))?;
+    pixmap.fill(Color::WHITE);
+    let size = tree.size();
+    #[allow(clippy::cast_precision_loss)]
+    let (width, height) = (width as f32, height as f32);
+    let scale = (width / size.width()).min(height / size.height());
+    let transform = Transform::from_scale(scale, scale).post_translate(
+        (width - size.width() * scale) / 2.0,
+        (height - size.height() * scale) / 2.0,
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+
+    Ok(pixmap.encode_png()?)
+}
+
 /// Represents a version of an image of a specific size (or format).
 #[derive(Debug, Clone)]
 pub(crate) struct ImageVersion {
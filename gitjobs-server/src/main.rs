@@ -8,7 +8,7 @@ use clap::Parser;
 use deadpool_postgres::Runtime;
 use event_tracker::EventTrackerDB;
 use img::db::DbImageStore;
-use notifications::PgNotificationsManager;
+use notifications::{PgNotificationsManager, channels::Channels};
 use openssl::ssl::{SslConnector, SslMethod, SslVerifyMode};
 use postgres_openssl::MakeTlsConnector;
 use tokio::{net::TcpListener, signal};
@@ -73,7 +73,7 @@ async fn main() -> Result<()> {
     let mut builder = SslConnector::builder(SslMethod::tls())?;
     builder.set_verify(SslVerifyMode::NONE);
     let connector = MakeTlsConnector::new(builder.build());
-    let pool = cfg.db.create_pool(Some(Runtime::Tokio1), connector)?;
+    let pool = cfg.db.create_pool(Some(Runtime::Tokio1), connector.clone())?;
     let db = Arc::new(PgDB::new(pool));
     {
         let db = db.clone();
@@ -87,12 +87,16 @@ async fn main() -> Result<()> {
     let image_store = Arc::new(DbImageStore::new(db.clone()));
 
     // Setup notifications manager.
+    let channels = Channels::new(&cfg)?;
     let notifications_manager = Arc::new(PgNotificationsManager::new(
         db.clone(),
-        &cfg.email,
+        &cfg,
+        channels,
+        cfg.db.get_pg_config()?,
+        connector,
         &task_tracker,
         &cancellation_token,
-    )?);
+    ));
 
     // Setup event tracker.
     let event_tracker = Arc::new(EventTrackerDB::new(
@@ -102,7 +106,13 @@ async fn main() -> Result<()> {
     ));
 
     // Run additional background workers.
-    workers::run(db.clone(), &task_tracker, cancellation_token.clone());
+    workers::run(
+        db.clone(),
+        notifications_manager.clone(),
+        &cfg.server,
+        &task_tracker,
+        cancellation_token.clone(),
+    );
 
     // Setup and launch the HTTP server.
     let router = router::setup(
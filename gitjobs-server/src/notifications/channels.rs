@@ -0,0 +1,763 @@
+//! This module defines the channels used to deliver notifications (email,
+//! generic webhooks, Slack, Matrix and Discord).
+
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    sync::Arc,
+};
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
+    message::{
+        Mailbox, MessageBuilder, MultiPart,
+        header::{ContentType, HeaderName, HeaderValue},
+    },
+    transport::smtp::authentication::Credentials,
+};
+use reqwest::Url;
+use serde::Serialize;
+use serde_json::json;
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::config::{ChannelConfig, Config, EmailConfig};
+
+use super::NotificationKind;
+
+/// Name of the built-in email channel.
+const EMAIL_CHANNEL: &str = "email";
+
+/// Maximum length of the content of a Discord message.
+const DISCORD_MAX_CONTENT_LEN: usize = 2000;
+
+/// Trait for a channel, responsible for delivering messages to a destination.
+#[async_trait]
+pub(crate) trait Channel {
+    /// Send the message provided through the channel.
+    async fn send(&self, message: &Message) -> Result<()>;
+}
+
+/// Shared trait object for a channel.
+pub(crate) type DynChannel = Arc<dyn Channel + Send + Sync>;
+
+/// Channels used to deliver each notification kind.
+#[derive(Clone)]
+pub(crate) struct Channels {
+    /// Channels used for the kinds without an explicit route.
+    default: Vec<DynChannel>,
+    /// Channels used for each notification kind.
+    routes: HashMap<NotificationKind, Vec<DynChannel>>,
+}
+
+impl Channels {
+    /// Create a new `Channels` instance from the configuration provided.
+    pub(crate) fn new(cfg: &Config) -> Result<Self> {
+        let http_client = reqwest::Client::new();
+
+        // Setup the channels defined in the configuration
+        let mut available: HashMap<&str, DynChannel> = HashMap::new();
+        for (name, channel_cfg) in &cfg.notifications.channels {
+            let channel = Self::setup_channel(channel_cfg, &cfg.email, &http_client)?;
+            available.insert(name.as_str(), channel);
+        }
+        if !available.contains_key(EMAIL_CHANNEL) {
+            let channel = Self::setup_channel(&ChannelConfig::Email, &cfg.email, &http_client)?;
+            available.insert(EMAIL_CHANNEL, channel);
+        }
+
+        // Setup the routes for each notification kind
+        let mut routes = HashMap::new();
+        for (kind, names) in &cfg.notifications.routes {
+            let mut channels = Vec::with_capacity(names.len());
+            for name in names {
+                let channel = available
+                    .get(name.as_str())
+                    .ok_or_else(|| anyhow!("channel {name} used in {kind} route not found"))?;
+                channels.push(channel.clone());
+            }
+            routes.insert(kind.clone(), channels);
+        }
+
+        // Job published announcements are not sent by email. The legacy Slack
+        // webhook is used for them if no route has been defined.
+        if let Entry::Vacant(entry) = routes.entry(NotificationKind::JobPublished) {
+            let mut channels = vec![];
+            if let Some(webhook_url) = &cfg.server.slack_webhook_url {
+                let channel_cfg = ChannelConfig::Slack {
+                    webhook_url: webhook_url.clone(),
+                };
+                channels.push(Self::setup_channel(&channel_cfg, &cfg.email, &http_client)?);
+            }
+            entry.insert(channels);
+        }
+
+        Ok(Self {
+            default: vec![available[EMAIL_CHANNEL].clone()],
+            routes,
+        })
+    }
+
+    /// Send the message provided through all the channels configured for its
+    /// kind. Delivery is attempted on all of them even if some fail.
+    pub(crate) async fn send(&self, message: &Message) -> Result<()> {
+        let mut errors = vec![];
+        for channel in self.get(&message.kind) {
+            if let Err(err) = channel.send(message).await {
+                errors.push(err.to_string());
+            }
+        }
+        if !errors.is_empty() {
+            bail!(errors.join("; "));
+        }
+
+        Ok(())
+    }
+
+    /// Return the channels configured for the notification kind provided.
+    fn get(&self, kind: &NotificationKind) -> &[DynChannel] {
+        self.routes.get(kind).unwrap_or(&self.default)
+    }
+
+    /// Setup a channel from its configuration.
+    fn setup_channel(
+        channel_cfg: &ChannelConfig,
+        email_cfg: &EmailConfig,
+        http_client: &reqwest::Client,
+    ) -> Result<DynChannel> {
+        let channel: DynChannel = match channel_cfg {
+            ChannelConfig::Discord { webhook_url } => Arc::new(DiscordChannel {
+                http_client: http_client.clone(),
+                webhook_url: webhook_url.clone(),
+            }),
+            ChannelConfig::Email => Arc::new(EmailChannel::new(email_cfg)?),
+            ChannelConfig::Matrix {
+                access_token,
+                homeserver_url,
+                room_id,
+            } => Arc::new(MatrixChannel {
+                access_token: access_token.clone(),
+                homeserver_url: Url::parse(homeserver_url)?,
+                http_client: http_client.clone(),
+                room_id: room_id.clone(),
+            }),
+            ChannelConfig::Slack { webhook_url } => Arc::new(SlackChannel {
+                http_client: http_client.clone(),
+                webhook_url: webhook_url.clone(),
+            }),
+            ChannelConfig::Webhook { url } => Arc::new(WebhookChannel {
+                http_client: http_client.clone(),
+                url: url.clone(),
+            }),
+        };
+
+        Ok(channel)
+    }
+}
+
+/// Message to deliver through a channel.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Message {
+    /// Message body.
+    pub body: String,
+    /// Format of the message body.
+    pub format: MessageFormat,
+    /// Kind of notification the message belongs to.
+    pub kind: NotificationKind,
+    /// Message subject.
+    pub subject: String,
+
+    /// Plain text alternative of the body, if available.
+    pub text: Option<String>,
+    /// Email address of the recipient, if the message is addressed to a user.
+    pub to: Option<String>,
+    /// URL to unsubscribe the recipient from the notifications of this kind,
+    /// if they can opt out of them.
+    pub unsubscribe_url: Option<String>,
+}
+
+impl Message {
+    /// Return the text to post in chat channels. Chat channels support
+    /// Markdown bodies only, so the plain text alternative (or the subject
+    /// when there isn't one) is used for HTML messages.
+    fn chat_text(&self) -> &str {
+        match self.format {
+            MessageFormat::Html => self.text.as_deref().unwrap_or(&self.subject),
+            MessageFormat::Markdown => &self.body,
+        }
+    }
+}
+
+/// Supported message body formats.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum MessageFormat {
+    /// HTML body.
+    Html,
+    /// Markdown body.
+    Markdown,
+}
+
+/// Channel that posts messages to a Discord webhook.
+struct DiscordChannel {
+    /// HTTP client used to post the messages.
+    http_client: reqwest::Client,
+    /// Discord webhook URL.
+    webhook_url: String,
+}
+
+#[async_trait]
+impl Channel for DiscordChannel {
+    async fn send(&self, message: &Message) -> Result<()> {
+        let content: String = message.chat_text().chars().take(DISCORD_MAX_CONTENT_LEN).collect();
+        let payload = json!({
+            "content": content,
+        });
+        self.http_client
+            .post(&self.webhook_url)
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Channel that sends messages by email using a SMTP server.
+struct EmailChannel {
+    /// Sender mailbox.
+    from: Mailbox,
+    /// SMTP client for sending emails.
+    smtp_client: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl EmailChannel {
+    /// Create a new `EmailChannel`.
+    fn new(cfg: &EmailConfig) -> Result<Self> {
+        let smtp_client = AsyncSmtpTransport::<Tokio1Executor>::relay(&cfg.smtp.host)?
+            .credentials(Credentials::new(
+                cfg.smtp.username.clone(),
+                cfg.smtp.password.clone(),
+            ))
+            .build();
+
+        Ok(Self {
+            from: Mailbox::new(Some(cfg.from_name.clone()), cfg.from_address.parse()?),
+            smtp_client,
+        })
+    }
+}
+
+#[async_trait]
+impl Channel for EmailChannel {
+    async fn send(&self, message: &Message) -> Result<()> {
+        let Some(to_address) = &message.to else {
+            bail!("email channel requires a recipient");
+        };
+
+        // Prepare message (HTML messages include the plain text alternative
+        // when available)
+        let builder = MessageBuilder::new()
+            .from(self.from.clone())
+            .to(to_address.parse()?)
+            .subject(&message.subject);
+        let mut email = match (&message.format, &message.text) {
+            (MessageFormat::Html, Some(text)) => builder.multipart(MultiPart::alternative_plain_html(
+                text.clone(),
+                message.body.clone(),
+            ))?,
+            (MessageFormat::Html, None) => {
+                builder.header(ContentType::TEXT_HTML).body(message.body.clone())?
+            }
+            (MessageFormat::Markdown, _) => {
+                builder.header(ContentType::TEXT_PLAIN).body(message.body.clone())?
+            }
+        };
+        if let Some(unsubscribe_url) = &message.unsubscribe_url {
+            // One-click unsubscribe (RFC 8058)
+            let headers = email.headers_mut();
+            headers.insert_raw(HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe"),
+                format!("<{unsubscribe_url}>"),
+            ));
+            headers.insert_raw(HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                "List-Unsubscribe=One-Click".to_string(),
+            ));
+        }
+
+        // Send email
+        self.smtp_client.send(email).await?;
+
+        Ok(())
+    }
+}
+
+/// Channel that posts messages to a Matrix room.
+struct MatrixChannel {
+    /// Access token of the user posting the messages.
+    access_token: String,
+    /// Homeserver URL.
+    homeserver_url: Url,
+    /// HTTP client used to post the messages.
+    http_client: reqwest::Client,
+    /// Room ID.
+    room_id: String,
+}
+
+#[async_trait]
+impl Channel for MatrixChannel {
+    async fn send(&self, message: &Message) -> Result<()> {
+        // Prepare room message endpoint url
+        let txn_id = Uuid::new_v4().to_string();
+        let mut url = self.homeserver_url.clone();
+        url.path_segments_mut()
+            .map_err(|()| anyhow!("invalid homeserver url"))?
+            .pop_if_empty()
+            .extend([
+                "_matrix",
+                "client",
+                "v3",
+                "rooms",
+                &self.room_id,
+                "send",
+                "m.room.message",
+                &txn_id,
+            ]);
+
+        // Send message
+        let formatted_body = match message.format {
+            MessageFormat::Html => message.body.clone(),
+            MessageFormat::Markdown => markdown::to_html(&message.body),
+        };
+        let payload = json!({
+            "msgtype": "m.text",
+            "body": message.chat_text(),
+            "format": "org.matrix.custom.html",
+            "formatted_body": formatted_body,
+        });
+        self.http_client
+            .put(url)
+            .bearer_auth(&self.access_token)
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Channel that posts messages to a Slack webhook.
+struct SlackChannel {
+    /// HTTP client used to post the messages.
+    http_client: reqwest::Client,
+    /// Slack webhook URL.
+    webhook_url: String,
+}
+
+#[async_trait]
+impl Channel for SlackChannel {
+    async fn send(&self, message: &Message) -> Result<()> {
+        let payload = json!({
+            "text": message.chat_text(),
+        });
+        self.http_client
+            .post(&self.webhook_url)
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Channel that posts messages to a generic webhook as JSON.
+struct WebhookChannel {
+    /// HTTP client used to post the messages.
+    http_client: reqwest::Client,
+    /// Webhook URL.
+    url: String,
+}
+
+#[async_trait]
+impl Channel for WebhookChannel {
+    async fn send(&self, message: &Message) -> Result<()> {
+        let payload = WebhookPayload::from(message);
+        self.http_client
+            .post(&self.url)
+            .json(&payload)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
+
+/// Payload posted to generic webhooks.
+///
+/// The recipient's email address and unsubscribe link are not included, as
+/// the webhook endpoint is usually operated by a third party.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct WebhookPayload<'a> {
+    /// Message body.
+    body: &'a str,
+    /// Format of the message body.
+    format: &'a MessageFormat,
+    /// Kind of notification the message belongs to.
+    kind: &'a NotificationKind,
+    /// Message subject.
+    subject: &'a str,
+
+    /// Plain text alternative of the body, if available.
+    text: Option<&'a str>,
+}
+
+impl<'a> From<&'a Message> for WebhookPayload<'a> {
+    fn from(message: &'a Message) -> Self {
+        Self {
+            body: &message.body,
+            format: &message.format,
+            kind: &message.kind,
+            subject: &message.subject,
+            text: message.text.as_deref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json, Router,
+        extract::State,
+        http::{HeaderMap, StatusCode, Uri},
+    };
+    use serde_json::Value;
+    use tokio::{net::TcpListener, sync::mpsc};
+
+    use crate::config::{
+        HttpServerConfig, LogConfig, LogFormat, LoginOptions, NotificationsConfig, SmtpConfig,
+    };
+
+    use super::*;
+
+    /// Request received by the local HTTP stand-in.
+    #[derive(Debug)]
+    struct ReceivedRequest {
+        authorization: Option<String>,
+        body: Value,
+        path: String,
+    }
+
+    /// Launch a local HTTP server that records the requests received, replying
+    /// with the status code provided. Returns its base url and a receiver for
+    /// the requests.
+    async fn setup_http_stand_in(status: StatusCode) -> (String, mpsc::UnboundedReceiver<ReceivedRequest>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let router = Router::new()
+            .fallback(
+                move |State(tx): State<mpsc::UnboundedSender<ReceivedRequest>>,
+                      uri: Uri,
+                      headers: HeaderMap,
+                      Json(body): Json<Value>| async move {
+                    let authorization = headers
+                        .get("authorization")
+                        .and_then(|v| v.to_str().ok())
+                        .map(ToString::to_string);
+                    tx.send(ReceivedRequest {
+                        authorization,
+                        body,
+                        path: uri.path().to_string(),
+                    })
+                    .unwrap();
+                    status
+                },
+            )
+            .with_state(tx);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, router).await.unwrap();
+        });
+
+        (format!("http://{addr}"), rx)
+    }
+
+    fn create_config(notifications: NotificationsConfig) -> Config {
+        Config {
+            db: deadpool_postgres::Config::default(),
+            email: EmailConfig {
+                from_address: "gitjobs@example.com".to_string(),
+                from_name: "GitJobs".to_string(),
+                smtp: SmtpConfig {
+                    host: "localhost".to_string(),
+                    port: 465,
+                    username: "user".to_string(),
+                    password: "pass".to_string(),
+                },
+                batch_size: 10,
+                poll_interval_secs: 15,
+                workers: 1,
+            },
+            log: LogConfig {
+                format: LogFormat::Pretty,
+            },
+            server: HttpServerConfig {
+                addr: "127.0.0.1:9000".to_string(),
+                base_url: "https://example.com".to_string(),
+                login: LoginOptions {
+                    email: true,
+                    github: false,
+                    linuxfoundation: false,
+                },
+                oauth2: HashMap::new(),
+                oidc: HashMap::new(),
+                unsubscribe_secret: "secret".to_string(),
+                analytics: None,
+                basic_auth: None,
+                cookie: None,
+                slack_webhook_url: None,
+            },
+            notifications,
+        }
+    }
+
+    fn create_message(kind: NotificationKind, format: MessageFormat) -> Message {
+        Message {
+            body: "New job published: *Software Engineer*".to_string(),
+            format,
+            kind,
+            subject: "New job published".to_string(),
+            text: None,
+            to: None,
+            unsubscribe_url: None,
+        }
+    }
+
+    fn http_client() -> reqwest::Client {
+        reqwest::Client::new()
+    }
+
+    #[tokio::test]
+    async fn discord_channel_posts_content() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::NO_CONTENT).await;
+        let channel = DiscordChannel {
+            http_client: http_client(),
+            webhook_url: format!("{url}/discord"),
+        };
+
+        let message = create_message(NotificationKind::JobPublished, MessageFormat::Markdown);
+        channel.send(&message).await.unwrap();
+
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.path, "/discord");
+        assert_eq!(request.body, json!({"content": message.body}));
+    }
+
+    #[tokio::test]
+    async fn matrix_channel_sends_room_message() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::OK).await;
+        let channel = MatrixChannel {
+            access_token: "token".to_string(),
+            homeserver_url: Url::parse(&format!("{url}/")).unwrap(),
+            http_client: http_client(),
+            room_id: "!room:example.com".to_string(),
+        };
+
+        let message = create_message(NotificationKind::JobPublished, MessageFormat::Markdown);
+        channel.send(&message).await.unwrap();
+
+        let request = rx.recv().await.unwrap();
+        assert!(
+            request
+                .path
+                .starts_with("/_matrix/client/v3/rooms/!room:example.com/send/m.room.message/")
+        );
+        assert_eq!(request.authorization.as_deref(), Some("Bearer token"));
+        assert_eq!(request.body["msgtype"], "m.text");
+        assert_eq!(request.body["body"], message.body);
+        assert_eq!(
+            request.body["formatted_body"],
+            "<p>New job published: <em>Software Engineer</em></p>"
+        );
+    }
+
+    #[tokio::test]
+    async fn slack_channel_posts_text() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::OK).await;
+        let channel = SlackChannel {
+            http_client: http_client(),
+            webhook_url: format!("{url}/slack"),
+        };
+
+        let message = create_message(NotificationKind::JobPublished, MessageFormat::Markdown);
+        channel.send(&message).await.unwrap();
+
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.path, "/slack");
+        assert_eq!(request.body, json!({"text": message.body}));
+    }
+
+    #[tokio::test]
+    async fn slack_channel_posts_subject_of_html_messages() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::OK).await;
+        let channel = SlackChannel {
+            http_client: http_client(),
+            webhook_url: format!("{url}/slack"),
+        };
+
+        let message = create_message(NotificationKind::JobApproved, MessageFormat::Html);
+        channel.send(&message).await.unwrap();
+
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.body, json!({"text": message.subject}));
+    }
+
+    #[tokio::test]
+    async fn slack_channel_posts_text_alternative_of_html_messages() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::OK).await;
+        let channel = SlackChannel {
+            http_client: http_client(),
+            webhook_url: format!("{url}/slack"),
+        };
+
+        let mut message = create_message(NotificationKind::JobApproved, MessageFormat::Html);
+        message.text = Some("Your job has been approved".to_string());
+        channel.send(&message).await.unwrap();
+
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.body, json!({"text": "Your job has been approved"}));
+    }
+
+    #[tokio::test]
+    async fn webhook_channel_posts_message_as_json() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::OK).await;
+        let channel = WebhookChannel {
+            http_client: http_client(),
+            url: format!("{url}/hook"),
+        };
+
+        let mut message = create_message(NotificationKind::JobApproved, MessageFormat::Html);
+        message.text = Some("Text".to_string());
+        message.to = Some("user@example.com".to_string());
+        message.unsubscribe_url = Some("https://example.com/notifications/unsubscribe".to_string());
+        channel.send(&message).await.unwrap();
+
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.path, "/hook");
+        assert_eq!(
+            request.body,
+            json!({
+                "body": message.body,
+                "format": "html",
+                "kind": "job-approved",
+                "subject": message.subject,
+                "text": "Text",
+            })
+        );
+        assert!(request.body.get("to").is_none());
+        assert!(request.body.get("unsubscribe_url").is_none());
+    }
+
+    #[tokio::test]
+    async fn webhook_channel_fails_on_error_status() {
+        let (url, _rx) = setup_http_stand_in(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let channel = WebhookChannel {
+            http_client: http_client(),
+            url,
+        };
+
+        let message = create_message(NotificationKind::JobApproved, MessageFormat::Html);
+        assert!(channel.send(&message).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn email_channel_requires_recipient() {
+        let cfg = create_config(NotificationsConfig::default());
+        let channel = EmailChannel::new(&cfg.email).unwrap();
+
+        let message = create_message(NotificationKind::JobApproved, MessageFormat::Html);
+        assert!(channel.send(&message).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn channels_route_messages_per_kind() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::OK).await;
+        let cfg = create_config(NotificationsConfig {
+            channels: HashMap::from([
+                (
+                    "hook".to_string(),
+                    ChannelConfig::Webhook {
+                        url: format!("{url}/hook"),
+                    },
+                ),
+                (
+                    "slack".to_string(),
+                    ChannelConfig::Slack {
+                        webhook_url: format!("{url}/slack"),
+                    },
+                ),
+            ]),
+            routes: HashMap::from([
+                (
+                    NotificationKind::JobPublished,
+                    vec!["slack".to_string(), "hook".to_string()],
+                ),
+                (NotificationKind::NewApplication, vec!["hook".to_string()]),
+            ]),
+        });
+        let channels = Channels::new(&cfg).unwrap();
+
+        // Routed kinds are delivered through all their channels
+        let message = create_message(NotificationKind::JobPublished, MessageFormat::Markdown);
+        channels.send(&message).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap().path, "/slack");
+        assert_eq!(rx.recv().await.unwrap().path, "/hook");
+
+        let message = create_message(NotificationKind::NewApplication, MessageFormat::Html);
+        channels.send(&message).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap().path, "/hook");
+
+        // Other kinds use the email channel
+        assert_eq!(channels.get(&NotificationKind::EmailVerification).len(), 1);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn channels_use_legacy_slack_webhook_for_job_published() {
+        let (url, mut rx) = setup_http_stand_in(StatusCode::OK).await;
+        let mut cfg = create_config(NotificationsConfig::default());
+        cfg.server.slack_webhook_url = Some(format!("{url}/slack"));
+        let channels = Channels::new(&cfg).unwrap();
+
+        let message = create_message(NotificationKind::JobPublished, MessageFormat::Markdown);
+        channels.send(&message).await.unwrap();
+        assert_eq!(rx.recv().await.unwrap().path, "/slack");
+    }
+
+    #[tokio::test]
+    async fn channels_skip_job_published_when_not_configured() {
+        let cfg = create_config(NotificationsConfig::default());
+        let channels = Channels::new(&cfg).unwrap();
+
+        assert!(channels.get(&NotificationKind::JobPublished).is_empty());
+        let message = create_message(NotificationKind::JobPublished, MessageFormat::Markdown);
+        channels.send(&message).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn channels_fail_on_unknown_channel_in_route() {
+        let cfg = create_config(NotificationsConfig {
+            channels: HashMap::new(),
+            routes: HashMap::from([(NotificationKind::JobApproved, vec!["missing".to_string()])]),
+        });
+
+        assert!(Channels::new(&cfg).is_err());
+    }
+}
@@ -0,0 +1,538 @@
+//! This module defines types and logic to manage and send user notifications.
+
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{Result, anyhow};
+use askama::Template;
+use async_trait::async_trait;
+use channels::{Channels, Message, MessageFormat};
+use futures::{StreamExt, future, stream};
+#[cfg(test)]
+use mockall::automock;
+use postgres_openssl::MakeTlsConnector;
+use serde::{Deserialize, Serialize};
+use tokio::{sync::Notify, time::sleep};
+use tokio_postgres::AsyncMessage;
+use tokio_util::{sync::CancellationToken, task::TaskTracker};
+use tracing::{error, instrument};
+use unsubscribe::UnsubscribeLink;
+use uuid::Uuid;
+
+use crate::{
+    config::Config,
+    db::DynDB,
+    templates::notifications::{
+        EmailTemplate, EmailVerification, JobAlertDigest, JobApproved, JobExpiring, JobPublished,
+        JobRejected, NewApplication, TeamInvitation,
+    },
+};
+
+pub(crate) mod channels;
+pub(crate) mod unsubscribe;
+
+/// Subject of the job published announcements.
+const JOB_PUBLISHED_SUBJECT: &str = "New job published";
+
+/// Maximum number of delivery attempts before a notification is dead-lettered.
+pub(crate) const MAX_DELIVERY_ATTEMPTS: i32 = 5;
+
+/// Channel used to notify the listener that new notifications were enqueued.
+const NOTIFICATION_ENQUEUED_CHANNEL: &str = "notification_enqueued";
+
+/// Time after which a notification claimed for delivery can be claimed again,
+/// in seconds. Claims are released once the delivery attempt is recorded, so
+/// this only applies when a worker stops before doing it.
+pub(crate) const CLAIM_TIMEOUT_SECS: i64 = 900;
+
+/// Time to wait after an error before retrying.
+const PAUSE_ON_ERROR: Duration = Duration::from_secs(30);
+
+/// Time to wait before retrying a failed delivery, in seconds. It is doubled
+/// after each failed attempt (5m, 10m, 20m, 40m).
+pub(crate) const RETRY_BACKOFF_SECS: i64 = 300;
+
+/// Trait for a notifications manager, responsible for delivering notifications.
+#[async_trait]
+#[cfg_attr(test, automock)]
+pub(crate) trait NotificationsManager {
+    /// Deliver a message immediately through the channels configured for
+    /// its kind, without enqueuing it.
+    async fn deliver(&self, message: &Message) -> Result<()>;
+
+    /// Enqueue a notification for delivery, unless the user has opted out
+    /// of its kind.
+    async fn enqueue(&self, notification: &NewNotification) -> Result<()>;
+}
+
+/// Shared trait object for a notifications manager.
+pub(crate) type DynNotificationsManager = Arc<dyn NotificationsManager + Send + Sync>;
+
+/// PostgreSQL-backed notifications manager implementation.
+pub(crate) struct PgNotificationsManager {
+    /// Handle to the database for notification operations.
+    db: DynDB,
+    /// Channels used to deliver the notifications.
+    channels: Arc<Channels>,
+}
+
+impl PgNotificationsManager {
+    /// Create a new `PgNotificationsManager`.
+    pub(crate) fn new(
+        db: DynDB,
+        cfg: &Config,
+        channels: Channels,
+        pg_cfg: tokio_postgres::Config,
+        tls: MakeTlsConnector,
+        task_tracker: &TaskTracker,
+        cancellation_token: &CancellationToken,
+    ) -> Self {
+        let channels = Arc::new(channels);
+        let wake_up = Arc::new(Notify::new());
+
+        // Setup and run a listener to wake up the workers when new
+        // notifications are enqueued
+        let listener = Listener {
+            pg_cfg,
+            tls,
+            wake_up: wake_up.clone(),
+            cancellation_token: cancellation_token.clone(),
+        };
+        task_tracker.spawn(async move {
+            listener.run().await;
+        });
+
+        // Setup and run some workers to deliver notifications
+        for _ in 1..=cfg.email.workers.max(1) {
+            let mut worker = Worker {
+                db: db.clone(),
+                channels: channels.clone(),
+                base_url: cfg.server.base_url.clone(),
+                batch_size: cfg.email.batch_size.max(1),
+                poll_interval: Duration::from_secs(cfg.email.poll_interval_secs),
+                unsubscribe_secret: cfg.server.unsubscribe_secret.clone(),
+                wake_up: wake_up.clone(),
+                cancellation_token: cancellation_token.clone(),
+            };
+            task_tracker.spawn(async move {
+                worker.run().await;
+            });
+        }
+
+        Self { db, channels }
+    }
+}
+
+#[async_trait]
+impl NotificationsManager for PgNotificationsManager {
+    /// Deliver a message immediately through the channels configured for
+    /// its kind, without enqueuing it.
+    async fn deliver(&self, message: &Message) -> Result<()> {
+        self.channels.send(message).await
+    }
+
+    /// Enqueue a notification for delivery, unless the user has opted out
+    /// of its kind.
+    async fn enqueue(&self, notification: &NewNotification) -> Result<()> {
+        if notification.kind.is_optional()
+            && !self
+                .db
+                .is_notification_enabled(&notification.user_id, &notification.kind)
+                .await?
+        {
+            return Ok(());
+        }
+        self.db.enqueue_notification(notification).await
+    }
+}
+
+/// Listener responsible for waking up the workers when new notifications are
+/// enqueued, using `PostgreSQL` `LISTEN/NOTIFY`.
+struct Listener {
+    /// Configuration of the dedicated connection used to listen.
+    pg_cfg: tokio_postgres::Config,
+    /// TLS connector used to connect to the database.
+    tls: MakeTlsConnector,
+    /// Used to wake up the workers waiting for new notifications.
+    wake_up: Arc<Notify>,
+    /// Token to signal listener shutdown.
+    cancellation_token: CancellationToken,
+}
+
+impl Listener {
+    /// Main listener loop: listens for new notifications until cancelled,
+    /// reconnecting when the connection is lost.
+    async fn run(&self) {
+        loop {
+            if let Err(err) = self.listen().await {
+                error!("error listening for new notifications: {err}");
+            }
+
+            // Pause before reconnecting unless we've been asked to stop
+            tokio::select! {
+                () = sleep(PAUSE_ON_ERROR) => {},
+                () = self.cancellation_token.cancelled() => break,
+            }
+        }
+    }
+
+    /// Listen for new notifications on a dedicated connection, waking up the
+    /// workers each time one is received.
+    async fn listen(&self) -> Result<()> {
+        let (client, mut connection) = self.pg_cfg.connect(self.tls.clone()).await?;
+
+        // The connection must be polled to receive the notifications (and for
+        // the client to make progress)
+        let wake_up = self.wake_up.clone();
+        let mut connection_task = tokio::spawn(async move {
+            let mut messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+            while let Some(message) = messages.next().await {
+                if let AsyncMessage::Notification(_) = message? {
+                    wake_up.notify_waiters();
+                }
+            }
+            Ok::<(), tokio_postgres::Error>(())
+        });
+        client
+            .batch_execute(&format!("listen {NOTIFICATION_ENQUEUED_CHANNEL};"))
+            .await?;
+
+        // Some notifications may have been enqueued while we were connecting
+        self.wake_up.notify_waiters();
+
+        tokio::select! {
+            result = &mut connection_task => {
+                result??;
+                Err(anyhow!("connection closed"))
+            }
+            () = self.cancellation_token.cancelled() => {
+                connection_task.abort();
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Worker responsible for delivering notifications from the queue.
+struct Worker {
+    /// Database handle for notification queries.
+    db: DynDB,
+    /// Channels used to deliver the notifications.
+    channels: Arc<Channels>,
+    /// Base URL used to build the unsubscribe links.
+    base_url: String,
+    /// Maximum number of notifications to deliver at once.
+    batch_size: i64,
+    /// Time to wait when there are no notifications to deliver.
+    poll_interval: Duration,
+    /// Secret used to sign the unsubscribe links.
+    unsubscribe_secret: String,
+    /// Notified when new notifications are enqueued.
+    wake_up: Arc<Notify>,
+    /// Token to signal worker shutdown.
+    cancellation_token: CancellationToken,
+}
+
+impl Worker {
+    /// Main worker loop: delivers notifications until cancelled.
+    async fn run(&mut self) {
+        loop {
+            // Register for wake ups before checking the queue, so that we
+            // don't miss the notifications enqueued in the meantime
+            let wake_up = self.wake_up.clone();
+            let notified = wake_up.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            // Try to deliver a batch of pending notifications
+            match self.deliver_notifications().await {
+                Ok(0) => tokio::select! {
+                    // No pending notifications, pause until new ones are
+                    // enqueued unless we've been asked to stop
+                    () = sleep(self.poll_interval) => {},
+                    () = notified => {},
+                    () = self.cancellation_token.cancelled() => break,
+                },
+                Ok(_) => {
+                    // Some notifications were delivered, try to deliver more
+                    // immediately
+                }
+                Err(err) => {
+                    // Something went wrong delivering the notifications, pause
+                    // unless we've been asked to stop
+                    error!("error delivering notifications: {err}");
+                    tokio::select! {
+                        () = sleep(PAUSE_ON_ERROR) => {},
+                        () = self.cancellation_token.cancelled() => break,
+                    }
+                }
+            }
+
+            // Exit if the worker has been asked to stop
+            if self.cancellation_token.is_cancelled() {
+                break;
+            }
+        }
+    }
+
+    /// Attempt to deliver a batch of pending notifications, if available.
+    /// Returns the number of notifications processed.
+    ///
+    /// Notifications are claimed before being delivered, so that no database
+    /// locks are held while the messages are being sent.
+    #[instrument(skip(self), err)]
+    async fn deliver_notifications(&mut self) -> Result<usize> {
+        // Claim pending notifications
+        let notifications = self.db.claim_pending_notifications(self.batch_size).await?;
+        if notifications.is_empty() {
+            return Ok(0);
+        }
+
+        // Deliver notifications concurrently
+        let results = future::join_all(
+            notifications
+                .iter()
+                .map(|notification| self.deliver_notification(notification)),
+        )
+        .await;
+
+        // Update notifications with the results
+        let client_id = self.db.tx_begin().await?;
+        for (notification, result) in notifications.iter().zip(results) {
+            let err = match result {
+                Ok(()) => None,
+                Err(err) => {
+                    if notification.attempts + 1 >= MAX_DELIVERY_ATTEMPTS {
+                        error!(
+                            notification_id = %notification.notification_id,
+                            "notification dead-lettered after {MAX_DELIVERY_ATTEMPTS} attempts: {err}"
+                        );
+                    }
+                    Some(err.to_string())
+                }
+            };
+            if let Err(err) = self.db.update_notification(client_id, notification, err).await {
+                self.db.tx_rollback(client_id).await?;
+                return Err(err);
+            }
+        }
+
+        // Commit transaction
+        self.db.tx_commit(client_id).await?;
+
+        Ok(notifications.len())
+    }
+
+    /// Deliver a notification through the channels configured for its kind.
+    async fn deliver_notification(&self, notification: &Notification) -> Result<()> {
+        let message = self.prepare_message(notification)?;
+        self.channels.send(&message).await
+    }
+
+    /// Prepare the message to deliver for a notification. Messages of the
+    /// kinds users can opt out of include a link to unsubscribe.
+    fn prepare_message(&self, notification: &Notification) -> Result<Message> {
+        let template_data = notification
+            .template_data
+            .clone()
+            .ok_or_else(|| anyhow!("missing template data"))?;
+        let unsubscribe_url = notification.kind.is_optional().then(|| {
+            UnsubscribeLink::new(
+                &self.unsubscribe_secret,
+                notification.user_id,
+                notification.kind.clone(),
+            )
+            .url(&self.base_url)
+        });
+
+        let email = match notification.kind {
+            NotificationKind::EmailVerification => {
+                let template: EmailVerification = serde_json::from_value(template_data)?;
+                template.render_email()?
+            }
+            NotificationKind::JobAlertDigest => {
+                let mut template: JobAlertDigest = serde_json::from_value(template_data)?;
+                template.unsubscribe_link.clone_from(&unsubscribe_url);
+                template.render_email()?
+            }
+            NotificationKind::JobApproved => {
+                let mut template: JobApproved = serde_json::from_value(template_data)?;
+                template.unsubscribe_link.clone_from(&unsubscribe_url);
+                template.render_email()?
+            }
+            NotificationKind::JobExpiring => {
+                let mut template: JobExpiring = serde_json::from_value(template_data)?;
+                template.unsubscribe_link.clone_from(&unsubscribe_url);
+                template.render_email()?
+            }
+            NotificationKind::JobPublished => {
+                // Job published announcements are delivered directly, they
+                // are never enqueued
+                return Err(anyhow!("job published notifications can't be enqueued"));
+            }
+            NotificationKind::JobRejected => {
+                let mut template: JobRejected = serde_json::from_value(template_data)?;
+                template.unsubscribe_link.clone_from(&unsubscribe_url);
+                template.render_email()?
+            }
+            NotificationKind::NewApplication => {
+                let mut template: NewApplication = serde_json::from_value(template_data)?;
+                template.unsubscribe_link.clone_from(&unsubscribe_url);
+                template.render_email()?
+            }
+            NotificationKind::TeamInvitation => {
+                let template: TeamInvitation = serde_json::from_value(template_data)?;
+                template.render_email()?
+            }
+        };
+
+        Ok(Message {
+            body: email.html,
+            format: MessageFormat::Html,
+            kind: notification.kind.clone(),
+            subject: email.subject,
+            text: Some(email.text),
+            to: Some(notification.email.clone()),
+            unsubscribe_url,
+        })
+    }
+}
+
+/// Announce that a job has been published through the channels configured
+/// for the job published notifications.
+pub(crate) async fn announce_job_published(
+    db: &DynDB,
+    notifications_manager: &DynNotificationsManager,
+    base_url: &str,
+    job_id: &Uuid,
+) -> Result<()> {
+    let Some(job) = db.get_job_jobboard(job_id).await? else {
+        return Ok(());
+    };
+    let template = JobPublished {
+        base_url: base_url.strip_suffix('/').unwrap_or(base_url).to_string(),
+        job,
+    };
+    let message = Message {
+        body: template.render()?,
+        format: MessageFormat::Markdown,
+        kind: NotificationKind::JobPublished,
+        subject: JOB_PUBLISHED_SUBJECT.to_string(),
+        text: None,
+        to: None,
+        unsubscribe_url: None,
+    };
+    notifications_manager.deliver(&message).await
+}
+
+/// Enqueue a notification of the kind provided for each of the recipients.
+///
+/// The action that triggered the notifications has already been completed
+/// when this is called, so errors are logged instead of returned.
+pub(crate) async fn notify_recipients<T: Serialize>(
+    notifications_manager: &DynNotificationsManager,
+    kind: NotificationKind,
+    recipients: &[Uuid],
+    template_data: &T,
+) {
+    let template_data = match serde_json::to_value(template_data) {
+        Ok(template_data) => template_data,
+        Err(err) => {
+            error!(%kind, "error preparing notification template data: {err}");
+            return;
+        }
+    };
+    for user_id in recipients {
+        let notification = NewNotification {
+            kind: kind.clone(),
+            user_id: *user_id,
+            template_data: Some(template_data.clone()),
+        };
+        if let Err(err) = notifications_manager.enqueue(&notification).await {
+            error!(%kind, %user_id, "error enqueuing notification: {err}");
+        }
+    }
+}
+
+/// Data required to create a new notification for a user.
+#[derive(Debug, Clone)]
+pub(crate) struct NewNotification {
+    /// The type of notification to send.
+    pub kind: NotificationKind,
+    /// The user ID to notify.
+    pub user_id: Uuid,
+    /// Optional template data for the notification content.
+    pub template_data: Option<serde_json::Value>,
+}
+
+/// Data required to deliver a notification to a user.
+#[derive(Debug, Clone)]
+pub(crate) struct Notification {
+    /// Unique identifier for the notification.
+    pub notification_id: Uuid,
+    /// Number of delivery attempts made so far.
+    pub attempts: i32,
+    /// Email address to send the notification to.
+    pub email: String,
+    /// The type of notification.
+    pub kind: NotificationKind,
+    /// User the notification is addressed to.
+    pub user_id: Uuid,
+    /// Optional template data for the notification content.
+    pub template_data: Option<serde_json::Value>,
+}
+
+/// Supported notification types.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum NotificationKind {
+    /// Notification for email verification.
+    EmailVerification,
+    /// Notification with the new jobs matching a saved search.
+    JobAlertDigest,
+    /// Notification for a job approved by the moderators.
+    JobApproved,
+    /// Notification for a job that is about to close.
+    JobExpiring,
+    /// Announcement of a job published for the first time. These are not
+    /// addressed to any user, so they are delivered directly instead of
+    /// being enqueued.
+    JobPublished,
+    /// Notification for a job rejected by the moderators.
+    JobRejected,
+    /// Notification for a new application to a job of the employer.
+    NewApplication,
+    /// Notification for a team invitation.
+    TeamInvitation,
+}
+
+impl NotificationKind {
+    /// Short description of the notifications of this kind, as displayed to
+    /// the users.
+    pub(crate) fn description(&self) -> &'static str {
+        match self {
+            NotificationKind::EmailVerification => "email verification",
+            NotificationKind::JobAlertDigest => "job alerts",
+            NotificationKind::JobApproved => "job approved",
+            NotificationKind::JobExpiring => "job closing soon",
+            NotificationKind::JobPublished => "job published",
+            NotificationKind::JobRejected => "job rejected",
+            NotificationKind::NewApplication => "new application",
+            NotificationKind::TeamInvitation => "team invitation",
+        }
+    }
+
+    /// Check if users can opt out of the notifications of this kind.
+    /// Transactional notifications, like the email verification ones, are
+    /// always delivered.
+    pub(crate) fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            NotificationKind::JobAlertDigest
+                | NotificationKind::JobApproved
+                | NotificationKind::JobExpiring
+                | NotificationKind::JobRejected
+                | NotificationKind::NewApplication
+        )
+    }
+}
@@ -0,0 +1,122 @@
+//! This module defines the signed links used to unsubscribe from notifications
+//! with a single click, without having to log in.
+
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use super::NotificationKind;
+
+/// Path of the unsubscribe endpoint.
+pub(crate) const UNSUBSCRIBE_PATH: &str = "/notifications/unsubscribe";
+
+/// Signed link to unsubscribe a user from a notification kind.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct UnsubscribeLink {
+    /// Kind of notification to unsubscribe from.
+    pub kind: NotificationKind,
+    /// Signature of the user and kind (HMAC-SHA256, hex encoded).
+    pub signature: String,
+    /// User to unsubscribe.
+    pub user_id: Uuid,
+}
+
+impl UnsubscribeLink {
+    /// Create a new unsubscribe link for the user and kind provided, signed
+    /// with the secret provided.
+    pub(crate) fn new(secret: &str, user_id: Uuid, kind: NotificationKind) -> Self {
+        let signature = hex::encode(new_mac(secret, &user_id, &kind).finalize().into_bytes());
+
+        Self {
+            kind,
+            signature,
+            user_id,
+        }
+    }
+
+    /// Returns the URL of the unsubscribe link.
+    pub(crate) fn url(&self, base_url: &str) -> String {
+        format!(
+            "{}{UNSUBSCRIBE_PATH}?kind={}&signature={}&user_id={}",
+            base_url.strip_suffix('/').unwrap_or(base_url),
+            self.kind,
+            self.signature,
+            self.user_id
+        )
+    }
+
+    /// Check if the link has been signed with the secret provided.
+    pub(crate) fn verify(&self, secret: &str) -> bool {
+        let Ok(signature) = hex::decode(&self.signature) else {
+            return false;
+        };
+        new_mac(secret, &self.user_id, &self.kind)
+            .verify_slice(&signature)
+            .is_ok()
+    }
+}
+
+/// Create a new MAC for the user and kind provided.
+fn new_mac(secret: &str, user_id: &Uuid, kind: &NotificationKind) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("any key size to be valid");
+    mac.update(format!("{user_id}:{kind}").as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "secret";
+
+    fn user_id() -> Uuid {
+        Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap()
+    }
+
+    #[test]
+    fn link_signed_with_same_secret_is_valid() {
+        let link = UnsubscribeLink::new(SECRET, user_id(), NotificationKind::JobAlertDigest);
+        assert!(link.verify(SECRET));
+    }
+
+    #[test]
+    fn link_signed_with_other_secret_is_not_valid() {
+        let link = UnsubscribeLink::new("other", user_id(), NotificationKind::JobAlertDigest);
+        assert!(!link.verify(SECRET));
+    }
+
+    #[test]
+    fn link_with_tampered_kind_is_not_valid() {
+        let mut link = UnsubscribeLink::new(SECRET, user_id(), NotificationKind::JobAlertDigest);
+        link.kind = NotificationKind::NewApplication;
+        assert!(!link.verify(SECRET));
+    }
+
+    #[test]
+    fn link_with_tampered_user_is_not_valid() {
+        let mut link = UnsubscribeLink::new(SECRET, user_id(), NotificationKind::JobAlertDigest);
+        link.user_id = Uuid::nil();
+        assert!(!link.verify(SECRET));
+    }
+
+    #[test]
+    fn link_with_malformed_signature_is_not_valid() {
+        let mut link = UnsubscribeLink::new(SECRET, user_id(), NotificationKind::JobAlertDigest);
+        link.signature = "not-hex".to_string();
+        assert!(!link.verify(SECRET));
+    }
+
+    #[test]
+    fn link_url() {
+        let link = UnsubscribeLink::new(SECRET, user_id(), NotificationKind::JobAlertDigest);
+        assert_eq!(
+            link.url("https://example.com/"),
+            format!(
+                "https://example.com/notifications/unsubscribe?kind=job-alert-digest&signature={}&user_id={}",
+                link.signature,
+                user_id()
+            )
+        );
+    }
+}
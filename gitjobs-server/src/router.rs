@@ -3,7 +3,7 @@
 use anyhow::Result;
 use axum::{
     Extension, Router,
-    extract::FromRef,
+    extract::{DefaultBodyLimit, FromRef},
     http::{
         HeaderValue, StatusCode, Uri,
         header::{CACHE_CONTROL, CONTENT_TYPE},
@@ -28,9 +28,13 @@ use crate::{
     db::DynDB,
     event_tracker::DynEventTracker,
     handlers::{
+        api,
         auth::{self, LOG_IN_URL},
-        dashboard, img, jobboard,
+        dashboard::{self, employer::jobs::MAX_IMPORT_FILE_SIZE},
+        img, jobboard,
         misc::{not_found, search_locations, search_members, search_projects, user_menu_section},
+        notifications,
+        resume::{self, MAX_RESUME_SIZE},
     },
     img::DynImageStore,
     notifications::DynNotificationsManager,
@@ -81,10 +85,12 @@ pub(crate) async fn setup(
     let auth_layer = crate::auth::setup_layer(&cfg, db).await?;
 
     // Setup sub-routers
+    let api_router = setup_api_router();
     let employer_dashboard_router = setup_employer_dashboard_router(&state);
     let job_seeker_dashboard_router = setup_job_seeker_dashboard_router();
     let moderator_dashboard_router = setup_moderator_dashboard_router(&state);
     let dashboard_images_router = setup_dashboard_images_router(&state);
+    let dashboard_resumes_router = setup_dashboard_resumes_router();
     let jobboard_images_router = setup_jobboard_images_router(&state);
 
     // Setup main router
@@ -93,6 +99,10 @@ pub(crate) async fn setup(
             "/dashboard/account/update/details",
             put(auth::update_user_details),
         )
+        .route(
+            "/dashboard/account/update/notifications",
+            put(auth::update_user_notifications_settings),
+        )
         .route(
             "/dashboard/account/update/password",
             put(auth::update_user_password),
@@ -101,6 +111,7 @@ pub(crate) async fn setup(
         .nest("/dashboard/images", dashboard_images_router)
         .nest("/dashboard/job-seeker", job_seeker_dashboard_router)
         .nest("/dashboard/moderator", moderator_dashboard_router)
+        .nest("/dashboard/resumes", dashboard_resumes_router)
         .route("/dashboard/members/search", get(search_members))
         .route("/jobs/{job_id}/apply", post(jobboard::jobs::apply))
         .route_layer(login_required!(
@@ -110,10 +121,15 @@ pub(crate) async fn setup(
         ))
         .route("/", get(jobboard::jobs::jobs_page))
         .route("/about", get(jobboard::about::page))
+        .nest("/api/v1", api_router)
         .route("/embed", get(jobboard::embed::jobs_page))
+        .route("/embed/job/{job_id}/card.png", get(jobboard::embed::job_card_png))
         .route("/embed/job/{job_id}/card.svg", get(jobboard::embed::job_card))
+        .route("/feeds/jobs.atom", get(jobboard::feeds::jobs_atom))
+        .route("/feeds/jobs.rss", get(jobboard::feeds::jobs_rss))
         .route("/health-check", get(health_check))
         .nest("/jobboard/images", jobboard_images_router)
+        .route("/jobs/{job_id}/{slug}", get(jobboard::jobs::job_page))
         .route("/jobs/{job_id}/views", post(jobboard::jobs::track_view))
         .route(
             "/jobs/search-appearances",
@@ -143,11 +159,17 @@ pub(crate) async fn setup(
     // Resume router setup.
     router = router
         .route("/log-out", get(auth::log_out))
+        .route(
+            "/notifications/unsubscribe",
+            get(notifications::unsubscribe_page).post(notifications::unsubscribe),
+        )
         .route("/projects/search", get(search_projects))
+        .route("/robots.txt", get(jobboard::seo::robots_txt))
         .route("/section/jobs/{job_id}", get(jobboard::jobs::job_section))
         .route("/section/jobs/results", get(jobboard::jobs::results_section))
         .route("/section/user-menu", get(user_menu_section))
         .route("/sign-up", get(auth::sign_up_page))
+        .route("/sitemap.xml", get(jobboard::seo::sitemap))
         .route("/stats", get(jobboard::stats::page))
         .route_layer(MessagesManagerLayer)
         .route_layer(auth_layer)
@@ -175,33 +197,76 @@ pub(crate) async fn setup(
     Ok(router.with_state(state))
 }
 
+/// Sets up the public API router and its routes.
+fn setup_api_router() -> Router<State> {
+    Router::new()
+        .route("/jobs", get(api::v1::jobs::search))
+        .route("/jobs/{job_id}", get(api::v1::jobs::get))
+}
+
 /// Sets up the employer dashboard router and its routes.
+#[allow(clippy::too_many_lines)]
 fn setup_employer_dashboard_router(state: &State) -> Router<State> {
     // Setup middleware
     let check_user_has_profile_access =
         middleware::from_fn_with_state(state.clone(), auth::user_has_profile_access);
+    let check_user_can_delete_job = middleware::from_fn_with_state(state.clone(), auth::user_can_delete_job);
+    let check_user_can_edit_job = middleware::from_fn_with_state(state.clone(), auth::user_can_edit_job);
+    let check_user_is_employer_admin =
+        middleware::from_fn_with_state(state.clone(), auth::user_is_employer_admin);
+    let check_user_is_employer_recruiter =
+        middleware::from_fn_with_state(state.clone(), auth::user_is_employer_recruiter);
     let check_user_owns_employer = middleware::from_fn_with_state(state.clone(), auth::user_owns_employer);
     let check_user_owns_job = middleware::from_fn_with_state(state.clone(), auth::user_owns_job);
 
     // Setup router
     Router::new()
         .route("/", get(dashboard::employer::home::page))
+        .route(
+            "/applications/export.csv",
+            get(dashboard::employer::applications::export_csv),
+        )
         .route(
             "/applications/list",
             get(dashboard::employer::applications::list_page),
         )
+        .route(
+            "/applications/profile/{profile_id}/feedback",
+            get(dashboard::employer::applications::feedback_section)
+                .layer(check_user_has_profile_access.clone()),
+        )
+        .route(
+            "/applications/profile/{profile_id}/notes",
+            post(dashboard::employer::applications::add_note)
+                .layer(check_user_has_profile_access.clone())
+                .layer(check_user_is_employer_recruiter.clone()),
+        )
         .route(
             "/applications/profile/{profile_id}/preview",
             get(dashboard::employer::applications::profile_preview_page)
                 .layer(check_user_has_profile_access.clone()),
         )
+        .route(
+            "/applications/profile/{profile_id}/rating",
+            put(dashboard::employer::applications::update_rating)
+                .layer(check_user_has_profile_access.clone())
+                .layer(check_user_is_employer_recruiter.clone()),
+        )
+        .route(
+            "/applications/{application_id}/status",
+            put(dashboard::employer::applications::update_status)
+                .layer(check_user_is_employer_recruiter.clone()),
+        )
+        .route("/audit-log", get(dashboard::audit::employer_list_page))
         .route(
             "/employers/add",
             get(dashboard::employer::employers::add_page).post(dashboard::employer::employers::add),
         )
         .route(
             "/employers/update",
-            get(dashboard::employer::employers::update_page).put(dashboard::employer::employers::update),
+            get(dashboard::employer::employers::update_page)
+                .put(dashboard::employer::employers::update)
+                .layer(check_user_is_employer_admin.clone()),
         )
         .route(
             "/employers/{employer_id}/select",
@@ -214,7 +279,16 @@ fn setup_employer_dashboard_router(state: &State) -> Router<State> {
         .route("/jobs/list", get(dashboard::employer::jobs::list_page))
         .route(
             "/jobs/add",
-            get(dashboard::employer::jobs::add_page).post(dashboard::employer::jobs::add),
+            get(dashboard::employer::jobs::add_page)
+                .post(dashboard::employer::jobs::add)
+                .layer(check_user_is_employer_recruiter.clone()),
+        )
+        .route(
+            "/jobs/import",
+            get(dashboard::employer::jobs::import_page)
+                .post(dashboard::employer::jobs::import)
+                .layer(DefaultBodyLimit::max(MAX_IMPORT_FILE_SIZE + 64 * 1024))
+                .layer(check_user_is_employer_recruiter.clone()),
         )
         .route(
             "/jobs/preview",
@@ -222,11 +296,15 @@ fn setup_employer_dashboard_router(state: &State) -> Router<State> {
         )
         .route(
             "/jobs/{job_id}/archive",
-            put(dashboard::employer::jobs::archive).layer(check_user_owns_job.clone()),
+            put(dashboard::employer::jobs::archive).layer(check_user_can_delete_job.clone()),
         )
         .route(
             "/jobs/{job_id}/delete",
-            delete(dashboard::employer::jobs::delete).layer(check_user_owns_job.clone()),
+            delete(dashboard::employer::jobs::delete).layer(check_user_can_delete_job.clone()),
+        )
+        .route(
+            "/jobs/{job_id}/duplicate",
+            post(dashboard::employer::jobs::duplicate).layer(check_user_can_edit_job.clone()),
         )
         .route(
             "/jobs/{job_id}/preview",
@@ -234,7 +312,14 @@ fn setup_employer_dashboard_router(state: &State) -> Router<State> {
         )
         .route(
             "/jobs/{job_id}/publish",
-            put(dashboard::employer::jobs::publish).layer(check_user_owns_job.clone()),
+            put(dashboard::employer::jobs::publish).layer(check_user_can_edit_job.clone()),
+        )
+        .route(
+            "/jobs/{job_id}/renew",
+            get(dashboard::employer::jobs::renew_page)
+                .layer(check_user_can_edit_job.clone())
+                .put(dashboard::employer::jobs::renew)
+                .layer(check_user_can_edit_job.clone()),
         )
         .route(
             "/jobs/{job_id}/stats",
@@ -243,9 +328,9 @@ fn setup_employer_dashboard_router(state: &State) -> Router<State> {
         .route(
             "/jobs/{job_id}/update",
             get(dashboard::employer::jobs::update_page)
-                .layer(check_user_owns_job.clone())
+                .layer(check_user_can_edit_job.clone())
                 .put(dashboard::employer::jobs::update)
-                .layer(check_user_owns_job.clone()),
+                .layer(check_user_can_edit_job.clone()),
         )
         .route(
             "/team/invitations/{employer_id}/accept",
@@ -255,7 +340,10 @@ fn setup_employer_dashboard_router(state: &State) -> Router<State> {
             "/team/invitations/{employer_id}/reject",
             put(dashboard::employer::team::reject_invitation),
         )
-        .route("/team/members/add", post(dashboard::employer::team::add_member))
+        .route(
+            "/team/members/add",
+            post(dashboard::employer::team::add_member).layer(check_user_is_employer_admin.clone()),
+        )
         .route(
             "/team/members/list",
             get(dashboard::employer::team::members_list_page),
@@ -264,6 +352,10 @@ fn setup_employer_dashboard_router(state: &State) -> Router<State> {
             "/team/members/{user_id}/delete",
             delete(dashboard::employer::team::delete_member),
         )
+        .route(
+            "/team/members/{user_id}/role",
+            put(dashboard::employer::team::update_member_role).layer(check_user_is_employer_admin.clone()),
+        )
 }
 
 /// Sets up the job seeker dashboard router and its routes.
@@ -286,6 +378,18 @@ fn setup_job_seeker_dashboard_router() -> Router<State> {
             "/profile/update",
             get(dashboard::job_seeker::profile::update_page).put(dashboard::job_seeker::profile::update),
         )
+        .route(
+            "/saved-searches/add",
+            post(dashboard::job_seeker::saved_searches::add),
+        )
+        .route(
+            "/saved-searches/list",
+            get(dashboard::job_seeker::saved_searches::list_page),
+        )
+        .route(
+            "/saved-searches/{saved_search_id}/delete",
+            delete(dashboard::job_seeker::saved_searches::delete),
+        )
 }
 
 /// Sets up the moderator dashboard router and its routes.
@@ -296,6 +400,7 @@ fn setup_moderator_dashboard_router(state: &State) -> Router<State> {
     // Setup router
     Router::new()
         .route("/", get(dashboard::moderator::home::page))
+        .route("/audit-log", get(dashboard::audit::moderator_list_page))
         .route("/jobs/live", get(dashboard::moderator::jobs::live_page))
         .route("/jobs/pending", get(dashboard::moderator::jobs::pending_page))
         .route("/jobs/{job_id}/approve", put(dashboard::moderator::jobs::approve))
@@ -304,6 +409,14 @@ fn setup_moderator_dashboard_router(state: &State) -> Router<State> {
             "/jobs/{employer_id}/{job_id}/preview",
             get(dashboard::moderator::jobs::preview_page),
         )
+        .route(
+            "/notifications/failed",
+            get(dashboard::moderator::notifications::failed_page),
+        )
+        .route(
+            "/notifications/{notification_id}/retry",
+            put(dashboard::moderator::notifications::retry),
+        )
         .route_layer(user_is_moderator)
 }
 
@@ -320,6 +433,17 @@ fn setup_dashboard_images_router(state: &State) -> Router<State> {
     )
 }
 
+/// Sets up the dashboard resumes router for authenticated resume access.
+fn setup_dashboard_resumes_router() -> Router<State> {
+    // Setup router (the body limit leaves some room for the multipart overhead)
+    Router::new()
+        .route(
+            "/",
+            post(resume::upload).layer(DefaultBodyLimit::max(MAX_RESUME_SIZE + 64 * 1024)),
+        )
+        .route("/{resume_id}", get(resume::get))
+}
+
 /// Sets up the job board images router for public image access.
 fn setup_jobboard_images_router(state: &State) -> Router<State> {
     // Setup middleware
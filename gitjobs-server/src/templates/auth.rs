@@ -3,11 +3,13 @@
 use askama::Template;
 use axum_messages::{Level, Message};
 use serde::{Deserialize, Serialize};
+use serde_with::{NoneAsEmptyString, serde_as};
 
 use crate::{
     auth::{AuthSession, UserSummary},
     config::LoginOptions,
-    templates::{Config, PageId, filters},
+    notifications::NotificationKind,
+    templates::{Config, PageId, dashboard::job_seeker::saved_searches::AlertFrequency, filters},
 };
 
 // Pages templates.
@@ -58,6 +60,8 @@ pub(crate) struct SignUpPage {
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "auth/update_user.html")]
 pub(crate) struct UpdateUserPage {
+    /// Notifications settings of the user.
+    pub notifications_settings: UserNotificationsSettings,
     /// User summary information for updating the user.
     pub user_summary: UserSummary,
 }
@@ -80,6 +84,43 @@ pub(crate) struct User {
     pub username: Option<String>,
 }
 
+/// Email notifications settings of a user.
+#[allow(clippy::struct_excessive_bools)]
+#[serde_as]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct UserNotificationsSettings {
+    /// Whether to send the user the new jobs matching their saved searches.
+    pub notify_job_alert_digest: bool,
+    /// Whether to notify the user when the jobs of their employers are approved.
+    pub notify_job_approved: bool,
+    /// Whether to notify the user when the jobs of their employers are about to close.
+    pub notify_job_expiring: bool,
+    /// Whether to notify the user when the jobs of their employers are rejected.
+    pub notify_job_rejected: bool,
+    /// Whether to notify the user when new applications arrive at their employers.
+    pub notify_new_applications: bool,
+
+    /// How often to send the job alerts, overriding the frequency of each
+    /// saved search.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub job_alert_digest_frequency: Option<AlertFrequency>,
+}
+
+impl UserNotificationsSettings {
+    /// Returns whether each of the notification kinds the user can opt out
+    /// of is enabled.
+    pub(crate) fn preferences(&self) -> [(NotificationKind, bool); 5] {
+        [
+            (NotificationKind::JobAlertDigest, self.notify_job_alert_digest),
+            (NotificationKind::JobApproved, self.notify_job_approved),
+            (NotificationKind::JobExpiring, self.notify_job_expiring),
+            (NotificationKind::JobRejected, self.notify_job_rejected),
+            (NotificationKind::NewApplication, self.notify_new_applications),
+        ]
+    }
+}
+
 /// Conversion from `AuthSession` to User for template rendering.
 impl From<AuthSession> for User {
     fn from(session: AuthSession) -> Self {
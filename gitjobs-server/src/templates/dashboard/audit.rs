@@ -0,0 +1,205 @@
+//! Templates and types for the audit log pages in the employer and moderator
+//! dashboards.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::templates::{
+    dashboard::employer::{applications::ApplicationStatus, team::EmployerRole},
+    filters,
+    helpers::DATE_FORMAT,
+    pagination::{NavigationLinks, Pagination},
+};
+
+/// Time format used to display when the entries were recorded.
+const TIME_FORMAT: &str = "%H:%M";
+
+// Pages templates.
+
+/// Template for the audit log page.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/audit_log.html")]
+pub(crate) struct AuditLogPage {
+    /// List of audit log entries.
+    pub entries: Vec<AuditLogEntry>,
+    /// Filters applied to the audit log.
+    pub filters: Filters,
+    /// Navigation links for pagination.
+    pub navigation_links: NavigationLinks,
+}
+
+// Types.
+
+/// Action recorded in the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum AuditAction {
+    /// The status of an application was updated.
+    ApplicationStatusUpdated,
+    /// An employer was added.
+    EmployerAdded,
+    /// An employer's profile was updated.
+    EmployerUpdated,
+    /// A job was added.
+    JobAdded,
+    /// A job was approved by a moderator.
+    JobApproved,
+    /// A job was archived.
+    JobArchived,
+    /// A job was deleted.
+    JobDeleted,
+    /// A job was submitted for approval.
+    JobPublished,
+    /// A job was rejected by a moderator.
+    JobRejected,
+    /// A job's closing date was extended.
+    JobRenewed,
+    /// A job was updated.
+    JobUpdated,
+    /// A user was invited to join the team.
+    TeamMemberAdded,
+    /// A user accepted an invitation to join the team.
+    TeamMemberJoined,
+    /// A team member was removed.
+    TeamMemberRemoved,
+    /// A team member's role was updated.
+    TeamMemberRoleUpdated,
+}
+
+impl AuditAction {
+    /// Returns a human readable description of the action.
+    pub(crate) fn description(self) -> &'static str {
+        match self {
+            AuditAction::ApplicationStatusUpdated => "Updated application status",
+            AuditAction::EmployerAdded => "Added employer",
+            AuditAction::EmployerUpdated => "Updated employer profile",
+            AuditAction::JobAdded => "Added job",
+            AuditAction::JobApproved => "Approved job",
+            AuditAction::JobArchived => "Archived job",
+            AuditAction::JobDeleted => "Deleted job",
+            AuditAction::JobPublished => "Published job",
+            AuditAction::JobRejected => "Rejected job",
+            AuditAction::JobRenewed => "Renewed job",
+            AuditAction::JobUpdated => "Updated job",
+            AuditAction::TeamMemberAdded => "Invited team member",
+            AuditAction::TeamMemberJoined => "Joined team",
+            AuditAction::TeamMemberRemoved => "Removed team member",
+            AuditAction::TeamMemberRoleUpdated => "Updated team member role",
+        }
+    }
+}
+
+/// Additional details recorded with an audit log entry.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AuditLogDetails {
+    /// Fields changed by an update.
+    pub changed_fields: Option<Vec<String>>,
+    /// Review notes provided by the moderator.
+    pub review_notes: Option<String>,
+    /// Role assigned to a team member.
+    pub role: Option<EmployerRole>,
+    /// Status assigned to an application.
+    pub status: Option<ApplicationStatus>,
+}
+
+impl AuditLogDetails {
+    /// Returns a short summary of the details, if any.
+    pub(crate) fn summary(&self) -> Option<String> {
+        let mut parts = vec![];
+        if let Some(changed_fields) = &self.changed_fields {
+            parts.push(format!("Changed: {}", changed_fields.join(", ")));
+        }
+        if let Some(role) = &self.role {
+            parts.push(format!("Role: {role}"));
+        }
+        if let Some(status) = &self.status {
+            parts.push(format!("Status: {status}"));
+        }
+        if let Some(review_notes) = &self.review_notes {
+            parts.push(format!("Notes: {review_notes}"));
+        }
+
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" · "))
+        }
+    }
+}
+
+/// Entry of the audit log.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct AuditLogEntry {
+    /// Action performed.
+    pub action: AuditAction,
+    /// Unique identifier for the entry.
+    pub audit_log_id: Uuid,
+    /// Timestamp when the action was performed.
+    pub created_at: DateTime<Utc>,
+
+    /// Name of the user who performed the action.
+    pub actor_name: Option<String>,
+    /// Name of the company the action applies to.
+    pub company: Option<String>,
+    /// Additional details about the action.
+    pub details: Option<AuditLogDetails>,
+    /// Unique identifier of the employer the action applies to.
+    pub employer_id: Option<Uuid>,
+    /// Unique identifier of the target of the action.
+    pub target_id: Option<Uuid>,
+    /// Name of the target of the action (job title, user name...).
+    pub target_name: Option<String>,
+}
+
+/// Filters used to browse the audit log.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Filters {
+    /// Limit the number of results.
+    pub limit: Option<usize>,
+    /// Offset for pagination.
+    pub offset: Option<usize>,
+
+    /// Dashboard where the audit log is displayed.
+    #[serde(skip)]
+    pub scope: Scope,
+}
+
+impl Pagination for Filters {
+    fn get_base_hx_url(&self) -> String {
+        format!("/dashboard/{}/audit-log", self.scope)
+    }
+
+    fn get_base_url(&self) -> String {
+        format!("/dashboard/{}?tab=audit-log", self.scope)
+    }
+
+    fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    fn set_offset(&mut self, offset: Option<usize>) {
+        self.offset = offset;
+    }
+}
+
+/// Dashboard where the audit log is displayed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, strum::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum Scope {
+    /// Employer dashboard, entries of the selected employer only (default).
+    #[default]
+    Employer,
+    /// Moderator dashboard, all entries.
+    Moderator,
+}
@@ -4,16 +4,20 @@ use anyhow::Result;
 use askama::Template;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
+use serde_with::{NoneAsEmptyString, serde_as, skip_serializing_none};
 use uuid::Uuid;
 
 use crate::templates::{
     dashboard::employer::jobs::{JobSummary, Workplace},
+    filters,
     helpers::{DATE_FORMAT, build_dashboard_image_url},
     misc::Location,
     pagination::{NavigationLinks, Pagination},
 };
 
+/// Maximum length of the application notes.
+pub(crate) const MAX_NOTE_LEN: usize = 5000;
+
 // Pages templates.
 
 /// Applications page template for employer dashboard.
@@ -40,6 +44,74 @@ impl ApplicationsPage {
     }
 }
 
+/// CSV export of the applications, written in batches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApplicationsCsv {
+    /// List of job applications.
+    pub applications: Vec<Application>,
+    /// Base URL used to build the profiles links.
+    pub base_url: String,
+    /// Whether the header row should be included.
+    pub include_header: bool,
+}
+
+impl ApplicationsCsv {
+    /// Columns of the export.
+    const HEADER: [&str; 9] = [
+        "name",
+        "email",
+        "job_title",
+        "applied_at",
+        "last_position",
+        "location",
+        "cover_letter",
+        "screening_answers",
+        "profile_url",
+    ];
+
+    /// Write the applications as CSV records.
+    pub(crate) fn write(&self) -> Result<Vec<u8>> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        if self.include_header {
+            writer.write_record(Self::HEADER)?;
+        }
+        for application in &self.applications {
+            writer.write_record([
+                escape_formula(&application.name),
+                escape_formula(&application.email),
+                escape_formula(&application.job_title),
+                application.applied_at.to_rfc3339(),
+                escape_formula(application.last_position.as_deref().unwrap_or_default()),
+                escape_formula(
+                    &application
+                        .location
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                ),
+                escape_formula(application.cover_letter.as_deref().unwrap_or_default()),
+                escape_formula(&application.screening_answers_text()),
+                format!(
+                    "{}/dashboard/employer/applications/profile/{}/preview",
+                    self.base_url, application.job_seeker_profile_id
+                ),
+            ])?;
+        }
+
+        writer.into_inner().map_err(|err| err.into_error().into())
+    }
+}
+
+/// Team feedback section displayed in the applicant profile preview.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/employer/applications/feedback.html")]
+pub(crate) struct FeedbackSection {
+    /// Applications of the job seeker to the employer's jobs.
+    pub applications: Vec<ApplicationFeedback>,
+    /// Unique identifier for the job seeker profile.
+    pub profile_id: Uuid,
+}
+
 // Types.
 
 /// Application information for employer dashboard listings.
@@ -51,6 +123,8 @@ pub(crate) struct Application {
     name: String,
     /// Timestamp when the application was submitted.
     applied_at: DateTime<Utc>,
+    /// Email address of the applicant.
+    email: String,
     /// Unique identifier for the job.
     job_id: Uuid,
     /// Title of the job applied for.
@@ -59,13 +133,156 @@ pub(crate) struct Application {
     job_seeker_profile_id: Uuid,
     /// Workplace type for the job.
     job_workplace: Workplace,
+    /// Number of notes left by the team.
+    notes_count: usize,
+    /// Current status of the application.
+    status: ApplicationStatus,
 
+    /// Cover letter provided by the applicant, if any.
+    cover_letter: Option<String>,
     /// Location of the job, if specified.
     job_location: Option<Location>,
     /// Last position held by the applicant, if any.
     last_position: Option<String>,
+    /// Location of the applicant, if specified.
+    location: Option<Location>,
     /// Photo identifier for the applicant, if available.
     photo_id: Option<Uuid>,
+    /// Rating given by the team (1-5), if any.
+    rating: Option<u8>,
+    /// Answers to the job screening questions, if any.
+    screening_answers: Option<Vec<ScreeningAnswer>>,
+    /// Changes of the application status, most recent first.
+    status_history: Option<Vec<ApplicationStatusChange>>,
+}
+
+impl Application {
+    /// Returns the position of the application in the applications list.
+    pub(crate) fn keyset(&self) -> ApplicationsKeyset {
+        ApplicationsKeyset {
+            application_id: self.application_id,
+            applied_at: self.applied_at,
+        }
+    }
+
+    /// Returns the screening answers formatted as one `question: answer`
+    /// entry per line.
+    fn screening_answers_text(&self) -> String {
+        self.screening_answers
+            .iter()
+            .flatten()
+            .map(|a| format!("{}: {}", a.question, a.answer))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Team feedback about an application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApplicationFeedback {
+    /// Unique identifier for the application.
+    pub application_id: Uuid,
+    /// Timestamp when the application was submitted.
+    pub applied_at: DateTime<Utc>,
+    /// Title of the job applied for.
+    pub job_title: String,
+    /// Current status of the application.
+    pub status: ApplicationStatus,
+
+    /// Cover letter provided by the applicant, if any.
+    pub cover_letter: Option<String>,
+    /// Notes left by the team, most recent first.
+    pub notes: Option<Vec<ApplicationNote>>,
+    /// Rating given by the team (1-5), if any.
+    pub rating: Option<u8>,
+    /// Answers to the job screening questions, if any.
+    pub screening_answers: Option<Vec<ScreeningAnswer>>,
+}
+
+/// Note left by a team member about an application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApplicationNote {
+    /// Content of the note.
+    pub content: String,
+    /// Timestamp when the note was created.
+    pub created_at: DateTime<Utc>,
+
+    /// Name of the user who wrote the note, if still available.
+    pub user_name: Option<String>,
+}
+
+/// Input used to add a note to an application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct NewApplicationNote {
+    /// Unique identifier for the application.
+    pub application_id: Uuid,
+    /// Content of the note.
+    pub content: String,
+}
+
+/// Input used to rate an application.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApplicationRatingInput {
+    /// Unique identifier for the application.
+    pub application_id: Uuid,
+    /// Rating (1-5). An empty value clears the rating.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub rating: Option<u8>,
+}
+
+/// Answer to a job screening question provided when applying.
+///
+/// The question text is stored along with the answer so that later changes to
+/// the job screening questions do not affect existing applications.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ScreeningAnswer {
+    /// Answer provided by the applicant.
+    pub answer: String,
+    /// Question answered.
+    pub question: String,
+}
+
+/// Status of an application in the hiring pipeline.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ApplicationStatus {
+    /// Application received, not reviewed yet (default).
+    #[default]
+    New,
+    /// Application being reviewed by the team.
+    Reviewing,
+    /// Applicant in the interviewing process.
+    Interviewing,
+    /// Offer made to the applicant.
+    Offered,
+    /// Applicant hired.
+    Hired,
+    /// Application rejected.
+    Rejected,
+}
+
+/// Change of the status of an application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApplicationStatusChange {
+    /// Timestamp when the status was changed.
+    pub changed_at: DateTime<Utc>,
+    /// Status set.
+    pub status: ApplicationStatus,
+
+    /// Name of the user who changed the status, if still available.
+    pub changed_by: Option<String>,
+}
+
+/// Input used to update the status of an application.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ApplicationStatusInput {
+    /// New status of the application.
+    pub status: ApplicationStatus,
 }
 
 /// Filters used to search for applications.
@@ -78,11 +295,17 @@ pub(crate) struct Filters {
     pub limit: Option<usize>,
     /// Offset for pagination.
     pub offset: Option<usize>,
+    /// Filter by application status.
+    pub status: Option<ApplicationStatus>,
+
+    /// Only include the applications sorted after this position, used to
+    /// paginate with a keyset (set internally, it can't be provided by users).
+    #[serde(skip)]
+    pub after: Option<ApplicationsKeyset>,
 }
 
 impl Filters {
     /// Convert the filters to a raw query string.
-    #[allow(dead_code)]
     fn to_raw_query(&self) -> Result<String> {
         serde_qs::to_string(self).map_err(Into::into)
     }
@@ -110,9 +333,111 @@ impl Pagination for Filters {
     }
 }
 
+/// Position of an application in the applications list, which is sorted by
+/// application date and identifier (both descending).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ApplicationsKeyset {
+    /// Unique identifier for the application.
+    pub application_id: Uuid,
+    /// Timestamp when the application was submitted.
+    pub applied_at: DateTime<Utc>,
+}
+
 /// Filter options used in the applications page.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct FiltersOptions {
     /// List of job summaries for filter selection.
     pub jobs: Vec<JobSummary>,
 }
+
+/// Prefix values that could be interpreted as formulas by spreadsheet
+/// applications with a quote.
+fn escape_formula(value: &str) -> String {
+    if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::templates::{misc::Location, test_helpers::create_application};
+
+    use super::{ApplicationsCsv, ScreeningAnswer, escape_formula};
+
+    #[test]
+    fn escape_formula_prefixes_formula_characters() {
+        for prefix in ['=', '+', '-', '@', '\t', '\r'] {
+            let value = format!("{prefix}SUM(A1:A2)");
+            assert_eq!(escape_formula(&value), format!("'{value}"), "{prefix:?}");
+        }
+    }
+
+    #[test]
+    fn escape_formula_keeps_other_values() {
+        assert_eq!(escape_formula("Jane Doe"), "Jane Doe");
+        assert_eq!(escape_formula("a=b"), "a=b");
+        assert_eq!(escape_formula(""), "");
+    }
+
+    #[test]
+    fn applications_csv_write() {
+        let mut application = create_application(
+            Uuid::parse_str("550e8400-e29b-41d4-a716-446655440030").unwrap(),
+            Utc.with_ymd_and_hms(2025, 1, 15, 10, 0, 0).unwrap(),
+        );
+        application.name = "=HYPERLINK(\"https://example.com\")".to_string();
+        application.cover_letter = Some("Hello,\nI'm interested".to_string());
+        application.last_position = Some("Engineer at ACME".to_string());
+        application.location = Some(Location {
+            location_id: Uuid::nil(),
+            city: "Madrid".to_string(),
+            country: "Spain".to_string(),
+            state: None,
+        });
+        application.screening_answers = Some(vec![
+            ScreeningAnswer {
+                answer: "Yes".to_string(),
+                question: "Remote?".to_string(),
+            },
+            ScreeningAnswer {
+                answer: "-5".to_string(),
+                question: "Years?".to_string(),
+            },
+        ]);
+        let csv = ApplicationsCsv {
+            applications: vec![application],
+            base_url: "https://example.com".to_string(),
+            include_header: true,
+        };
+
+        assert_eq!(
+            String::from_utf8(csv.write().unwrap()).unwrap(),
+            "name,email,job_title,applied_at,last_position,location,cover_letter,screening_answers,profile_url\n\
+             \"'=HYPERLINK(\"\"https://example.com\"\")\",jane@example.com,Software Engineer,\
+             2025-01-15T10:00:00+00:00,Engineer at ACME,\"Madrid, Spain\",\"Hello,\nI'm interested\",\
+             \"Remote?: Yes\nYears?: -5\",\
+             https://example.com/dashboard/employer/applications/profile/550e8400-e29b-41d4-a716-446655440040/preview\n"
+        );
+    }
+
+    #[test]
+    fn applications_csv_write_without_header() {
+        let csv = ApplicationsCsv {
+            applications: vec![create_application(
+                Uuid::parse_str("550e8400-e29b-41d4-a716-446655440030").unwrap(),
+                Utc.with_ymd_and_hms(2025, 1, 15, 10, 0, 0).unwrap(),
+            )],
+            base_url: "https://example.com".to_string(),
+            include_header: false,
+        };
+
+        let output = String::from_utf8(csv.write().unwrap()).unwrap();
+        assert!(output.starts_with("Jane Doe,jane@example.com,"));
+        assert_eq!(output.lines().count(), 1);
+    }
+}
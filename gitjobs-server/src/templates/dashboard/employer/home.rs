@@ -8,7 +8,7 @@ use uuid::Uuid;
 use crate::templates::{
     Config, PageId,
     auth::{self, User},
-    dashboard::employer,
+    dashboard::{audit, employer},
     filters,
     helpers::{build_dashboard_image_url, find_employer},
 };
@@ -49,6 +49,8 @@ pub(crate) enum Content {
     Account(auth::UpdateUserPage),
     /// Applications list page.
     Applications(employer::applications::ApplicationsPage),
+    /// Audit log page.
+    AuditLog(audit::AuditLogPage),
     /// Initial setup page for employer profile.
     EmployerInitialSetup(employer::employers::InitialSetupPage),
     /// Team invitations list page.
@@ -72,6 +74,11 @@ impl Content {
         matches!(self, Content::Applications(_))
     }
 
+    /// Check if the content is the audit log page.
+    fn is_audit_log(&self) -> bool {
+        matches!(self, Content::AuditLog(_))
+    }
+
     /// Check if the content is the invitations page.
     fn is_invitations(&self) -> bool {
         matches!(self, Content::Invitations(_))
@@ -98,6 +105,7 @@ impl std::fmt::Display for Content {
         match self {
             Content::Account(template) => write!(f, "{}", template.render()?),
             Content::Applications(template) => write!(f, "{}", template.render()?),
+            Content::AuditLog(template) => write!(f, "{}", template.render()?),
             Content::EmployerInitialSetup(template) => write!(f, "{}", template.render()?),
             Content::Invitations(template) => write!(f, "{}", template.render()?),
             Content::Jobs(template) => write!(f, "{}", template.render()?),
@@ -115,6 +123,8 @@ pub(crate) enum Tab {
     Account,
     /// Applications tab.
     Applications,
+    /// Audit log tab.
+    AuditLog,
     /// Employer initial setup tab.
     EmployerInitialSetup,
     /// Team invitations tab.
@@ -1,12 +1,20 @@
 //! Templates and types for the employer dashboard jobs page.
 
+use std::fmt;
+
+use anyhow::{Result, bail};
 use askama::Template;
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{self, SeqAccess, Visitor},
+};
 use serde_with::skip_serializing_none;
 use uuid::Uuid;
 
 use crate::templates::{
+    Config, PageId,
+    auth::User,
     dashboard::employer::employers::Employer,
     filters,
     helpers::{DATE_FORMAT, build_dashboard_image_url, format_location, normalize, normalize_salary},
@@ -14,6 +22,18 @@ use crate::templates::{
     misc::{Certification, Foundation, Location, Project},
 };
 
+/// Currencies supported for job salaries.
+const SALARY_CURRENCIES: &[&str] = &["CAD", "CHF", "EUR", "GBP", "JPY", "USD"];
+
+/// Periods supported for job salaries.
+const SALARY_PERIODS: &[&str] = &["year", "month", "week", "day", "hour"];
+
+/// Maximum length of the screening questions text.
+pub(crate) const MAX_SCREENING_QUESTION_LEN: usize = 250;
+
+/// Maximum length of each of the options of single choice screening questions.
+pub(crate) const MAX_SCREENING_OPTION_LEN: usize = 100;
+
 // Pages templates.
 
 /// Add job page template for the employer dashboard.
@@ -26,6 +46,26 @@ pub(crate) struct AddPage {
     pub foundations: Vec<Foundation>,
 }
 
+/// Import jobs page template for the employer dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/employer/jobs/import.html")]
+pub(crate) struct ImportPage {
+    /// List of available foundations to resolve the jobs projects.
+    pub foundations: Vec<Foundation>,
+}
+
+/// Import jobs results page template for the employer dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/employer/jobs/import_results.html")]
+pub(crate) struct ImportResultsPage {
+    /// Number of jobs created.
+    pub created: usize,
+    /// Rows that couldn't be imported.
+    pub errors: Vec<ImportRowError>,
+    /// Status of the jobs created.
+    pub status: JobStatus,
+}
+
 /// Jobs list page template for the employer dashboard.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "dashboard/employer/jobs/list.html")]
@@ -44,6 +84,23 @@ pub(crate) struct PreviewPage {
     pub job: Job,
 }
 
+/// Page to confirm the renewal of a job, linked from the expiry reminders.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/employer/jobs/renew.html")]
+pub(crate) struct RenewPage {
+    /// Server configuration.
+    pub cfg: Config,
+    /// Job to renew.
+    pub job: Job,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Authenticated user information.
+    pub user: User,
+
+    /// Name of the authentication provider, if any.
+    pub auth_provider: Option<String>,
+}
+
 /// Update job page template for the employer dashboard.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "dashboard/employer/jobs/update.html")]
@@ -77,8 +134,12 @@ pub(crate) struct JobSummary {
     pub archived_at: Option<DateTime<Utc>>,
     /// City where the job is located, if specified.
     pub city: Option<String>,
+    /// Date when the job closes and gets archived, if specified.
+    pub closing_date: Option<NaiveDate>,
     /// Country where the job is located, if specified.
     pub country: Option<String>,
+    /// Date when the job should be published once approved, if specified.
+    pub publish_date: Option<NaiveDate>,
     /// Timestamp when the job was published, if applicable.
     pub published_at: Option<DateTime<Utc>>,
     /// Notes from job review, if any.
@@ -108,6 +169,8 @@ pub(crate) struct Job {
     pub benefits: Option<Vec<String>>,
     /// Desired certifications, if any.
     pub certifications: Option<Vec<Certification>>,
+    /// Date when the job closes and gets archived, if specified.
+    pub closing_date: Option<NaiveDate>,
     /// Unique identifier for the job, if available.
     pub job_id: Option<Uuid>,
     /// Location details for the job, if specified.
@@ -116,6 +179,8 @@ pub(crate) struct Job {
     pub open_source: Option<i32>,
     /// Related projects, if any.
     pub projects: Option<Vec<Project>>,
+    /// Date when the job should be published once approved, if specified.
+    pub publish_date: Option<NaiveDate>,
     /// Timestamp when the job was published, if applicable.
     pub published_at: Option<DateTime<Utc>>,
     /// Required qualifications, if any.
@@ -142,6 +207,8 @@ pub(crate) struct Job {
     pub salary_period: Option<String>,
     /// Seniority level for the job, if specified.
     pub seniority: Option<Seniority>,
+    /// Screening questions applicants must answer, if any.
+    pub screening_questions: Option<Vec<ScreeningQuestion>>,
     /// List of required or desired skills, if any.
     pub skills: Option<Vec<String>>,
     /// End of timezone range, if specified.
@@ -170,6 +237,27 @@ impl Job {
         self.salary_min_usd_year = normalize_salary(self.salary_min.or(self.salary), currency, period).await;
         self.salary_max_usd_year = normalize_salary(self.salary_max.or(self.salary), currency, period).await;
 
+        // Screening questions
+        if let Some(questions) = &mut self.screening_questions {
+            for question in questions.iter_mut() {
+                question.question = question.question.trim().to_string();
+                if question.kind == ScreeningQuestionKind::SingleChoice {
+                    if let Some(options) = &mut question.options {
+                        for option in options.iter_mut() {
+                            *option = option.trim().to_string();
+                        }
+                        options.retain(|option| !option.is_empty());
+                    }
+                } else {
+                    question.options = None;
+                }
+            }
+            questions.retain(|question| !question.question.is_empty());
+            if questions.is_empty() {
+                self.screening_questions = None;
+            }
+        }
+
         // Skills
         if let Some(skills) = &mut self.skills {
             for skill in skills.iter_mut() {
@@ -178,6 +266,35 @@ impl Job {
         }
     }
 
+    /// Check if the screening questions are valid.
+    ///
+    /// Questions can't exceed `MAX_SCREENING_QUESTION_LEN` characters, and
+    /// single choice questions must provide at least two options of up to
+    /// `MAX_SCREENING_OPTION_LEN` characters.
+    pub(crate) fn screening_questions_are_valid(&self) -> bool {
+        self.screening_questions.iter().flatten().all(|question| {
+            question.question.chars().count() <= MAX_SCREENING_QUESTION_LEN
+                && (question.kind != ScreeningQuestionKind::SingleChoice
+                    || question.options.as_ref().is_some_and(|options| {
+                        options.len() >= 2
+                            && options
+                                .iter()
+                                .all(|option| option.chars().count() <= MAX_SCREENING_OPTION_LEN)
+                    }))
+        })
+    }
+
+    /// Check if the publish and closing dates are valid.
+    ///
+    /// The closing date can't be in the past, and it must be later than the
+    /// publish date when both are provided.
+    pub(crate) fn dates_are_valid(&self) -> bool {
+        let Some(closing_date) = self.closing_date else {
+            return true;
+        };
+        closing_date >= Utc::now().date_naive() && self.publish_date.is_none_or(|date| closing_date > date)
+    }
+
     /// Get the salary kind of the job.
     pub(crate) fn salary_kind(&self) -> SalaryKind {
         if self.salary_min.is_some() && self.salary_max.is_some() {
@@ -188,6 +305,272 @@ impl Job {
     }
 }
 
+/// Job as provided in an import file.
+///
+/// List fields can be provided as arrays (JSON) or as semicolon separated
+/// values (CSV). Projects and certifications are referenced by name.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct ImportedJob {
+    /// Job description text.
+    pub description: String,
+    /// Kind of job (full-time, part-time, etc.).
+    pub kind: JobKind,
+    /// Job title.
+    pub title: String,
+    /// Workplace type for the job.
+    pub workplace: Workplace,
+
+    /// Application instructions, if provided.
+    pub apply_instructions: Option<String>,
+    /// External application URL, if provided.
+    pub apply_url: Option<String>,
+    /// List of job benefits, if any.
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub benefits: Option<Vec<String>>,
+    /// Names of the desired certifications, if any.
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub certifications: Option<Vec<String>>,
+    /// Date when the job closes and gets archived, if specified.
+    pub closing_date: Option<NaiveDate>,
+    /// Open source commitment level, if specified.
+    pub open_source: Option<i32>,
+    /// Names of the related projects, if any.
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub projects: Option<Vec<String>>,
+    /// Date when the job should be published once approved, if specified.
+    pub publish_date: Option<NaiveDate>,
+    /// Required qualifications, if any.
+    pub qualifications: Option<String>,
+    /// Job responsibilities, if any.
+    pub responsibilities: Option<String>,
+    /// Salary amount, if specified.
+    pub salary: Option<i64>,
+    /// Currency of the salary, if specified.
+    pub salary_currency: Option<String>,
+    /// Maximum salary, if specified.
+    pub salary_max: Option<i64>,
+    /// Minimum salary, if specified.
+    pub salary_min: Option<i64>,
+    /// Salary period (e.g., year, month, week, day, hour), if specified.
+    pub salary_period: Option<String>,
+    /// Seniority level for the job, if specified.
+    pub seniority: Option<Seniority>,
+    /// List of required or desired skills, if any.
+    #[serde(default, deserialize_with = "deserialize_list")]
+    pub skills: Option<Vec<String>>,
+    /// End of timezone range, if specified.
+    pub tz_end: Option<String>,
+    /// Start of timezone range, if specified.
+    pub tz_start: Option<String>,
+    /// Upstream commitment level, if specified.
+    pub upstream_commitment: Option<i32>,
+}
+
+impl ImportedJob {
+    /// Parse the jobs in the import file provided, which can be a JSON array
+    /// or a CSV document with a header row. Each entry contains the job in the
+    /// corresponding row, or the error found parsing it.
+    pub(crate) fn parse_file(file_name: &str, data: &[u8]) -> Result<Vec<Result<Self, String>>> {
+        let extension = file_name.rsplit_once('.').map(|(_, ext)| ext.to_lowercase());
+
+        let rows = match extension.as_deref() {
+            Some("csv") => csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(data)
+                .deserialize()
+                .map(|row| {
+                    row.map_err(|err| match err.kind() {
+                        csv::ErrorKind::Deserialize { err, .. } => err.to_string(),
+                        _ => err.to_string(),
+                    })
+                })
+                .collect(),
+            Some("json") => {
+                let Ok(values) = serde_json::from_slice::<Vec<serde_json::Value>>(data) else {
+                    bail!("the file must contain a JSON array of jobs");
+                };
+                values
+                    .into_iter()
+                    .map(|value| serde_json::from_value(value).map_err(|err| err.to_string()))
+                    .collect()
+            }
+            _ => bail!("the file must be a CSV or JSON document"),
+        };
+
+        Ok(rows)
+    }
+
+    /// Validate the imported job and convert it into a job with the status
+    /// provided, resolving its projects and certifications by name.
+    pub(crate) fn into_job(
+        self,
+        status: JobStatus,
+        projects: &[Project],
+        certifications: &[Certification],
+    ) -> Result<Job, Vec<String>> {
+        let mut errors = self.validate_fields();
+
+        // Projects
+        let mut job_projects = vec![];
+        for name in self.projects.iter().flatten() {
+            match projects.iter().find(|p| p.name.eq_ignore_ascii_case(name)) {
+                Some(project) => job_projects.push(project.clone()),
+                None => errors.push(format!("unknown project: {name}")),
+            }
+        }
+
+        // Certifications
+        let mut job_certifications = vec![];
+        for name in self.certifications.iter().flatten() {
+            match certifications
+                .iter()
+                .find(|c| c.short_name.eq_ignore_ascii_case(name) || c.name.eq_ignore_ascii_case(name))
+            {
+                Some(certification) => job_certifications.push(certification.clone()),
+                None => errors.push(format!("unknown certification: {name}")),
+            }
+        }
+
+        // Prepare job
+        let job = Job {
+            description: self.description,
+            status,
+            title: self.title,
+            kind: self.kind,
+            workplace: self.workplace,
+            apply_instructions: self.apply_instructions,
+            apply_url: self.apply_url,
+            benefits: self.benefits,
+            certifications: (!job_certifications.is_empty()).then_some(job_certifications),
+            closing_date: self.closing_date,
+            job_id: None,
+            location: None,
+            open_source: self.open_source,
+            projects: (!job_projects.is_empty()).then_some(job_projects),
+            publish_date: self.publish_date,
+            published_at: None,
+            qualifications: self.qualifications,
+            responsibilities: self.responsibilities,
+            review_notes: None,
+            salary: self.salary,
+            salary_usd_year: None,
+            salary_currency: self.salary_currency,
+            salary_min: self.salary_min,
+            salary_min_usd_year: None,
+            salary_max: self.salary_max,
+            salary_max_usd_year: None,
+            salary_period: self.salary_period,
+            seniority: self.seniority,
+            screening_questions: None,
+            skills: self.skills,
+            tz_end: self.tz_end,
+            tz_start: self.tz_start,
+            updated_at: None,
+            upstream_commitment: self.upstream_commitment,
+        };
+        if !job.dates_are_valid() {
+            errors.push("closing_date must not be in the past and must be after publish_date".to_string());
+        }
+
+        if errors.is_empty() { Ok(job) } else { Err(errors) }
+    }
+
+    /// Validate the fields of the imported job that don't depend on other
+    /// entities, returning the errors found.
+    fn validate_fields(&self) -> Vec<String> {
+        let mut errors = vec![];
+
+        // Required fields
+        if self.title.trim().is_empty() {
+            errors.push("title is required".to_string());
+        }
+        if self.description.trim().is_empty() {
+            errors.push("description is required".to_string());
+        }
+
+        // Salary
+        let amounts = [self.salary, self.salary_min, self.salary_max];
+        if self.salary.is_some() && (self.salary_min.is_some() || self.salary_max.is_some()) {
+            errors.push("salary can't be combined with salary_min or salary_max".to_string());
+        }
+        if self
+            .salary_min
+            .zip(self.salary_max)
+            .is_some_and(|(min, max)| min > max)
+        {
+            errors.push("salary_min can't be greater than salary_max".to_string());
+        }
+        if amounts.iter().flatten().any(|amount| *amount < 0) {
+            errors.push("salary amounts can't be negative".to_string());
+        }
+        if amounts.iter().any(Option::is_some) {
+            if !self
+                .salary_currency
+                .as_deref()
+                .is_some_and(|c| SALARY_CURRENCIES.contains(&c))
+            {
+                errors.push(format!(
+                    "salary_currency must be one of: {}",
+                    SALARY_CURRENCIES.join(", ")
+                ));
+            }
+            if !self
+                .salary_period
+                .as_deref()
+                .is_some_and(|p| SALARY_PERIODS.contains(&p))
+            {
+                errors.push(format!(
+                    "salary_period must be one of: {}",
+                    SALARY_PERIODS.join(", ")
+                ));
+            }
+        }
+
+        // Open source and upstream commitment
+        for (field, value) in [
+            ("open_source", self.open_source),
+            ("upstream_commitment", self.upstream_commitment),
+        ] {
+            if value.is_some_and(|value| !(0..=100).contains(&value)) {
+                errors.push(format!("{field} must be between 0 and 100"));
+            }
+        }
+
+        // Timezone range
+        for (field, value) in [("tz_start", &self.tz_start), ("tz_end", &self.tz_end)] {
+            if value
+                .as_ref()
+                .is_some_and(|tz| !(-12..=12).any(|i| *tz == format!("UTC{i:+}")))
+            {
+                errors.push(format!("{field} must be a timezone between UTC-12 and UTC+12"));
+            }
+        }
+
+        // Apply URL
+        if self
+            .apply_url
+            .as_ref()
+            .is_some_and(|url| !url.starts_with("https://") && !url.starts_with("http://"))
+        {
+            errors.push("apply_url must be an http or https URL".to_string());
+        }
+
+        errors
+    }
+}
+
+/// Errors found in a row of an import file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ImportRowError {
+    /// Errors found.
+    pub errors: Vec<String>,
+    /// Row number, starting at 1.
+    pub row: usize,
+
+    /// Title of the job in the row, if available.
+    pub title: Option<String>,
+}
+
 /// Statistics for a specific job.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct JobStats {
@@ -221,6 +604,8 @@ pub(crate) enum JobStatus {
     Published,
     /// Job was rejected by moderators.
     Rejected,
+    /// Job was approved and will be published on its publish date.
+    Scheduled,
 }
 
 /// Job kind for employer dashboard jobs.
@@ -238,6 +623,32 @@ pub(crate) enum JobKind {
     PartTime,
 }
 
+/// Screening question applicants answer when applying to a job.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ScreeningQuestion {
+    /// Kind of answer expected.
+    pub kind: ScreeningQuestionKind,
+    /// Question text.
+    pub question: String,
+
+    /// Options to choose from (single choice questions only).
+    pub options: Option<Vec<String>>,
+}
+
+/// Kind of answer expected for a screening question.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ScreeningQuestionKind {
+    /// Free text answer.
+    FreeText,
+    /// Single choice from a list of options.
+    SingleChoice,
+    /// Yes or no answer.
+    YesNo,
+}
+
 /// Salary kind for employer dashboard jobs.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
 #[serde(rename_all = "kebab-case")]
@@ -262,3 +673,246 @@ pub(crate) enum Workplace {
     /// Fully remote workplace.
     Remote,
 }
+
+/// Deserialize a list of strings provided either as a sequence or as a
+/// semicolon separated string. Empty lists are deserialized as `None`.
+fn deserialize_list<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct ListVisitor;
+
+    impl<'de> Visitor<'de> for ListVisitor {
+        type Value = Vec<String>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a list or a semicolon separated string")
+        }
+
+        fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(vec![])
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut items = vec![];
+            while let Some(item) = seq.next_element::<String>()? {
+                items.push(item.trim().to_string());
+            }
+            Ok(items)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            Ok(value.split(';').map(|item| item.trim().to_string()).collect())
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            self.visit_str(&value.to_string())
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(vec![])
+        }
+    }
+
+    let mut items = deserializer.deserialize_any(ListVisitor)?;
+    items.retain(|item| !item.is_empty());
+
+    Ok((!items.is_empty()).then_some(items))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate, Utc};
+    use uuid::Uuid;
+
+    use crate::templates::test_helpers::create_base_dashboard_job;
+
+    use super::{ImportedJob, JobKind, JobStatus, Project, Workplace};
+
+    /// Check if the dashboard job dates provided are valid.
+    fn dates_are_valid(publish_date: Option<NaiveDate>, closing_date: Option<NaiveDate>) -> bool {
+        let mut job = create_base_dashboard_job();
+        job.publish_date = publish_date;
+        job.closing_date = closing_date;
+        job.dates_are_valid()
+    }
+
+    /// Returns the date the number of days provided away from today.
+    fn days_from_today(days: i64) -> NaiveDate {
+        Utc::now().date_naive() + Duration::days(days)
+    }
+
+    fn project(name: &str) -> Project {
+        Project {
+            project_id: Uuid::nil(),
+            foundation: "cncf".to_string(),
+            logo_url: String::new(),
+            maturity: "graduated".to_string(),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_file_csv_splits_lists() {
+        let data = b"title,description,kind,workplace,skills,projects\n\
+                     Engineer,Build things,full-time,remote,rust; go,\n";
+        let rows = ImportedJob::parse_file("jobs.csv", data).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        let job = rows[0].as_ref().unwrap();
+        assert_eq!(job.kind, JobKind::FullTime);
+        assert_eq!(job.workplace, Workplace::Remote);
+        assert_eq!(job.skills, Some(vec!["rust".to_string(), "go".to_string()]));
+        assert_eq!(job.projects, None);
+    }
+
+    #[test]
+    fn parse_file_json_reports_invalid_rows() {
+        let data = br#"[
+            {"title": "Engineer", "description": "Build things", "kind": "full-time", "workplace": "remote", "skills": ["rust"]},
+            {"title": "Engineer", "description": "Build things", "kind": "unknown", "workplace": "remote"}
+        ]"#;
+        let rows = ImportedJob::parse_file("jobs.json", data).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].is_ok());
+        assert!(rows[1].is_err());
+    }
+
+    #[test]
+    fn parse_file_unsupported_extension() {
+        assert!(ImportedJob::parse_file("jobs.txt", b"").is_err());
+    }
+
+    #[test]
+    fn into_job_resolves_projects() {
+        let data = br#"[{"title": "Engineer", "description": "Build things", "kind": "full-time", "workplace": "remote", "projects": ["kubernetes"]}]"#;
+        let imported_job = ImportedJob::parse_file("jobs.json", data).unwrap().remove(0).unwrap();
+        let job = imported_job
+            .into_job(JobStatus::Draft, &[project("Kubernetes")], &[])
+            .unwrap();
+
+        assert_eq!(job.status, JobStatus::Draft);
+        assert_eq!(job.projects, Some(vec![project("Kubernetes")]));
+    }
+
+    #[test]
+    fn into_job_reports_all_errors() {
+        let data = br#"[{
+            "title": "Engineer",
+            "description": "Build things",
+            "kind": "full-time",
+            "workplace": "remote",
+            "projects": ["unknown"],
+            "salary": 100000,
+            "salary_min": 90000,
+            "salary_currency": "XYZ",
+            "salary_period": "year",
+            "open_source": 150,
+            "tz_start": "UTC+20"
+        }]"#;
+        let imported_job = ImportedJob::parse_file("jobs.json", data).unwrap().remove(0).unwrap();
+        let errors = imported_job
+            .into_job(JobStatus::PendingApproval, &[project("Kubernetes")], &[])
+            .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![
+                "salary can't be combined with salary_min or salary_max",
+                "salary_currency must be one of: CAD, CHF, EUR, GBP, JPY, USD",
+                "open_source must be between 0 and 100",
+                "tz_start must be a timezone between UTC-12 and UTC+12",
+                "unknown project: unknown",
+            ]
+        );
+    }
+
+    #[test]
+    fn dates_are_valid_without_dates() {
+        assert!(dates_are_valid(None, None));
+    }
+
+    #[test]
+    fn dates_are_valid_only_publish_date() {
+        assert!(dates_are_valid(Some(days_from_today(-10)), None));
+        assert!(dates_are_valid(Some(days_from_today(10)), None));
+    }
+
+    #[test]
+    fn dates_are_valid_only_closing_date() {
+        assert!(dates_are_valid(None, Some(days_from_today(0))));
+        assert!(dates_are_valid(None, Some(days_from_today(30))));
+    }
+
+    #[test]
+    fn dates_are_valid_closing_date_in_the_past() {
+        assert!(!dates_are_valid(None, Some(days_from_today(-1))));
+        assert!(!dates_are_valid(
+            Some(days_from_today(-10)),
+            Some(days_from_today(-1))
+        ));
+    }
+
+    #[test]
+    fn dates_are_valid_closing_date_after_publish_date() {
+        assert!(dates_are_valid(
+            Some(days_from_today(1)),
+            Some(days_from_today(2))
+        ));
+        assert!(dates_are_valid(
+            Some(days_from_today(-10)),
+            Some(days_from_today(0))
+        ));
+    }
+
+    #[test]
+    fn dates_are_valid_closing_date_not_after_publish_date() {
+        assert!(!dates_are_valid(
+            Some(days_from_today(5)),
+            Some(days_from_today(5))
+        ));
+        assert!(!dates_are_valid(
+            Some(days_from_today(5)),
+            Some(days_from_today(3))
+        ));
+    }
+
+    #[test]
+    fn into_job_rejects_invalid_dates() {
+        let data = format!(
+            r#"[{{
+                "title": "Engineer",
+                "description": "Build things",
+                "kind": "full-time",
+                "workplace": "remote",
+                "publish_date": "{}",
+                "closing_date": "{}"
+            }}]"#,
+            days_from_today(5),
+            days_from_today(3)
+        );
+        let imported_job = ImportedJob::parse_file("jobs.json", data.as_bytes())
+            .unwrap()
+            .remove(0)
+            .unwrap();
+        let errors = imported_job.into_job(JobStatus::Draft, &[], &[]).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec!["closing_date must not be in the past and must be after publish_date"]
+        );
+    }
+}
@@ -13,10 +13,41 @@ use crate::templates::helpers::DATE_FORMAT;
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "dashboard/employer/teams/members_list.html")]
 pub(crate) struct MembersListPage {
-    /// Count of approved team members.
-    pub approved_members_count: usize,
+    /// Count of approved team members with the owner role.
+    pub approved_owners_count: usize,
     /// List of team members.
     pub members: Vec<TeamMember>,
+    /// Unique ID of the user viewing the page.
+    pub user_id: Uuid,
+    /// Role of the user viewing the page in the team.
+    pub user_role: EmployerRole,
+}
+
+impl MembersListPage {
+    /// Create a new members list page for the user provided.
+    pub(crate) fn new(members: Vec<TeamMember>, user_id: Uuid, user_role: EmployerRole) -> Self {
+        Self {
+            approved_owners_count: members
+                .iter()
+                .filter(|m| m.approved && m.role == EmployerRole::Owner)
+                .count(),
+            members,
+            user_id,
+            user_role,
+        }
+    }
+
+    /// Check if the user viewing the page can remove or change the role of the
+    /// team member provided.
+    ///
+    /// Admins can manage any member except owners, who can only be managed by
+    /// other owners. Members can always remove themselves.
+    fn can_manage_member(&self, member: &TeamMember) -> bool {
+        if member.user_id == self.user_id {
+            return true;
+        }
+        self.user_role.can_manage(member.role)
+    }
 }
 
 /// Template for the user invitations list page.
@@ -49,6 +80,8 @@ pub(crate) struct TeamMember {
     pub email: String,
     /// Full name of the member.
     pub name: String,
+    /// Role of the member in the team.
+    pub role: EmployerRole,
     /// Unique ID of the user.
     pub user_id: Uuid,
     /// Username of the member.
@@ -60,4 +93,88 @@ pub(crate) struct TeamMember {
 pub(crate) struct NewTeamMember {
     /// Email address of the new member.
     pub email: String,
+    /// Role of the new member in the team.
+    #[serde(default)]
+    pub role: EmployerRole,
+}
+
+/// Input used to update the role of a team member.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TeamMemberRoleInput {
+    /// New role of the team member.
+    pub role: EmployerRole,
+}
+
+/// Role of a member in an employer's team.
+///
+/// Roles are sorted by privileges, so that a role includes all the
+/// permissions of the roles below it.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum EmployerRole {
+    /// Read-only access to jobs and applications.
+    Viewer,
+    /// Can manage jobs and applications, but cannot delete or archive jobs
+    /// (default).
+    #[default]
+    Recruiter,
+    /// Can manage the employer profile, the jobs and the team members.
+    Admin,
+    /// Full access, including managing other owners.
+    Owner,
+}
+
+impl EmployerRole {
+    /// Check if this role can assign the role provided or manage a member who
+    /// has it. Only owners can manage owners.
+    pub(crate) fn can_manage(self, role: EmployerRole) -> bool {
+        self >= EmployerRole::Admin && (role != EmployerRole::Owner || self == EmployerRole::Owner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmployerRole::{self, Admin, Owner, Recruiter, Viewer};
+
+    #[test]
+    fn roles_are_sorted_by_privileges() {
+        assert!(Viewer < Recruiter);
+        assert!(Recruiter < Admin);
+        assert!(Admin < Owner);
+    }
+
+    #[test]
+    fn can_manage() {
+        let roles = [Viewer, Recruiter, Admin, Owner];
+        let cases: [(EmployerRole, [bool; 4]); 4] = [
+            (Viewer, [false, false, false, false]),
+            (Recruiter, [false, false, false, false]),
+            (Admin, [true, true, true, false]),
+            (Owner, [true, true, true, true]),
+        ];
+
+        for (role, expected) in cases {
+            for (other_role, can_manage) in roles.into_iter().zip(expected) {
+                assert_eq!(
+                    role.can_manage(other_role),
+                    can_manage,
+                    "{role} managing {other_role}"
+                );
+            }
+        }
+    }
 }
@@ -6,7 +6,10 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::templates::{
-    dashboard::employer::jobs::{JobStatus, Workplace},
+    dashboard::employer::{
+        applications::ApplicationStatus,
+        jobs::{JobStatus, Workplace},
+    },
     helpers::DATE_FORMAT,
     misc::Location,
 };
@@ -38,6 +41,8 @@ pub(crate) struct Application {
     pub job_title: String,
     /// Workplace type for the job.
     pub job_workplace: Workplace,
+    /// Status of the application in the hiring pipeline.
+    pub status: ApplicationStatus,
 
     /// Location of the job, if specified.
     pub job_location: Option<Location>,
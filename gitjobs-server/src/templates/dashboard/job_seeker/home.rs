@@ -44,6 +44,8 @@ pub(crate) enum Content {
     Applications(job_seeker::applications::ApplicationsPage),
     /// Profile update page content.
     Profile(job_seeker::profile::UpdatePage),
+    /// Saved searches list page content.
+    SavedSearches(job_seeker::saved_searches::SavedSearchesPage),
 }
 
 impl Content {
@@ -62,6 +64,11 @@ impl Content {
     fn is_profile(&self) -> bool {
         matches!(self, Content::Profile(_))
     }
+
+    /// Check if the content is the saved searches page.
+    fn is_saved_searches(&self) -> bool {
+        matches!(self, Content::SavedSearches(_))
+    }
 }
 
 impl std::fmt::Display for Content {
@@ -70,6 +77,7 @@ impl std::fmt::Display for Content {
             Content::Account(template) => write!(f, "{}", template.render()?),
             Content::Applications(template) => write!(f, "{}", template.render()?),
             Content::Profile(template) => write!(f, "{}", template.render()?),
+            Content::SavedSearches(template) => write!(f, "{}", template.render()?),
         }
     }
 }
@@ -85,4 +93,6 @@ pub(crate) enum Tab {
     /// Profile tab (default).
     #[default]
     Profile,
+    /// Saved searches tab.
+    SavedSearches,
 }
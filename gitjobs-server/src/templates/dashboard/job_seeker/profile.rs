@@ -7,6 +7,7 @@ use serde_with::skip_serializing_none;
 use uuid::Uuid;
 
 use crate::templates::{
+    dashboard::employer::applications::FeedbackSection,
     filters,
     helpers::{DATE_FORMAT_2, build_dashboard_image_url, normalize},
     misc::Location,
@@ -20,6 +21,9 @@ use crate::templates::{
 pub(crate) struct PreviewPage {
     /// Job seeker profile data to preview.
     pub profile: JobSeekerProfile,
+
+    /// Team feedback section, only available to employers.
+    pub feedback: Option<FeedbackSection>,
 }
 
 /// Template for the update profile page in the job seeker dashboard.
@@ -71,6 +75,8 @@ pub(crate) struct JobSeekerProfile {
     pub photo_id: Option<Uuid>,
     /// List of projects.
     pub projects: Option<Vec<Project>>,
+    /// Resume (PDF document) identifier.
+    pub resume_id: Option<Uuid>,
     /// List of skills.
     pub skills: Option<Vec<String>>,
     /// Twitter profile URL.
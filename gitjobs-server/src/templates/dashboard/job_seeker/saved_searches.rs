@@ -0,0 +1,65 @@
+//! Templates and types for the job seeker saved searches page.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::templates::{helpers::DATE_FORMAT, jobboard::jobs::Filters, pagination::build_url};
+
+// Pages templates.
+
+/// Saved searches page template for job seeker dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/job_seeker/saved_searches/list.html")]
+pub(crate) struct SavedSearchesPage {
+    /// List of searches saved by the job seeker.
+    pub saved_searches: Vec<SavedSearch>,
+}
+
+// Types.
+
+/// Represents a search saved by a job seeker to receive job alerts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SavedSearch {
+    /// Timestamp when the search was saved.
+    pub created_at: DateTime<Utc>,
+    /// Filters applied to the job search.
+    pub filters: Filters,
+    /// How often job alerts are sent.
+    pub frequency: AlertFrequency,
+    /// Name of the saved search.
+    pub name: String,
+    /// Unique identifier for the saved search.
+    pub saved_search_id: Uuid,
+
+    /// Timestamp when the job alerts were last processed, if any.
+    pub last_run_at: Option<DateTime<Utc>>,
+}
+
+impl SavedSearch {
+    /// Returns the job board URL with the saved search filters applied.
+    fn url(&self) -> String {
+        build_url("/", &self.filters).unwrap_or_else(|_| "/".to_string())
+    }
+}
+
+/// Information required to save a new search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct NewSavedSearch {
+    /// How often job alerts should be sent.
+    pub frequency: AlertFrequency,
+    /// Name of the saved search.
+    pub name: String,
+}
+
+/// Frequency options for job alerts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum AlertFrequency {
+    /// Once a day.
+    Daily,
+    /// Once a week.
+    Weekly,
+}
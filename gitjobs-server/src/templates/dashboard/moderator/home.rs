@@ -4,7 +4,15 @@ use askama::Template;
 use axum_messages::{Level, Message};
 use serde::{Deserialize, Serialize};
 
-use crate::templates::{Config, PageId, auth::User, dashboard::moderator::jobs, filters};
+use crate::templates::{
+    Config, PageId,
+    auth::User,
+    dashboard::{
+        audit,
+        moderator::{jobs, notifications},
+    },
+    filters,
+};
 
 // Pages templates.
 
@@ -31,7 +39,12 @@ pub(crate) struct Page {
 
 /// Content section for the moderator dashboard home page.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum Content {
+    /// Audit log page content.
+    AuditLog(audit::AuditLogPage),
+    /// Failed notifications page content.
+    FailedNotifications(notifications::FailedPage),
     /// Live jobs page content.
     LiveJobs(jobs::LivePage),
     /// Pending jobs page content.
@@ -39,6 +52,16 @@ pub(crate) enum Content {
 }
 
 impl Content {
+    /// Check if the content is the audit log page.
+    fn is_audit_log(&self) -> bool {
+        matches!(self, Content::AuditLog(_))
+    }
+
+    /// Check if the content is the failed notifications page.
+    fn is_failed_notifications(&self) -> bool {
+        matches!(self, Content::FailedNotifications(_))
+    }
+
     /// Check if the content is the live jobs page.
     fn is_live_jobs(&self) -> bool {
         matches!(self, Content::LiveJobs(_))
@@ -53,6 +76,8 @@ impl Content {
 impl std::fmt::Display for Content {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            Content::AuditLog(template) => write!(f, "{}", template.render()?),
+            Content::FailedNotifications(template) => write!(f, "{}", template.render()?),
             Content::LiveJobs(template) => write!(f, "{}", template.render()?),
             Content::PendingJobs(template) => write!(f, "{}", template.render()?),
         }
@@ -63,6 +88,10 @@ impl std::fmt::Display for Content {
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "kebab-case")]
 pub(crate) enum Tab {
+    /// Audit log tab.
+    AuditLog,
+    /// Failed notifications tab.
+    FailedNotifications,
     /// Live jobs tab.
     LiveJobs,
     /// Pending jobs tab (default).
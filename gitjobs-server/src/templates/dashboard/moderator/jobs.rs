@@ -7,7 +7,12 @@ use serde_with::skip_serializing_none;
 use uuid::Uuid;
 
 use crate::templates::{
-    helpers::{DATE_FORMAT, DATE_FORMAT_3},
+    dashboard::employer::{
+        employers,
+        jobs::{Job, JobKind, SalaryKind, Workplace},
+    },
+    filters,
+    helpers::{DATE_FORMAT, DATE_FORMAT_3, build_dashboard_image_url},
     misc::Member,
 };
 
@@ -29,6 +34,43 @@ pub(crate) struct PendingPage {
     pub jobs: Vec<JobSummary>,
 }
 
+/// Template for the job preview page in the moderator dashboard, including
+/// the changes made to the job since it was last reviewed.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/moderator/job_preview.html")]
+pub(crate) struct PreviewPage {
+    /// Fields changed since the job was last reviewed.
+    pub changes: Vec<JobFieldChange>,
+    /// Employer information for the job.
+    pub employer: employers::Employer,
+    /// Current version of the job.
+    pub job: Job,
+
+    /// Timestamp when the job was last reviewed, if it has changed since.
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+impl PreviewPage {
+    /// Create a new preview page, comparing the job with the version last
+    /// reviewed (if any).
+    pub(crate) fn new(employer: employers::Employer, job: Job, reviewed_job: Option<ReviewedJob>) -> Self {
+        let (changes, reviewed_at) = match reviewed_job {
+            Some(reviewed_job) => (
+                JobFieldChange::list(&reviewed_job.job, &job),
+                Some(reviewed_job.reviewed_at),
+            ),
+            None => (vec![], None),
+        };
+
+        Self {
+            changes,
+            employer,
+            job,
+            reviewed_at,
+        }
+    }
+}
+
 // Types.
 
 /// Summary information for a job, used in moderator dashboard listings.
@@ -61,3 +103,176 @@ pub(crate) struct Employer {
     /// Optional website URL for the employer.
     pub website_url: Option<String>,
 }
+
+/// Change of a job field between two versions of a job.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct JobFieldChange {
+    /// Name of the field changed.
+    pub field: String,
+
+    /// Value of the field in the current version.
+    pub current: Option<String>,
+    /// Value of the field in the previous version.
+    pub previous: Option<String>,
+}
+
+impl JobFieldChange {
+    /// List the fields that differ between the two versions of the job.
+    pub(crate) fn list(previous: &Job, current: &Job) -> Vec<JobFieldChange> {
+        comparable_fields(previous)
+            .into_iter()
+            .zip(comparable_fields(current))
+            .filter(|((_, previous), (_, current))| previous != current)
+            .map(|((field, previous), (_, current))| JobFieldChange {
+                field: field.to_string(),
+                current,
+                previous,
+            })
+            .collect()
+    }
+}
+
+/// Reviewed version of a job.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct ReviewedJob {
+    /// Job as it was when it was reviewed.
+    pub job: Job,
+    /// Timestamp when the job was reviewed.
+    pub reviewed_at: DateTime<Utc>,
+}
+
+/// Returns a printable version of the job fields compared between versions.
+fn comparable_fields(job: &Job) -> Vec<(&'static str, Option<String>)> {
+    let join = |items: Vec<String>, sep: &str| (!items.is_empty()).then(|| items.join(sep));
+
+    let salary = match (job.salary, job.salary_min, job.salary_max) {
+        (Some(salary), _, _) => Some(salary.to_string()),
+        (None, Some(min), Some(max)) => Some(format!("{min} - {max}")),
+        _ => None,
+    }
+    .map(|amount| {
+        let currency = job.salary_currency.as_deref().unwrap_or_default();
+        let period = job
+            .salary_period
+            .as_ref()
+            .map(|p| format!(" / {p}"))
+            .unwrap_or_default();
+        format!("{currency} {amount}{period}").trim().to_string()
+    });
+    let timezone = (job.tz_start.is_some() || job.tz_end.is_some()).then(|| {
+        format!(
+            "{} - {}",
+            job.tz_start.as_deref().unwrap_or_default(),
+            job.tz_end.as_deref().unwrap_or_default()
+        )
+    });
+
+    vec![
+        ("Title", Some(job.title.clone())),
+        ("Kind", Some(job.kind.to_string())),
+        ("Workplace", Some(job.workplace.to_string())),
+        ("Location", job.location.as_ref().map(ToString::to_string)),
+        ("Seniority", job.seniority.as_ref().map(ToString::to_string)),
+        ("Salary", salary),
+        ("Description", Some(job.description.clone())),
+        ("Responsibilities", job.responsibilities.clone()),
+        ("Qualifications", job.qualifications.clone()),
+        ("Benefits", join(job.benefits.clone().unwrap_or_default(), ", ")),
+        ("Skills", join(job.skills.clone().unwrap_or_default(), ", ")),
+        (
+            "Projects",
+            join(
+                job.projects.iter().flatten().map(|p| p.name.clone()).collect(),
+                ", ",
+            ),
+        ),
+        (
+            "Certifications",
+            join(
+                job.certifications
+                    .iter()
+                    .flatten()
+                    .map(|c| c.short_name.clone())
+                    .collect(),
+                ", ",
+            ),
+        ),
+        ("Timezone", timezone),
+        ("Open source", job.open_source.map(|v| v.to_string())),
+        (
+            "Upstream commitment",
+            job.upstream_commitment.map(|v| v.to_string()),
+        ),
+        ("Apply URL", job.apply_url.clone()),
+        ("Apply instructions", job.apply_instructions.clone()),
+        (
+            "Screening questions",
+            join(
+                job.screening_questions
+                    .iter()
+                    .flatten()
+                    .map(|q| format!("{} ({})", q.question, q.kind))
+                    .collect(),
+                "\n",
+            ),
+        ),
+        (
+            "Publish date",
+            job.publish_date.map(|d| d.format(DATE_FORMAT).to_string()),
+        ),
+        (
+            "Closing date",
+            job.closing_date.map(|d| d.format(DATE_FORMAT).to_string()),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::templates::test_helpers::create_base_dashboard_job;
+
+    use super::JobFieldChange;
+
+    #[test]
+    fn list_changed_fields() {
+        let previous = create_base_dashboard_job();
+        let mut current = create_base_dashboard_job();
+        current.title = "Senior Software Engineer".to_string();
+
+        assert_eq!(
+            JobFieldChange::list(&previous, &current),
+            vec![JobFieldChange {
+                field: "Title".to_string(),
+                current: Some("Senior Software Engineer".to_string()),
+                previous: Some("Software Engineer".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn list_unchanged_fields() {
+        let previous = create_base_dashboard_job();
+        let current = create_base_dashboard_job();
+
+        assert!(JobFieldChange::list(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn list_fields_set() {
+        let previous = create_base_dashboard_job();
+        let mut current = create_base_dashboard_job();
+        current.closing_date = NaiveDate::from_ymd_opt(2030, 1, 31);
+        current.skills = Some(vec!["rust".to_string(), "go".to_string()]);
+
+        let changes = JobFieldChange::list(&previous, &current);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].field, "Skills");
+        assert_eq!(changes[0].previous, None);
+        assert_eq!(changes[0].current, Some("rust, go".to_string()));
+        assert_eq!(changes[1].field, "Closing date");
+        assert_eq!(changes[1].previous, None);
+        assert!(changes[1].current.is_some());
+    }
+}
@@ -0,0 +1,48 @@
+//! Templates and types for the moderator dashboard notifications pages.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use uuid::Uuid;
+
+use crate::{
+    notifications::NotificationKind,
+    templates::{filters, helpers::DATE_FORMAT},
+};
+
+/// Time format used to display the notifications timestamps.
+const TIME_FORMAT: &str = "%H:%M";
+
+// Pages templates.
+
+/// Template for the failed notifications page in the moderator dashboard.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "dashboard/moderator/failed_notifications.html")]
+pub(crate) struct FailedPage {
+    /// List of notifications that could not be delivered.
+    pub notifications: Vec<FailedNotification>,
+}
+
+// Types.
+
+/// Notification that could not be delivered after all the attempts made.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FailedNotification {
+    /// Number of delivery attempts made.
+    pub attempts: i32,
+    /// Timestamp when the notification was created.
+    pub created_at: DateTime<Utc>,
+    /// Email address of the recipient.
+    pub email: String,
+    /// Error returned by the last delivery attempt.
+    pub error: String,
+    /// Kind of notification.
+    pub kind: NotificationKind,
+    /// Unique identifier for the notification.
+    pub notification_id: Uuid,
+
+    /// Timestamp when the notification was dead-lettered.
+    pub processed_at: Option<DateTime<Utc>>,
+}
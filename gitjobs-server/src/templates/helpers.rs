@@ -29,6 +29,19 @@ pub(crate) fn build_jobboard_image_url(image_id: &Uuid, version: &str) -> String
     format!("/jobboard/images/{image_id}/{version}")
 }
 
+/// Segments used by other routes under the path of a job, which can't be used
+/// as its slug.
+const RESERVED_JOB_PATH_SEGMENTS: [&str; 2] = ["apply", "views"];
+
+/// Build the path of the standalone page of a job.
+pub(crate) fn build_job_path(job_id: &Uuid, title: &str) -> String {
+    let mut slug = slugify(title);
+    if RESERVED_JOB_PATH_SEGMENTS.contains(&slug.as_str()) {
+        slug.push_str("-job");
+    }
+    format!("/jobs/{job_id}/{slug}")
+}
+
 /// Find an employer by id in a list of employers.
 pub(crate) fn find_employer<'a>(
     employer_id: Option<&'a Uuid>,
@@ -86,6 +99,19 @@ pub(crate) fn normalize(s: &str) -> String {
     MULTIPLE_HYPHENS.replace(&normalized, "-").to_string()
 }
 
+/// Build a URL friendly slug from the string provided, made of its lowercase
+/// ASCII alphanumeric words separated by hyphens.
+pub(crate) fn slugify(s: &str) -> String {
+    let slug = s
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    if slug.is_empty() { "job".to_string() } else { slug }
+}
+
 /// Convert a salary to a yearly USD value using exchange rates and period.
 pub(crate) async fn normalize_salary(
     salary: Option<i64>,
@@ -163,3 +189,55 @@ async fn download_exchange_rates() -> Result<HashMap<String, f64>> {
 struct ExchangeRatesApiResponse {
     pub usd: HashMap<String, f64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::{build_job_path, slugify};
+
+    const JOB_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    #[test]
+    fn slugify_joins_lowercase_words() {
+        assert_eq!(slugify("Senior Rust Engineer"), "senior-rust-engineer");
+        assert_eq!(
+            slugify("  C++ / Go -- Developer (Remote)  "),
+            "c-go-developer-remote"
+        );
+        assert_eq!(slugify("Développeur Backend"), "d-veloppeur-backend");
+    }
+
+    #[test]
+    fn slugify_empty_falls_back_to_job() {
+        assert_eq!(slugify(""), "job");
+        assert_eq!(slugify("¿¡ !?"), "job");
+    }
+
+    #[test]
+    fn build_job_path_uses_slug() {
+        let job_id = Uuid::parse_str(JOB_ID).unwrap();
+        assert_eq!(
+            build_job_path(&job_id, "Software Engineer"),
+            format!("/jobs/{JOB_ID}/software-engineer")
+        );
+        assert_eq!(build_job_path(&job_id, "!!!"), format!("/jobs/{JOB_ID}/job"));
+    }
+
+    #[test]
+    fn build_job_path_avoids_reserved_segments() {
+        let job_id = Uuid::parse_str(JOB_ID).unwrap();
+        assert_eq!(
+            build_job_path(&job_id, "Apply"),
+            format!("/jobs/{JOB_ID}/apply-job")
+        );
+        assert_eq!(
+            build_job_path(&job_id, "VIEWS!"),
+            format!("/jobs/{JOB_ID}/views-job")
+        );
+        assert_eq!(
+            build_job_path(&job_id, "Apply Engineer"),
+            format!("/jobs/{JOB_ID}/apply-engineer")
+        );
+    }
+}
@@ -1,5 +1,8 @@
 //! Templates and types for job board embed pages and cards.
 
+use std::fmt;
+
+use anyhow::{Error, bail};
 use askama::Template;
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +21,8 @@ pub(crate) struct JobsPage {
     pub base_url: String,
     /// List of jobs to display.
     pub jobs: Vec<JobSummary>,
+    /// Options to customize the look of the embed.
+    pub options: EmbedOptions,
 }
 
 /// Template for a single job card embed, rendered as SVG.
@@ -30,3 +35,97 @@ pub(crate) struct JobCard {
     /// Job data to display in the card.
     pub job: Option<Job>,
 }
+
+// Types.
+
+/// Options to customize the look of the jobs page embed, provided in the
+/// query string. All of them are validated, so only known values end up in
+/// the rendered page.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EmbedOptions {
+    /// Accent color, used to highlight open source jobs.
+    pub accent_color: Option<HexColor>,
+    /// Background color of the embed.
+    pub bg_color: Option<HexColor>,
+    /// Layout used to display the jobs.
+    #[serde(default)]
+    pub layout: Layout,
+    /// Color theme.
+    #[serde(default)]
+    pub theme: Theme,
+}
+
+/// Color in hexadecimal notation (i.e. `#1c1917`). The leading `#` is
+/// optional when the color is provided.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub(crate) struct HexColor(String);
+
+impl fmt::Display for HexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<HexColor> for String {
+    fn from(color: HexColor) -> Self {
+        color.0
+    }
+}
+
+impl TryFrom<String> for HexColor {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let hex = value.strip_prefix('#').unwrap_or(&value);
+        if !matches!(hex.len(), 3 | 6) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("invalid color: {value}");
+        }
+        Ok(Self(format!("#{}", hex.to_lowercase())))
+    }
+}
+
+/// Layout used to display the jobs in the embed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum Layout {
+    /// One line per job, with its main details only.
+    Compact,
+    /// Full job cards, like in the job board (default).
+    #[default]
+    Detailed,
+}
+
+/// Color theme of the embed.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum Theme {
+    /// Dark theme.
+    Dark,
+    /// Light theme (default).
+    #[default]
+    Light,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HexColor;
+
+    #[test]
+    fn hex_color_valid() {
+        assert_eq!(
+            HexColor::try_from("#1C1917".to_string()).unwrap().to_string(),
+            "#1c1917"
+        );
+        assert_eq!(HexColor::try_from("fff".to_string()).unwrap().to_string(), "#fff");
+    }
+
+    #[test]
+    fn hex_color_invalid() {
+        for value in ["", "#12345", "red", "#fff;}body{display:none", "#ggg"] {
+            assert!(HexColor::try_from(value.to_string()).is_err(), "{value}");
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Templates and types for the job board feeds.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::templates::{dashboard::employer::jobs::Workplace, filters, jobboard::jobs::JobSummary};
+
+/// Template for the jobs Atom feed.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "jobboard/feeds/jobs_atom.xml")]
+pub(crate) struct AtomFeed {
+    /// Base URL for job links.
+    pub base_url: String,
+    /// Path (and query) of the feed.
+    pub feed_url: String,
+    /// List of jobs to include in the feed.
+    pub jobs: Vec<JobSummary>,
+    /// Path (and query) of the job board page equivalent to the feed.
+    pub page_url: String,
+    /// Timestamp when the feed was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Template for the jobs RSS feed.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "jobboard/feeds/jobs_rss.xml")]
+pub(crate) struct RssFeed {
+    /// Base URL for job links.
+    pub base_url: String,
+    /// Path (and query) of the feed.
+    pub feed_url: String,
+    /// List of jobs to include in the feed.
+    pub jobs: Vec<JobSummary>,
+    /// Path (and query) of the job board page equivalent to the feed.
+    pub page_url: String,
+    /// Timestamp when the feed was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Trait implemented by the jobs feeds templates.
+pub(crate) trait JobsFeed: Template {
+    /// Content type of the feed.
+    const CONTENT_TYPE: &str;
+    /// Path of the feed.
+    const PATH: &str;
+
+    /// Create a new feed including the jobs provided.
+    fn new(base_url: String, feed_url: String, page_url: String, jobs: Vec<JobSummary>) -> Self;
+}
+
+impl JobsFeed for AtomFeed {
+    const CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+    const PATH: &str = "/feeds/jobs.atom";
+
+    fn new(base_url: String, feed_url: String, page_url: String, jobs: Vec<JobSummary>) -> Self {
+        Self {
+            base_url,
+            feed_url,
+            page_url,
+            updated_at: last_updated_at(&jobs),
+            jobs,
+        }
+    }
+}
+
+impl JobsFeed for RssFeed {
+    const CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+    const PATH: &str = "/feeds/jobs.rss";
+
+    fn new(base_url: String, feed_url: String, page_url: String, jobs: Vec<JobSummary>) -> Self {
+        Self {
+            base_url,
+            feed_url,
+            page_url,
+            updated_at: last_updated_at(&jobs),
+            jobs,
+        }
+    }
+}
+
+/// Returns the most recent publication or update timestamp of the jobs provided.
+fn last_updated_at(jobs: &[JobSummary]) -> DateTime<Utc> {
+    jobs.iter()
+        .map(|job| job.updated_at.unwrap_or(job.published_at).max(job.published_at))
+        .max()
+        .unwrap_or_else(Utc::now)
+}
+
+#[cfg(test)]
+mod tests {
+    use askama::Template;
+
+    use crate::templates::{
+        misc::Location,
+        test_helpers::{assert_golden_file, create_job_summary},
+    };
+
+    use super::*;
+
+    const BASE_URL: &str = "https://example.com";
+
+    fn create_jobs() -> Vec<JobSummary> {
+        let mut job = create_job_summary("550e8400-e29b-41d4-a716-446655440010", "Software Engineer");
+        job.location = Some(Location {
+            location_id: uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002").unwrap(),
+            city: "San Francisco".to_string(),
+            country: "United States".to_string(),
+            state: Some("CA".to_string()),
+        });
+        job.skills = Some(vec!["rust".to_string(), "kubernetes".to_string()]);
+
+        vec![
+            job,
+            create_job_summary(
+                "550e8400-e29b-41d4-a716-446655440011",
+                "Site Reliability Engineer & SRE",
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_jobs_atom_feed() {
+        let feed = AtomFeed::new(
+            BASE_URL.to_string(),
+            "/feeds/jobs.atom?workplace[0]=remote".to_string(),
+            "/?workplace[0]=remote".to_string(),
+            create_jobs(),
+        );
+
+        assert_golden_file("feed_jobs_atom", &feed.render().unwrap());
+    }
+
+    #[test]
+    fn test_jobs_rss_feed() {
+        let feed = RssFeed::new(
+            BASE_URL.to_string(),
+            "/feeds/jobs.rss?workplace[0]=remote".to_string(),
+            "/?workplace[0]=remote".to_string(),
+            create_jobs(),
+        );
+
+        assert_golden_file("feed_jobs_rss", &feed.render().unwrap());
+    }
+}
@@ -9,13 +9,25 @@ use uuid::Uuid;
 use crate::templates::{
     Config, PageId,
     auth::User,
-    dashboard::employer::jobs::{JobKind, SalaryKind, Workplace},
+    dashboard::employer::{
+        applications::ScreeningAnswer,
+        jobs::{JobKind, SalaryKind, ScreeningQuestion, ScreeningQuestionKind, Workplace},
+    },
     filters,
-    helpers::{DATE_FORMAT, DATE_FORMAT_3, build_jobboard_image_url, option_is_none_or_default},
+    helpers::{
+        DATE_FORMAT, DATE_FORMAT_3, build_job_path, build_jobboard_image_url, option_is_none_or_default,
+    },
+    jobboard::structured_data::JobPosting,
     misc::{Certification, Foundation, Location, Member, Project},
     pagination::{NavigationLinks, Pagination},
 };
 
+/// Maximum length of the cover letter provided when applying to a job.
+pub(crate) const MAX_COVER_LETTER_LEN: usize = 5000;
+
+/// Maximum length of each of the screening answers provided when applying.
+pub(crate) const MAX_SCREENING_ANSWER_LEN: usize = 2000;
+
 // Pages and sections templates.
 
 /// Template for the main jobs page.
@@ -33,6 +45,8 @@ pub(crate) struct JobsPage {
 
     /// Name of the authentication provider, if any.
     pub auth_provider: Option<String>,
+    /// Structured data (JSON-LD) of the job requested, if any.
+    pub job_posting: Option<String>,
 }
 
 /// Template for the explore section, containing filters and results.
@@ -62,6 +76,25 @@ pub(crate) struct ResultsSection {
     pub offset: Option<usize>,
 }
 
+/// Template for the standalone page of a job.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "jobboard/jobs/job_page.html")]
+pub(crate) struct JobPage {
+    /// Base URL for job links and assets.
+    pub base_url: String,
+    /// Server configuration.
+    pub cfg: Config,
+    /// Full job details.
+    pub job: Job,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Authenticated user information.
+    pub user: User,
+
+    /// Name of the authentication provider, if any.
+    pub auth_provider: Option<String>,
+}
+
 /// Template for a single job section, displaying job details.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "jobboard/jobs/job_section.html")]
@@ -107,6 +140,14 @@ pub(crate) struct Filters {
     /// Project filters.
     #[serde(skip_serializing_if = "option_is_none_or_default")]
     pub projects: Option<Vec<JobProject>>,
+    /// Only include jobs published after this timestamp (set internally, it
+    /// can't be provided by users).
+    #[serde(skip)]
+    pub published_after: Option<DateTime<Utc>>,
+    /// Only include jobs published at or before this timestamp (set
+    /// internally, it can't be provided by users).
+    #[serde(skip)]
+    pub published_before: Option<DateTime<Utc>>,
     /// Minimum salary filter.
     #[serde(skip_serializing_if = "option_is_none_or_default")]
     pub salary_min: Option<u64>,
@@ -334,6 +375,8 @@ pub(crate) struct Job {
     pub salary_max: Option<i64>,
     /// Salary period, if specified.
     pub salary_period: Option<String>,
+    /// Screening questions to answer when applying, if any.
+    pub screening_questions: Option<Vec<ScreeningQuestion>>,
     /// Seniority level, if specified.
     pub seniority: Option<Seniority>,
     /// List of required skills, if any.
@@ -349,6 +392,16 @@ pub(crate) struct Job {
 }
 
 impl Job {
+    /// Returns the schema.org `JobPosting` JSON-LD document for the job.
+    pub(crate) fn json_ld(&self, base_url: &str) -> String {
+        JobPosting::new(base_url, self).to_json_ld()
+    }
+
+    /// Returns the path of the standalone page of the job.
+    pub(crate) fn path(&self) -> String {
+        build_job_path(&self.job_id, &self.title)
+    }
+
     /// Determines if the job salary is a fixed value or a range.
     #[allow(dead_code)]
     pub(crate) fn salary_kind(&self) -> SalaryKind {
@@ -359,3 +412,140 @@ impl Job {
         }
     }
 }
+
+/// Information provided by a job seeker when applying to a job.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct JobApplication {
+    /// Cover letter, if provided.
+    pub cover_letter: Option<String>,
+    /// Answers to the job screening questions, in the same order.
+    pub screening_answers: Option<Vec<String>>,
+}
+
+impl JobApplication {
+    /// Returns the cover letter provided, if it is not empty.
+    pub(crate) fn cover_letter(&self) -> Option<&str> {
+        self.cover_letter.as_deref().map(str::trim).filter(|c| !c.is_empty())
+    }
+
+    /// Check if the cover letter provided does not exceed the maximum length.
+    pub(crate) fn cover_letter_is_valid(&self) -> bool {
+        self.cover_letter()
+            .is_none_or(|cover_letter| cover_letter.chars().count() <= MAX_COVER_LETTER_LEN)
+    }
+
+    /// Matches the answers provided with the screening questions of the job.
+    ///
+    /// Returns `None` if any question has not been answered, if the answer is
+    /// too long or if it is not valid for the kind of question (yes/no and
+    /// single choice answers must be one of the options available).
+    pub(crate) fn screening_answers(&self, questions: &[ScreeningQuestion]) -> Option<Vec<ScreeningAnswer>> {
+        let answers = self.screening_answers.as_deref().unwrap_or_default();
+        questions
+            .iter()
+            .enumerate()
+            .map(|(i, question)| {
+                let answer = answers
+                    .get(i)
+                    .map(|a| a.trim())
+                    .filter(|a| !a.is_empty() && a.chars().count() <= MAX_SCREENING_ANSWER_LEN)?;
+                let valid = match question.kind {
+                    ScreeningQuestionKind::FreeText => true,
+                    ScreeningQuestionKind::SingleChoice => {
+                        question.options.iter().flatten().any(|option| option == answer)
+                    }
+                    ScreeningQuestionKind::YesNo => answer == "yes" || answer == "no",
+                };
+                valid.then(|| ScreeningAnswer {
+                    answer: answer.to_string(),
+                    question: question.question.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn questions() -> Vec<ScreeningQuestion> {
+        vec![
+            ScreeningQuestion {
+                kind: ScreeningQuestionKind::YesNo,
+                question: "Can you relocate?".to_string(),
+                options: None,
+            },
+            ScreeningQuestion {
+                kind: ScreeningQuestionKind::SingleChoice,
+                question: "Years of experience?".to_string(),
+                options: Some(vec!["0-2".to_string(), "3+".to_string()]),
+            },
+            ScreeningQuestion {
+                kind: ScreeningQuestionKind::FreeText,
+                question: "Why us?".to_string(),
+                options: None,
+            },
+        ]
+    }
+
+    fn application(answers: &[&str]) -> JobApplication {
+        JobApplication {
+            cover_letter: None,
+            screening_answers: Some(answers.iter().map(ToString::to_string).collect()),
+        }
+    }
+
+    #[test]
+    fn screening_answers_valid() {
+        let answers = application(&["yes", "3+", " Because "])
+            .screening_answers(&questions())
+            .unwrap();
+
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers[1].question, "Years of experience?");
+        assert_eq!(answers[2].answer, "Because");
+    }
+
+    #[test]
+    fn screening_answers_missing_answer() {
+        assert!(application(&["yes", "3+"]).screening_answers(&questions()).is_none());
+    }
+
+    #[test]
+    fn screening_answers_yes_no_not_in_options() {
+        assert!(
+            application(&["maybe", "3+", "Because"])
+                .screening_answers(&questions())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn screening_answers_single_choice_not_in_options() {
+        assert!(
+            application(&["yes", "10+", "Because"])
+                .screening_answers(&questions())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn screening_answers_too_long() {
+        let answer = "a".repeat(MAX_SCREENING_ANSWER_LEN + 1);
+        assert!(
+            application(&["yes", "3+", &answer])
+                .screening_answers(&questions())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn cover_letter_too_long() {
+        let mut application = application(&[]);
+        application.cover_letter = Some("a".repeat(MAX_COVER_LETTER_LEN));
+        assert!(application.cover_letter_is_valid());
+        application.cover_letter = Some("a".repeat(MAX_COVER_LETTER_LEN + 1));
+        assert!(!application.cover_letter_is_valid());
+    }
+}
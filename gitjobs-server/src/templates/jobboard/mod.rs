@@ -2,5 +2,8 @@
 
 pub(crate) mod about;
 pub(crate) mod embed;
+pub(crate) mod feeds;
 pub(crate) mod jobs;
+pub(crate) mod seo;
 pub(crate) mod stats;
+pub(crate) mod structured_data;
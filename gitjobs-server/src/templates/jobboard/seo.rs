@@ -0,0 +1,44 @@
+//! Templates and types used to help search engines crawl the job board.
+
+use askama::Template;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::templates::helpers::build_job_path;
+
+/// Template for the robots.txt file.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "jobboard/seo/robots.txt")]
+pub(crate) struct RobotsTxt {
+    /// Base URL used to build the sitemap location.
+    pub base_url: String,
+}
+
+/// Template for the sitemap listing the job board pages and published jobs.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "jobboard/seo/sitemap.xml")]
+pub(crate) struct Sitemap {
+    /// Base URL for the pages locations.
+    pub base_url: String,
+    /// Published jobs.
+    pub jobs: Vec<SitemapJob>,
+}
+
+/// Published job included in the sitemap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct SitemapJob {
+    /// Unique identifier for the job.
+    pub job_id: Uuid,
+    /// Title of the job.
+    pub title: String,
+    /// Timestamp when the job was last updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SitemapJob {
+    /// Returns the path of the standalone page of the job.
+    pub(crate) fn path(&self) -> String {
+        build_job_path(&self.job_id, &self.title)
+    }
+}
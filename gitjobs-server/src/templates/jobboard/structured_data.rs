@@ -0,0 +1,316 @@
+//! Types used to describe jobs as schema.org structured data, so that they can
+//! be indexed by search engines.
+
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::templates::{
+    dashboard::employer::jobs::{JobKind, Workplace},
+    helpers::build_jobboard_image_url,
+    jobboard::jobs::Job,
+};
+
+/// schema.org `JobPosting` describing a job.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct JobPosting {
+    #[serde(rename = "@context")]
+    context: &'static str,
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    description: String,
+    employment_type: &'static str,
+    hiring_organization: Organization,
+    identifier: PropertyValue,
+    title: String,
+    url: String,
+
+    applicant_location_requirements: Option<Country>,
+    base_salary: Option<MonetaryAmount>,
+    date_posted: Option<String>,
+    date_modified: Option<String>,
+    job_location: Option<Place>,
+    job_location_type: Option<&'static str>,
+    skills: Option<String>,
+}
+
+impl JobPosting {
+    /// Create a new `JobPosting` from the job provided.
+    pub(crate) fn new(base_url: &str, job: &Job) -> Self {
+        let url = format!("{base_url}{}", job.path());
+
+        // Description (schema.org expects it in HTML)
+        let description = markdown::to_html_with_options(&job.description, &markdown::Options::gfm())
+            .unwrap_or_else(|_| job.description.clone());
+
+        // Location
+        let (job_location, applicant_location_requirements) = match &job.location {
+            Some(location) if job.workplace == Workplace::Remote => (
+                None,
+                Some(Country {
+                    kind: "Country",
+                    name: location.country.clone(),
+                }),
+            ),
+            Some(location) => (
+                Some(Place {
+                    kind: "Place",
+                    address: PostalAddress {
+                        kind: "PostalAddress",
+                        address_country: location.country.clone(),
+                        address_locality: location.city.clone(),
+                        address_region: location.state.clone(),
+                    },
+                }),
+                None,
+            ),
+            None => (None, None),
+        };
+
+        Self {
+            context: "https://schema.org",
+            kind: "JobPosting",
+            description,
+            employment_type: employment_type(&job.kind),
+            hiring_organization: Organization {
+                kind: "Organization",
+                name: job.employer.company.clone(),
+                logo: job
+                    .employer
+                    .logo_id
+                    .map(|logo_id| format!("{base_url}{}", build_jobboard_image_url(&logo_id, "small"))),
+                same_as: job.employer.website_url.clone(),
+            },
+            identifier: PropertyValue {
+                kind: "PropertyValue",
+                name: "GitJobs",
+                value: job.job_id.to_string(),
+            },
+            title: job.title.clone(),
+            url,
+            applicant_location_requirements,
+            base_salary: MonetaryAmount::new(job),
+            date_posted: job.published_at.map(|ts| ts.format("%Y-%m-%d").to_string()),
+            date_modified: job.updated_at.map(|ts| ts.to_rfc3339()),
+            job_location,
+            job_location_type: (job.workplace == Workplace::Remote).then_some("TELECOMMUTE"),
+            skills: job.skills.as_ref().filter(|s| !s.is_empty()).map(|s| s.join(", ")),
+        }
+    }
+
+    /// Render the job posting as a JSON-LD document, ready to be embedded in
+    /// a script element.
+    pub(crate) fn to_json_ld(&self) -> String {
+        serde_json::to_string_pretty(self)
+            .unwrap_or_default()
+            .replace("</", "<\\/")
+    }
+}
+
+/// schema.org `Country`.
+#[derive(Debug, Clone, Serialize)]
+struct Country {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    name: String,
+}
+
+/// schema.org `MonetaryAmount`, used to describe the salary.
+#[derive(Debug, Clone, Serialize)]
+struct MonetaryAmount {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    currency: String,
+    value: QuantitativeValue,
+}
+
+impl MonetaryAmount {
+    /// Create a new `MonetaryAmount` from the job salary, if it's available.
+    fn new(job: &Job) -> Option<Self> {
+        let currency = job.salary_currency.clone()?;
+        let unit_text = match job.salary_period.as_deref()? {
+            "year" => "YEAR",
+            "month" => "MONTH",
+            "week" => "WEEK",
+            "day" => "DAY",
+            "hour" => "HOUR",
+            _ => return None,
+        };
+        if job.salary.is_none() && job.salary_min.is_none() && job.salary_max.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            kind: "MonetaryAmount",
+            currency,
+            value: QuantitativeValue {
+                kind: "QuantitativeValue",
+                unit_text,
+                max_value: job.salary_max,
+                min_value: job.salary_min,
+                value: job.salary,
+            },
+        })
+    }
+}
+
+/// schema.org `Organization`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Organization {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    name: String,
+
+    logo: Option<String>,
+    same_as: Option<String>,
+}
+
+/// schema.org `Place`.
+#[derive(Debug, Clone, Serialize)]
+struct Place {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    address: PostalAddress,
+}
+
+/// schema.org `PostalAddress`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PostalAddress {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    address_country: String,
+    address_locality: String,
+
+    address_region: Option<String>,
+}
+
+/// schema.org `PropertyValue`, used to identify the job.
+#[derive(Debug, Clone, Serialize)]
+struct PropertyValue {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    name: &'static str,
+    value: String,
+}
+
+/// schema.org `QuantitativeValue`.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct QuantitativeValue {
+    #[serde(rename = "@type")]
+    kind: &'static str,
+    unit_text: &'static str,
+
+    max_value: Option<i64>,
+    min_value: Option<i64>,
+    value: Option<i64>,
+}
+
+/// Returns the schema.org employment type for the job kind provided.
+fn employment_type(kind: &JobKind) -> &'static str {
+    match kind {
+        JobKind::Contractor => "CONTRACTOR",
+        JobKind::FullTime => "FULL_TIME",
+        JobKind::Internship => "INTERN",
+        JobKind::PartTime => "PART_TIME",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use uuid::Uuid;
+
+    use crate::templates::{
+        dashboard::employer::jobs::{JobKind, Workplace},
+        jobboard::jobs::Job,
+        misc::Location,
+        test_helpers::{self, assert_golden_file},
+    };
+
+    use super::JobPosting;
+
+    const BASE_URL: &str = "https://example.com";
+
+    /// Create a published job with the minimum details set.
+    fn create_base_job() -> Job {
+        let mut job = test_helpers::create_base_job();
+        job.published_at = Some(Utc.with_ymd_and_hms(2025, 1, 15, 10, 0, 0).unwrap());
+        job
+    }
+
+    fn create_location() -> Location {
+        Location {
+            location_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002").unwrap(),
+            city: "San Francisco".to_string(),
+            country: "USA".to_string(),
+            state: Some("CA".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_job_posting_minimal() {
+        let job = create_base_job();
+
+        let json_ld = JobPosting::new(BASE_URL, &job).to_json_ld();
+        assert_golden_file("job_posting_minimal", &json_ld);
+    }
+
+    #[test]
+    fn test_job_posting_full_details() {
+        let mut job = create_base_job();
+        job.description = "## About the role\n\nBuild **great** things.".to_string();
+        job.employer.logo_id = Some(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440003").unwrap());
+        job.employer.website_url = Some("https://acme.example.com".to_string());
+        job.kind = JobKind::Contractor;
+        job.location = Some(create_location());
+        job.salary_currency = Some("USD".to_string());
+        job.salary_min = Some(100_000);
+        job.salary_max = Some(150_000);
+        job.salary_period = Some("year".to_string());
+        job.skills = Some(vec!["rust".to_string(), "kubernetes".to_string()]);
+        job.updated_at = Some(Utc.with_ymd_and_hms(2025, 1, 20, 12, 30, 0).unwrap());
+
+        let json_ld = JobPosting::new(BASE_URL, &job).to_json_ld();
+        assert_golden_file("job_posting_full_details", &json_ld);
+    }
+
+    #[test]
+    fn test_job_posting_remote() {
+        let mut job = create_base_job();
+        job.workplace = Workplace::Remote;
+        job.location = Some(create_location());
+
+        let json_ld = JobPosting::new(BASE_URL, &job).to_json_ld();
+        assert_golden_file("job_posting_remote", &json_ld);
+    }
+
+    #[test]
+    fn test_job_posting_fixed_salary() {
+        let mut job = create_base_job();
+        job.kind = JobKind::PartTime;
+        job.salary = Some(50);
+        job.salary_currency = Some("EUR".to_string());
+        job.salary_period = Some("hour".to_string());
+
+        let json_ld = JobPosting::new(BASE_URL, &job).to_json_ld();
+        assert_golden_file("job_posting_fixed_salary", &json_ld);
+    }
+
+    #[test]
+    fn test_job_posting_escapes_script_end() {
+        let mut job = create_base_job();
+        job.kind = JobKind::Internship;
+        job.title = "Intern </script><script>alert(1)</script>".to_string();
+
+        let json_ld = JobPosting::new(BASE_URL, &job).to_json_ld();
+        assert!(!json_ld.contains("</script>"));
+        assert_golden_file("job_posting_escapes_script_end", &json_ld);
+    }
+}
@@ -4,7 +4,10 @@ use askama::Template;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::templates::{Config, PageId, auth::User, filters, helpers::format_location};
+use crate::{
+    notifications::NotificationKind,
+    templates::{Config, PageId, auth::User, filters, helpers::format_location},
+};
 
 // Templates.
 
@@ -23,6 +26,36 @@ pub(crate) struct NotFoundPage {
     pub auth_provider: Option<String>,
 }
 
+/// Template for the page to unsubscribe from a notification kind.
+#[derive(Debug, Clone, Template, PartialEq, Serialize, Deserialize)]
+#[template(path = "misc/unsubscribe.html")]
+pub(crate) struct UnsubscribePage {
+    /// Server configuration.
+    pub cfg: Config,
+    /// Kind of notification to unsubscribe from.
+    pub kind: NotificationKind,
+    /// Identifier for the current page.
+    pub page_id: PageId,
+    /// Whether the user has been unsubscribed already.
+    pub unsubscribed: bool,
+    /// Authenticated user information.
+    pub user: User,
+
+    /// Name of the authentication provider, if any.
+    pub auth_provider: Option<String>,
+}
+
+impl UnsubscribePage {
+    /// Returns the URL of the account settings page where the notifications
+    /// of this kind can be managed.
+    fn settings_url(&self) -> &'static str {
+        match self.kind {
+            NotificationKind::JobAlertDigest => "/dashboard/job-seeker?tab=account",
+            _ => "/dashboard/employer?tab=account",
+        }
+    }
+}
+
 /// Template for the user menu section in the UI.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "misc/user_menu_section.html")]
@@ -12,6 +12,8 @@ pub(crate) mod jobboard;
 pub(crate) mod misc;
 pub(crate) mod notifications;
 pub(crate) mod pagination;
+#[cfg(test)]
+pub(crate) mod test_helpers;
 
 /// Subset of the server configuration used in templates.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -40,4 +42,5 @@ pub(crate) enum PageId {
     NotFound,
     SignUp,
     Stats,
+    Unsubscribe,
 }
@@ -1,11 +1,40 @@
 //! Templates for notification-related emails and messages.
 
 use askama::Template;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
-use super::jobboard::jobs::Job;
+use super::jobboard::jobs::{Job, JobSummary};
 
-use crate::templates::{dashboard::employer::jobs::Workplace, filters};
+use crate::templates::{dashboard::employer::jobs::Workplace, filters, helpers::DATE_FORMAT};
+
+/// Trait implemented by the email notifications templates, which provide an
+/// HTML and a plain text alternative for each email.
+pub(crate) trait EmailTemplate: Template {
+    /// Render the plain text alternative of the email.
+    fn render_text(&self) -> askama::Result<String>;
+
+    /// Render the email, including its subject, which is taken from the
+    /// `<title>` element of the rendered HTML alternative.
+    fn render_email(&self) -> askama::Result<RenderedEmail> {
+        let html = self.render()?;
+        let subject = extract_subject(&html);
+        let text = self.render_text()?;
+
+        Ok(RenderedEmail { html, subject, text })
+    }
+}
+
+/// Email rendered from a notification template.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RenderedEmail {
+    /// HTML alternative of the email body.
+    pub html: String,
+    /// Email subject.
+    pub subject: String,
+    /// Plain text alternative of the email body.
+    pub text: String,
+}
 
 // Emails templates.
 
@@ -17,12 +46,201 @@ pub(crate) struct EmailVerification {
     pub link: String,
 }
 
+/// Template for the job alert digest notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/job_alert_digest.html")]
+pub(crate) struct JobAlertDigest {
+    /// Base URL for the job board.
+    pub base_url: String,
+    /// New jobs matching the saved search.
+    pub jobs: Vec<JobSummary>,
+    /// Link to the saved searches management page.
+    pub manage_link: String,
+    /// Name of the saved search.
+    pub name: String,
+    /// Link to the job board with the saved search filters applied.
+    pub search_link: String,
+    /// Total number of new jobs matching the saved search.
+    pub total: usize,
+
+    /// Link to unsubscribe from these notifications, added when delivering
+    /// them.
+    pub unsubscribe_link: Option<String>,
+}
+
+/// Template for the job approved notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/job_approved.html")]
+pub(crate) struct JobApproved {
+    /// Title of the job approved.
+    pub job_title: String,
+    /// Link to the job in the job board.
+    pub link: String,
+
+    /// Date when the job will be published, if it has been scheduled.
+    pub scheduled_for: Option<NaiveDate>,
+    /// Link to unsubscribe from these notifications, added when delivering
+    /// them.
+    pub unsubscribe_link: Option<String>,
+}
+
+/// Template for the job expiring notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/job_expiring.html")]
+pub(crate) struct JobExpiring {
+    /// Date when the job closes.
+    pub closing_date: NaiveDate,
+    /// Title of the job expiring.
+    pub job_title: String,
+    /// Link to renew the job.
+    pub renew_link: String,
+
+    /// Link to unsubscribe from these notifications, added when delivering
+    /// them.
+    pub unsubscribe_link: Option<String>,
+}
+
+/// Template for the job rejected notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/job_rejected.html")]
+pub(crate) struct JobRejected {
+    /// Title of the job rejected.
+    pub job_title: String,
+    /// Link to the job update page.
+    pub link: String,
+
+    /// Notes provided by the moderator when rejecting the job, if any.
+    pub review_notes: Option<String>,
+    /// Link to unsubscribe from these notifications, added when delivering
+    /// them.
+    pub unsubscribe_link: Option<String>,
+}
+
+/// Template for the new application notification.
+#[derive(Debug, Clone, Template, Serialize, Deserialize)]
+#[template(path = "notifications/new_application.html")]
+pub(crate) struct NewApplication {
+    /// Title of the job applied to.
+    pub job_title: String,
+    /// Link to the applicant's profile preview page.
+    pub link: String,
+    /// Link to the account settings page.
+    pub settings_link: String,
+
+    /// Link to unsubscribe from these notifications, added when delivering
+    /// them.
+    pub unsubscribe_link: Option<String>,
+}
+
 /// Template for team invitation notification.
 #[derive(Debug, Clone, Template, Serialize, Deserialize)]
 #[template(path = "notifications/team_invitation.html")]
 pub(crate) struct TeamInvitation {
     /// Link to invitations page.
     pub link: String,
+
+    /// Name of the employer the user has been invited to.
+    pub employer_name: Option<String>,
+}
+
+// Emails plain text templates.
+
+/// Plain text template for email verification notification.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notifications/email_verification.txt")]
+pub(crate) struct EmailVerificationText<'a> {
+    /// Notification data.
+    pub data: &'a EmailVerification,
+}
+
+/// Plain text template for the job alert digest notification.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notifications/job_alert_digest.txt")]
+pub(crate) struct JobAlertDigestText<'a> {
+    /// Notification data.
+    pub data: &'a JobAlertDigest,
+}
+
+/// Plain text template for the job approved notification.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notifications/job_approved.txt")]
+pub(crate) struct JobApprovedText<'a> {
+    /// Notification data.
+    pub data: &'a JobApproved,
+}
+
+/// Plain text template for the job expiring notification.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notifications/job_expiring.txt")]
+pub(crate) struct JobExpiringText<'a> {
+    /// Notification data.
+    pub data: &'a JobExpiring,
+}
+
+/// Plain text template for the job rejected notification.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notifications/job_rejected.txt")]
+pub(crate) struct JobRejectedText<'a> {
+    /// Notification data.
+    pub data: &'a JobRejected,
+}
+
+/// Plain text template for the new application notification.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notifications/new_application.txt")]
+pub(crate) struct NewApplicationText<'a> {
+    /// Notification data.
+    pub data: &'a NewApplication,
+}
+
+/// Plain text template for team invitation notification.
+#[derive(Debug, Clone, Template)]
+#[template(path = "notifications/team_invitation.txt")]
+pub(crate) struct TeamInvitationText<'a> {
+    /// Notification data.
+    pub data: &'a TeamInvitation,
+}
+
+impl EmailTemplate for EmailVerification {
+    fn render_text(&self) -> askama::Result<String> {
+        EmailVerificationText { data: self }.render()
+    }
+}
+
+impl EmailTemplate for JobAlertDigest {
+    fn render_text(&self) -> askama::Result<String> {
+        JobAlertDigestText { data: self }.render()
+    }
+}
+
+impl EmailTemplate for JobApproved {
+    fn render_text(&self) -> askama::Result<String> {
+        JobApprovedText { data: self }.render()
+    }
+}
+
+impl EmailTemplate for JobExpiring {
+    fn render_text(&self) -> askama::Result<String> {
+        JobExpiringText { data: self }.render()
+    }
+}
+
+impl EmailTemplate for JobRejected {
+    fn render_text(&self) -> askama::Result<String> {
+        JobRejectedText { data: self }.render()
+    }
+}
+
+impl EmailTemplate for NewApplication {
+    fn render_text(&self) -> askama::Result<String> {
+        NewApplicationText { data: self }.render()
+    }
+}
+
+impl EmailTemplate for TeamInvitation {
+    fn render_text(&self) -> askama::Result<String> {
+        TeamInvitationText { data: self }.render()
+    }
 }
 
 // Slack templates.
@@ -37,83 +255,88 @@ pub(crate) struct JobPublished {
     pub job: Job,
 }
 
+// Helpers.
+
+/// Extract the subject from the title of the HTML email provided.
+fn extract_subject(html: &str) -> String {
+    let title = html
+        .split_once("<title>")
+        .and_then(|(_, rest)| rest.split_once("</title>"))
+        .map(|(title, _)| title)
+        .unwrap_or_default();
+
+    // Normalize whitespace and undo the HTML escaping
+    unescape_html(&title.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Replace the HTML character references in the text provided with the
+/// characters they represent. Both numeric references (as produced by askama)
+/// and the most common named ones are supported.
+fn unescape_html(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let reference = &rest[1..end];
+            let c = if let Some(hex) = reference.strip_prefix("#x").or(reference.strip_prefix("#X")) {
+                u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+            } else if let Some(dec) = reference.strip_prefix('#') {
+                dec.parse().ok().and_then(char::from_u32)
+            } else {
+                match reference {
+                    "amp" => Some('&'),
+                    "apos" => Some('\''),
+                    "gt" => Some('>'),
+                    "lt" => Some('<'),
+                    "quot" => Some('"'),
+                    _ => None,
+                }
+            };
+            c.map(|c| (c, end))
+        });
+
+        if let Some((c, end)) = decoded {
+            unescaped.push(c);
+            rest = &rest[end + 1..];
+        } else {
+            unescaped.push('&');
+            rest = &rest[1..];
+        }
+    }
+    unescaped.push_str(rest);
+
+    unescaped
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{env, fs};
-
     use askama::Template;
     use uuid::Uuid;
 
     use crate::templates::{
         dashboard::employer::jobs::{JobKind, Workplace},
-        jobboard::jobs::{Employer, Seniority},
+        jobboard::jobs::Seniority,
         misc::Location,
+        test_helpers::{assert_golden_file, create_base_job, create_job_summary},
     };
 
     use super::*;
 
-    fn create_base_job() -> Job {
-        Job {
-            job_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
-            title: "Software Engineer".to_string(),
-            employer: Employer {
-                employer_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap(),
-                company: "ACME Corp".to_string(),
-                description: None,
-                logo_id: None,
-                member: None,
-                website_url: None,
-            },
-            kind: JobKind::FullTime,
-            workplace: Workplace::OnSite,
-            location: None,
-            seniority: None,
-            salary: None,
-            salary_currency: None,
-            salary_min: None,
-            salary_max: None,
-            salary_period: None,
-            open_source: None,
-            upstream_commitment: None,
-            skills: None,
-            description: "Job description".to_string(),
-            apply_instructions: None,
-            apply_url: None,
-            benefits: None,
-            certifications: None,
-            projects: None,
-            published_at: None,
-            qualifications: None,
-            responsibilities: None,
-            tz_end: None,
-            tz_start: None,
-            updated_at: None,
-        }
-    }
+    const UNSUBSCRIBE_LINK: &str = "https://example.com/notifications/unsubscribe?signature=abc";
 
-    fn golden_file_path(test_name: &str) -> String {
-        format!("src/templates/testdata/{test_name}.golden")
-    }
-
-    fn assert_golden_file(test_name: &str, actual: &str) {
-        let golden_path = golden_file_path(test_name);
-
-        // Check if we should regenerate golden files
-        if env::var("REGENERATE_GOLDEN_FILES").is_ok() {
-            fs::write(&golden_path, actual).expect("Failed to write golden file");
-            eprintln!("Regenerated golden file: {golden_path}");
-            return;
-        }
-
-        // Read expected content from golden file
-        let expected = fs::read_to_string(&golden_path)
-            .unwrap_or_else(|_| panic!("Golden file not found: {golden_path}. Run tests with REGENERATE_GOLDEN_FILES=1 to generate it."));
+    /// Render the email provided, checking the HTML alternative includes the
+    /// subject and link, and return its subject and plain text alternative.
+    fn render_email(template: &impl EmailTemplate, link: &str) -> String {
+        let email = template.render_email().unwrap();
+        assert!(email.html.contains(link));
+        assert!(!email.subject.is_empty());
+        assert!(!email.text.contains('<'));
 
-        assert_eq!(
-            actual.trim(),
-            expected.trim(),
-            "Output doesn't match golden file: {golden_path}"
-        );
+        format!("Subject: {}\n\n{}", email.subject, email.text)
     }
 
     #[test]
@@ -375,4 +598,194 @@ mod tests {
         let rendered = template.render().unwrap();
         assert_golden_file("job_part_time", &rendered);
     }
+
+    #[test]
+    fn test_email_verification() {
+        let template = EmailVerification {
+            link: "https://example.com/verify-email/1234".to_string(),
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_verification", &rendered);
+    }
+
+    #[test]
+    fn test_email_job_alert_digest() {
+        let template = JobAlertDigest {
+            base_url: "https://example.com".to_string(),
+            jobs: vec![
+                create_job_summary("550e8400-e29b-41d4-a716-446655440010", "Software Engineer"),
+                create_job_summary(
+                    "550e8400-e29b-41d4-a716-446655440011",
+                    "Site Reliability Engineer",
+                ),
+            ],
+            manage_link: "https://example.com/dashboard/job-seeker?tab=saved-searches".to_string(),
+            name: "Rust jobs".to_string(),
+            search_link: "https://example.com/?ts_query=rust".to_string(),
+            total: 2,
+            unsubscribe_link: None,
+        };
+
+        let rendered = render_email(&template, &template.search_link);
+        assert_golden_file("email_job_alert_digest", &rendered);
+    }
+
+    #[test]
+    fn test_email_job_approved() {
+        let template = JobApproved {
+            job_title: "Software Engineer".to_string(),
+            link: "https://example.com/?job_id=550e8400-e29b-41d4-a716-446655440000".to_string(),
+            scheduled_for: None,
+            unsubscribe_link: None,
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_job_approved", &rendered);
+    }
+
+    #[test]
+    fn test_email_job_approved_scheduled() {
+        let template = JobApproved {
+            job_title: "Software Engineer".to_string(),
+            link: "https://example.com/dashboard/employer?tab=jobs".to_string(),
+            scheduled_for: NaiveDate::from_ymd_opt(2025, 2, 1),
+            unsubscribe_link: None,
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_job_approved_scheduled", &rendered);
+    }
+
+    #[test]
+    fn test_email_job_expiring() {
+        let template = JobExpiring {
+            closing_date: NaiveDate::from_ymd_opt(2025, 2, 15).unwrap(),
+            job_title: "Software Engineer".to_string(),
+            renew_link: "https://example.com/dashboard/employer?tab=jobs".to_string(),
+            unsubscribe_link: None,
+        };
+
+        let rendered = render_email(&template, &template.renew_link);
+        assert_golden_file("email_job_expiring", &rendered);
+    }
+
+    #[test]
+    fn test_email_job_rejected() {
+        let template = JobRejected {
+            job_title: "Software Engineer".to_string(),
+            link: "https://example.com/dashboard/employer?tab=jobs".to_string(),
+            review_notes: None,
+            unsubscribe_link: None,
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_job_rejected", &rendered);
+    }
+
+    #[test]
+    fn test_email_job_rejected_with_notes() {
+        let template = JobRejected {
+            job_title: "Software Engineer".to_string(),
+            link: "https://example.com/dashboard/employer?tab=jobs".to_string(),
+            review_notes: Some("Please add a salary range.".to_string()),
+            unsubscribe_link: None,
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_job_rejected_with_notes", &rendered);
+    }
+
+    #[test]
+    fn test_email_new_application() {
+        let template = NewApplication {
+            job_title: "Software Engineer".to_string(),
+            link: "https://example.com/dashboard/employer?tab=applications".to_string(),
+            settings_link: "https://example.com/dashboard/job-seeker?tab=account".to_string(),
+            unsubscribe_link: None,
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_new_application", &rendered);
+    }
+
+    #[test]
+    fn test_email_job_approved_with_unsubscribe_link() {
+        let template = JobApproved {
+            job_title: "Software Engineer".to_string(),
+            link: "https://example.com/?job_id=550e8400-e29b-41d4-a716-446655440000".to_string(),
+            scheduled_for: None,
+            unsubscribe_link: Some(UNSUBSCRIBE_LINK.to_string()),
+        };
+
+        let rendered = render_email(&template, UNSUBSCRIBE_LINK);
+        assert_golden_file("email_job_approved_with_unsubscribe_link", &rendered);
+    }
+
+    #[test]
+    fn test_email_new_application_with_unsubscribe_link() {
+        let template = NewApplication {
+            job_title: "Software Engineer".to_string(),
+            link: "https://example.com/dashboard/employer?tab=applications".to_string(),
+            settings_link: "https://example.com/dashboard/job-seeker?tab=account".to_string(),
+            unsubscribe_link: Some(UNSUBSCRIBE_LINK.to_string()),
+        };
+
+        let rendered = render_email(&template, UNSUBSCRIBE_LINK);
+        assert_golden_file("email_new_application_with_unsubscribe_link", &rendered);
+    }
+
+    #[test]
+    fn test_email_team_invitation() {
+        let template = TeamInvitation {
+            link: "https://example.com/dashboard/employer?tab=invitations".to_string(),
+            employer_name: Some("ACME Corp".to_string()),
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_team_invitation", &rendered);
+    }
+
+    #[test]
+    fn test_email_team_invitation_without_employer_name() {
+        let template = TeamInvitation {
+            link: "https://example.com/dashboard/employer?tab=invitations".to_string(),
+            employer_name: None,
+        };
+
+        let rendered = render_email(&template, &template.link);
+        assert_golden_file("email_team_invitation_without_employer_name", &rendered);
+    }
+
+    #[test]
+    fn test_email_subject_is_unescaped() {
+        let template = TeamInvitation {
+            link: "https://example.com/dashboard/employer?tab=invitations".to_string(),
+            employer_name: Some(r#"Foo & Bar <"Open" Source's>"#.to_string()),
+        };
+        let email = template.render_email().unwrap();
+        assert_eq!(
+            email.subject,
+            r#"You have been invited to join the Foo & Bar <"Open" Source's> team"#
+        );
+
+        let template = JobAlertDigest {
+            base_url: "https://example.com".to_string(),
+            jobs: vec![],
+            manage_link: "https://example.com/dashboard/job-seeker?tab=saved-searches".to_string(),
+            name: "C & C++".to_string(),
+            search_link: "https://example.com/?ts_query=c".to_string(),
+            total: 0,
+            unsubscribe_link: None,
+        };
+        let email = template.render_email().unwrap();
+        assert_eq!(email.subject, r#"New jobs matching your saved search "C & C++""#);
+    }
+
+    #[test]
+    fn test_extract_subject() {
+        let html = "<html><head><title>\n  Tom &amp; Jerry&#x27;s &#34;team&#34; &#60;3 &unknown;\n</title></head></html>";
+        assert_eq!(extract_subject(html), r#"Tom & Jerry's "team" <3 &unknown;"#);
+        assert_eq!(extract_subject("<html></html>"), "");
+    }
 }
@@ -0,0 +1,175 @@
+//! Helpers shared by the templates tests.
+
+use std::{env, fs};
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::templates::{
+    dashboard::employer::{
+        applications::Application,
+        jobs::{self, JobKind, JobStatus, Workplace},
+    },
+    jobboard::jobs::{Employer, Job, JobSummary},
+};
+
+/// Create a job with the minimum details set.
+pub(crate) fn create_base_job() -> Job {
+    Job {
+        job_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+        title: "Software Engineer".to_string(),
+        employer: Employer {
+            employer_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap(),
+            company: "ACME Corp".to_string(),
+            description: None,
+            logo_id: None,
+            member: None,
+            website_url: None,
+        },
+        kind: JobKind::FullTime,
+        workplace: Workplace::OnSite,
+        location: None,
+        seniority: None,
+        salary: None,
+        salary_currency: None,
+        salary_min: None,
+        salary_max: None,
+        salary_period: None,
+        screening_questions: None,
+        open_source: None,
+        upstream_commitment: None,
+        skills: None,
+        description: "Job description".to_string(),
+        apply_instructions: None,
+        apply_url: None,
+        benefits: None,
+        certifications: None,
+        projects: None,
+        published_at: None,
+        qualifications: None,
+        responsibilities: None,
+        tz_end: None,
+        tz_start: None,
+        updated_at: None,
+    }
+}
+
+/// Create a dashboard job with the minimum details set.
+pub(crate) fn create_base_dashboard_job() -> jobs::Job {
+    jobs::Job {
+        description: "Job description".to_string(),
+        status: JobStatus::Draft,
+        title: "Software Engineer".to_string(),
+        kind: JobKind::FullTime,
+        workplace: Workplace::OnSite,
+        apply_instructions: None,
+        apply_url: None,
+        benefits: None,
+        certifications: None,
+        closing_date: None,
+        job_id: Some(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap()),
+        location: None,
+        open_source: None,
+        projects: None,
+        publish_date: None,
+        published_at: None,
+        qualifications: None,
+        responsibilities: None,
+        review_notes: None,
+        salary: None,
+        salary_usd_year: None,
+        salary_currency: None,
+        salary_min: None,
+        salary_min_usd_year: None,
+        salary_max: None,
+        salary_max_usd_year: None,
+        salary_period: None,
+        seniority: None,
+        screening_questions: None,
+        skills: None,
+        tz_end: None,
+        tz_start: None,
+        updated_at: None,
+        upstream_commitment: None,
+    }
+}
+
+/// Create a job summary with the id and title provided.
+pub(crate) fn create_job_summary(job_id: &str, title: &str) -> JobSummary {
+    JobSummary {
+        employer: Employer {
+            employer_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap(),
+            company: "ACME Corp".to_string(),
+            description: None,
+            logo_id: None,
+            member: None,
+            website_url: None,
+        },
+        job_id: Uuid::parse_str(job_id).unwrap(),
+        kind: JobKind::FullTime,
+        published_at: Utc.with_ymd_and_hms(2025, 1, 15, 10, 0, 0).unwrap(),
+        title: title.to_string(),
+        workplace: Workplace::Remote,
+        location: None,
+        open_source: None,
+        projects: None,
+        salary: None,
+        salary_currency: None,
+        salary_min: None,
+        salary_max: None,
+        salary_period: None,
+        seniority: None,
+        skills: None,
+        updated_at: None,
+        upstream_commitment: None,
+    }
+}
+
+/// Create an application with the id and application date provided.
+pub(crate) fn create_application(application_id: Uuid, applied_at: DateTime<Utc>) -> Application {
+    serde_json::from_value(json!({
+        "application_id": application_id,
+        "name": "Jane Doe",
+        "applied_at": applied_at,
+        "email": "jane@example.com",
+        "job_id": "550e8400-e29b-41d4-a716-446655440000",
+        "job_title": "Software Engineer",
+        "job_seeker_profile_id": "550e8400-e29b-41d4-a716-446655440040",
+        "job_workplace": "remote",
+        "notes_count": 0,
+        "status": "new",
+    }))
+    .unwrap()
+}
+
+/// Returns the path of the golden file of the test provided.
+fn golden_file_path(test_name: &str) -> String {
+    format!("src/templates/testdata/{test_name}.golden")
+}
+
+/// Check the output provided matches the golden file of the test. Golden
+/// files are regenerated when `REGENERATE_GOLDEN_FILES` is set.
+pub(crate) fn assert_golden_file(test_name: &str, actual: &str) {
+    let golden_path = golden_file_path(test_name);
+
+    // Check if we should regenerate golden files
+    if env::var("REGENERATE_GOLDEN_FILES").is_ok() {
+        fs::write(&golden_path, actual).expect("Failed to write golden file");
+        eprintln!("Regenerated golden file: {golden_path}");
+        return;
+    }
+
+    // Read expected content from golden file
+    let expected = fs::read_to_string(&golden_path).unwrap_or_else(|_| {
+        panic!(
+            "Golden file not found: {golden_path}. Run tests with REGENERATE_GOLDEN_FILES=1 to generate it."
+        )
+    });
+
+    assert_eq!(
+        actual.trim(),
+        expected.trim(),
+        "Output doesn't match golden file: {golden_path}"
+    );
+}
@@ -2,20 +2,112 @@
 
 use std::time::Duration;
 
+use anyhow::Result;
 use tokio::time::sleep;
 use tokio_util::{sync::CancellationToken, task::TaskTracker};
-use tracing::{debug, error};
+use tracing::{debug, error, instrument, warn};
 
-use crate::db::DynDB;
+use crate::{
+    config::HttpServerConfig,
+    db::{
+        DynDB,
+        jobboard::JobsSearchOutput,
+        workers::{DueSavedSearch, ExpiringJob},
+    },
+    notifications::{DynNotificationsManager, NewNotification, NotificationKind, announce_job_published},
+    templates::{
+        notifications::{JobAlertDigest, JobExpiring},
+        pagination::build_url,
+    },
+};
+
+/// Maximum number of jobs included in a job alert digest.
+const JOB_ALERT_DIGEST_MAX_JOBS: usize = 10;
 
 /// Launches all background workers.
-pub(crate) fn run(db: DynDB, task_tracker: &TaskTracker, cancellation_token: CancellationToken) {
+pub(crate) fn run(
+    db: DynDB,
+    notifications_manager: DynNotificationsManager,
+    cfg: &HttpServerConfig,
+    task_tracker: &TaskTracker,
+    cancellation_token: CancellationToken,
+) {
+    let base_url = cfg.base_url.strip_suffix('/').unwrap_or(&cfg.base_url).to_string();
+
+    // Scheduled jobs publisher
+    {
+        let db = db.clone();
+        let base_url = base_url.clone();
+        let cancellation_token = cancellation_token.clone();
+        task_tracker.spawn(async move {
+            publisher(db, notifications_manager, base_url, cancellation_token).await;
+        });
+    }
+
     // Jobs archiver
+    {
+        let db = db.clone();
+        let cancellation_token = cancellation_token.clone();
+        task_tracker.spawn(async move {
+            archiver(db, cancellation_token).await;
+        });
+    }
+
+    // Expiry reminders sender
+    {
+        let db = db.clone();
+        let base_url = base_url.clone();
+        let cancellation_token = cancellation_token.clone();
+        task_tracker.spawn(async move {
+            expiry_reminders_sender(db, base_url, cancellation_token).await;
+        });
+    }
+
+    // Job alerts sender
     task_tracker.spawn(async move {
-        archiver(db, cancellation_token).await;
+        job_alerts_sender(db, base_url, cancellation_token).await;
     });
 }
 
+/// Worker that publishes the scheduled jobs whose publish date has arrived
+/// periodically.
+pub(crate) async fn publisher(
+    db: DynDB,
+    notifications_manager: DynNotificationsManager,
+    base_url: String,
+    cancellation_token: CancellationToken,
+) {
+    // Random sleep to avoid multiple workers running at the same time
+    tokio::select! {
+        () = sleep(Duration::from_secs(rand::random_range(60..300))) => {},
+        () = cancellation_token.cancelled() => return,
+    }
+
+    loop {
+        // Publish scheduled jobs
+        debug!("publishing scheduled jobs");
+        match db.publish_scheduled_jobs().await {
+            Ok(jobs) => {
+                // Announce the jobs published for the first time
+                for job in jobs.iter().filter(|job| job.first_published) {
+                    if let Err(err) =
+                        announce_job_published(&db, &notifications_manager, &base_url, &job.job_id).await
+                    {
+                        warn!("error announcing job published: {err}");
+                    }
+                }
+            }
+            Err(err) => error!("error publishing scheduled jobs: {err}"),
+        }
+
+        // Pause for a while before the next iteration
+        tokio::select! {
+            () = sleep(Duration::from_secs(60*60)) => {},
+            () = cancellation_token.cancelled() => break,
+        }
+    }
+}
+
 /// Worker that archives expired jobs periodically.
 pub(crate) async fn archiver(db: DynDB, cancellation_token: CancellationToken) {
     // Random sleep to avoid multiple workers running at the same time
@@ -38,3 +130,136 @@ pub(crate) async fn archiver(db: DynDB, cancellation_token: CancellationToken) {
         }
     }
 }
+
+/// Worker that reminds employers about their jobs expiring soon periodically.
+pub(crate) async fn expiry_reminders_sender(
+    db: DynDB,
+    base_url: String,
+    cancellation_token: CancellationToken,
+) {
+    // Random sleep to avoid multiple workers running at the same time
+    tokio::select! {
+        () = sleep(Duration::from_secs(rand::random_range(60..300))) => {},
+        () = cancellation_token.cancelled() => return,
+    }
+
+    loop {
+        // Send reminders for the jobs expiring soon
+        debug!("sending expiry reminders");
+        match db.get_expiring_jobs().await {
+            Ok(jobs) => {
+                for job in jobs {
+                    if let Err(err) = send_expiry_reminder(&db, &base_url, &job).await {
+                        error!("error sending expiry reminder: {err}");
+                    }
+                }
+            }
+            Err(err) => error!("error getting expiring jobs: {err}"),
+        }
+
+        // Pause for a while before the next iteration
+        tokio::select! {
+            () = sleep(Duration::from_secs(60*60)) => {},
+            () = cancellation_token.cancelled() => break,
+        }
+    }
+}
+
+/// Worker that sends job alerts for the saved searches that are due periodically.
+pub(crate) async fn job_alerts_sender(db: DynDB, base_url: String, cancellation_token: CancellationToken) {
+    // Random sleep to avoid multiple workers running at the same time
+    tokio::select! {
+        () = sleep(Duration::from_secs(rand::random_range(60..300))) => {},
+        () = cancellation_token.cancelled() => return,
+    }
+
+    loop {
+        // Send job alerts for the saved searches that are due
+        debug!("sending job alerts");
+        match db.get_due_saved_searches().await {
+            Ok(saved_searches) => {
+                for saved_search in saved_searches {
+                    if let Err(err) = send_job_alert(&db, &base_url, &saved_search).await {
+                        error!("error sending job alert: {err}");
+                    }
+                }
+            }
+            Err(err) => error!("error getting due saved searches: {err}"),
+        }
+
+        // Pause for a while before the next iteration
+        tokio::select! {
+            () = sleep(Duration::from_secs(60*60)) => {},
+            () = cancellation_token.cancelled() => break,
+        }
+    }
+}
+
+/// Enqueues a job expiring notification for each of the employer's team
+/// members, marking the job's expiry reminder as sent.
+#[instrument(skip(db), err)]
+async fn send_expiry_reminder(db: &DynDB, base_url: &str, job: &ExpiringJob) -> Result<()> {
+    let template_data = JobExpiring {
+        closing_date: job.closing_date,
+        job_title: job.title.clone(),
+        renew_link: format!("{base_url}/dashboard/employer/jobs/{}/renew", job.job_id),
+        unsubscribe_link: None,
+    };
+    let template_data = serde_json::to_value(&template_data)?;
+    let notifications: Vec<_> = job
+        .recipients
+        .iter()
+        .map(|user_id| NewNotification {
+            kind: NotificationKind::JobExpiring,
+            user_id: *user_id,
+            template_data: Some(template_data.clone()),
+        })
+        .collect();
+
+    // Mark expiry reminder as sent, enqueuing the notifications
+    db.complete_expiry_reminder(job, &notifications).await
+}
+
+/// Enqueues a job alert digest notification with the jobs published since the
+/// saved search was last run (if any), marking it as run.
+#[instrument(skip(db), err)]
+async fn send_job_alert(db: &DynDB, base_url: &str, saved_search: &DueSavedSearch) -> Result<()> {
+    // Mark saved search as run without notifying users who disabled job alerts
+    if !saved_search.notify {
+        return db.complete_saved_search_run(saved_search, None).await;
+    }
+
+    // Get jobs published since the last run (jobs published after the saved
+    // search was retrieved will be included in the next digest)
+    let mut filters = saved_search.filters.clone();
+    filters.limit = Some(JOB_ALERT_DIGEST_MAX_JOBS);
+    filters.offset = None;
+    filters.published_after = Some(saved_search.since);
+    filters.published_before = Some(saved_search.until);
+    filters.sort = None;
+    let JobsSearchOutput { jobs, total } = db.search_jobs(&filters).await?;
+
+    // Prepare job alert digest notification (if there are new jobs)
+    let notification = if jobs.is_empty() {
+        None
+    } else {
+        let template_data = JobAlertDigest {
+            base_url: base_url.to_string(),
+            jobs,
+            manage_link: format!("{base_url}/dashboard/job-seeker?tab=saved-searches"),
+            name: saved_search.name.clone(),
+            search_link: build_url(&format!("{base_url}/"), &saved_search.filters)?,
+            total,
+            unsubscribe_link: None,
+        };
+        Some(NewNotification {
+            kind: NotificationKind::JobAlertDigest,
+            user_id: saved_search.user_id,
+            template_data: Some(serde_json::to_value(&template_data)?),
+        })
+    };
+
+    // Mark saved search as run, enqueuing the notification
+    db.complete_saved_search_run(saved_search, notification.as_ref())
+        .await
+}
@@ -0,0 +1,193 @@
+import { html, repeat } from "/static/vendor/js/lit-all.v3.2.1.min.js";
+import { LitWrapper } from "/static/js/common/lit-wrapper.js";
+
+/**
+ * Component for managing the screening questions of a job.
+ * Supports adding and removing questions of different kinds.
+ * @extends LitWrapper
+ */
+export class ScreeningQuestions extends LitWrapper {
+  /**
+   * Component properties definition
+   * @property {Array} questions - List of screening questions
+   * Each entry is an object with:
+   *  - id: Unique identifier
+   *  - question: Question text
+   *  - kind: Kind of answer expected (free-text, yes-no, single-choice)
+   *  - options: Options to choose from (single-choice only)
+   */
+  static properties = {
+    questions: { type: Array },
+  };
+
+  constructor() {
+    super();
+    this.questions = [];
+  }
+
+  connectedCallback() {
+    super.connectedCallback();
+    this._initializeQuestionsIds();
+  }
+
+  /**
+   * Assigns unique IDs to question entries.
+   * @private
+   */
+  _initializeQuestionsIds() {
+    if (this.questions === null) {
+      this.questions = [];
+    } else {
+      this.questions = this.questions.map((item, index) => {
+        return { ...item, options: item.options || [], id: index };
+      });
+    }
+    this._nextId = this.questions.length;
+  }
+
+  /**
+   * Creates a new empty question data object.
+   * @returns {Object} Empty question entry
+   * @private
+   */
+  _getData = () => {
+    return {
+      id: this._nextId++,
+      question: "",
+      kind: "free-text",
+      options: [],
+    };
+  };
+
+  /**
+   * Adds a new question at the end of the list.
+   * @private
+   */
+  _addQuestion() {
+    this.questions = [...this.questions, this._getData()];
+  }
+
+  /**
+   * Removes question entry at specified index.
+   * @param {number} index - Position of entry to remove
+   * @private
+   */
+  _removeQuestion(index) {
+    this.questions = this.questions.filter((_, i) => i !== index);
+  }
+
+  /**
+   * Updates a field of the question at specified index.
+   * @param {number} index - Index of entry to update
+   * @param {string} name - Name of the field to update
+   * @param {*} value - New value
+   * @private
+   */
+  _onDataChange(index, name, value) {
+    const currentQuestions = [...this.questions];
+    currentQuestions[index] = { ...currentQuestions[index], [name]: value };
+    this.questions = currentQuestions;
+  }
+
+  /**
+   * Renders a question entry with controls.
+   * @param {number} index - Entry index
+   * @param {Object} question - Question data
+   * @returns {import('lit').TemplateResult} Entry template
+   * @private
+   */
+  _getQuestion(index, question) {
+    const isSingleChoice = question.kind === "single-choice";
+
+    return html`<div class="flex w-full xl:w-2/3 mt-6">
+      <div class="me-3">
+        <button
+          @click=${() => this._removeQuestion(index)}
+          type="button"
+          class="cursor-pointer p-2 border border-stone-200 hover:bg-stone-100 rounded-full"
+          title="Delete"
+        >
+          <div class="svg-icon size-4 icon-trash bg-stone-600"></div>
+        </button>
+      </div>
+      <div
+        class="grid grid-cols-1 gap-x-6 gap-y-6 sm:grid-cols-6 border-2 border-stone-200 border-dashed p-6 rounded-lg bg-stone-50/25 w-full"
+      >
+        <div class="col-span-4">
+          <label class="form-label"> Question <span class="asterisk">*</span> </label>
+          <div class="mt-2">
+            <input
+              @input=${(e) => this._onDataChange(index, "question", e.target.value)}
+              type="text"
+              name="screening_questions[${index}][question]"
+              class="input-primary"
+              maxlength="250"
+              .value="${question.question}"
+              autocomplete="off"
+              required
+            />
+          </div>
+        </div>
+
+        <div class="col-span-2">
+          <label class="form-label"> Answer </label>
+          <div class="mt-2">
+            <select
+              @change=${(e) => this._onDataChange(index, "kind", e.target.value)}
+              name="screening_questions[${index}][kind]"
+              class="select-primary"
+            >
+              <option value="free-text" ?selected=${question.kind === "free-text"}>Free text</option>
+              <option value="yes-no" ?selected=${question.kind === "yes-no"}>Yes / No</option>
+              <option value="single-choice" ?selected=${isSingleChoice}>Single choice</option>
+            </select>
+          </div>
+        </div>
+
+        ${isSingleChoice
+          ? html`<div class="col-span-full">
+              <label class="form-label"> Options <span class="asterisk">*</span> </label>
+              <div class="mt-2">
+                <textarea
+                  @input=${(e) => this._onDataChange(index, "options", e.target.value.split("\n"))}
+                  rows="3"
+                  class="input-primary"
+                  .value="${question.options.join("\n")}"
+                  required
+                ></textarea>
+              </div>
+              <p class="form-legend">One option per line (up to 100 characters each). At least two options are required.</p>
+              ${question.options
+                .filter((option) => option.trim() !== "")
+                .map(
+                  (option, optionIndex) =>
+                    html`<input
+                      type="hidden"
+                      name="screening_questions[${index}][options][${optionIndex}]"
+                      .value="${option.trim()}"
+                    />`,
+                )}
+            </div>`
+          : ""}
+      </div>
+    </div>`;
+  }
+
+  render() {
+    return html`
+      <div id="screening-questions-section">
+        ${repeat(
+          this.questions,
+          (d) => d.id,
+          (d, index) => this._getQuestion(index, d),
+        )}
+      </div>
+      <div class="mt-6">
+        <button @click=${() => this._addQuestion()} type="button" class="btn-primary-outline btn-mini">
+          Add question
+        </button>
+      </div>
+    `;
+  }
+}
+customElements.define("screening-questions", ScreeningQuestions);
@@ -0,0 +1,61 @@
+/**
+ * GitJobs embed loader.
+ *
+ * Injects the jobs embed into the page as an iframe, right where the script
+ * is included, and resizes it automatically to fit its content.
+ *
+ * Options are provided using data attributes in the script element:
+ *   - data-query: filters to apply (query string, i.e. "kind[0]=full-time")
+ *   - data-limit: maximum number of jobs to display
+ *   - data-layout: "compact" or "detailed"
+ *   - data-theme: "light" or "dark"
+ *   - data-accent-color: accent color in hex notation (i.e. "#84cc16")
+ *   - data-bg-color: background color in hex notation (i.e. "#ffffff")
+ *
+ * All options are validated by the server.
+ */
+(() => {
+  const script = document.currentScript;
+  if (!script) {
+    return;
+  }
+
+  const origin = new URL(script.src).origin;
+  const data = script.dataset;
+
+  // Prepare embed url
+  const params = new URLSearchParams(data.query || "");
+  const options = {
+    limit: data.limit,
+    layout: data.layout,
+    theme: data.theme,
+    accent_color: data.accentColor,
+    bg_color: data.bgColor,
+  };
+  Object.entries(options).forEach(([name, value]) => {
+    if (value) {
+      params.set(name, value);
+    }
+  });
+
+  // Create iframe
+  const iframe = document.createElement("iframe");
+  iframe.src = `${origin}/embed?${params.toString()}`;
+  iframe.title = "GitJobs";
+  iframe.loading = "lazy";
+  iframe.style.cssText = "width:100%;max-width:870px;height:0;display:block;border:none;";
+  script.parentNode.insertBefore(iframe, script.nextSibling);
+
+  // Resize iframe when its content height changes
+  window.addEventListener("message", (event) => {
+    if (event.origin !== origin || event.source !== iframe.contentWindow) {
+      return;
+    }
+    if (event.data && event.data.type === "gitjobs:resize") {
+      const height = Number(event.data.height);
+      if (Number.isFinite(height) && height > 0) {
+        iframe.style.height = `${Math.ceil(height)}px`;
+      }
+    }
+  });
+})();
@@ -1,10 +1,5 @@
-import {
-  showConfirmAlert,
-  showErrorAlert,
-  showInfoAlert,
-  showSuccessAlert,
-} from "/static/js/common/alerts.js";
-import { isSuccessfulXHRStatus } from "/static/js/common/common.js";
+import { showErrorAlert, showInfoAlert, showSuccessAlert } from "/static/js/common/alerts.js";
+import { isSuccessfulXHRStatus, toggleModalVisibility } from "/static/js/common/common.js";
 
 /**
  * Initializes the job application button functionality.
@@ -18,6 +13,13 @@ export const initializeApplyButton = () => {
 
   const applyUrl = applyButton.dataset.applyUrl;
   const userButton = document.getElementById("user-dropdown-button");
+
+  // On the standalone job page the user menu is loaded after the job, so we
+  // wait for it to be available before setting up the button
+  if (!userButton) {
+    document.body.addEventListener("htmx:afterSettle", initializeApplyButton, { once: true });
+    return;
+  }
   const isUserLoggedIn = userButton.dataset.loggedIn;
   const hasProfile = userButton.dataset.hasProfile;
 
@@ -45,17 +47,27 @@ export const initializeApplyButton = () => {
           );
         });
       } else {
-        const jobId = applyButton.dataset.jobId;
-        applyButton.setAttribute("hx-post", `/jobs/${jobId}/apply`);
-        applyButton.setAttribute("hx-trigger", "confirmed");
-        htmx.process(applyButton);
+        // Open the apply modal, where the cover letter and the answers to
+        // the screening questions can be provided
+        const applyForm = document.getElementById("apply-form");
+        const closeApplyModal = () => toggleModalVisibility("apply-modal", "close");
         applyButton.addEventListener("click", () => {
-          showConfirmAlert("Are you sure you want to apply to this job?", "apply-button", "Yes");
+          toggleModalVisibility("apply-modal", "open");
         });
+        document.getElementById("close-apply-modal").addEventListener("click", closeApplyModal);
+        document.getElementById("backdrop-apply-modal").addEventListener("click", closeApplyModal);
 
-        applyButton.addEventListener("htmx:afterRequest", (e) => {
-          if (isSuccessfulXHRStatus(e.detail.xhr.status)) {
+        applyForm.addEventListener("htmx:afterRequest", (e) => {
+          const status = e.detail.xhr.status;
+          if (isSuccessfulXHRStatus(status)) {
+            closeApplyModal();
+            applyForm.reset();
             showSuccessAlert("You have successfully applied to this job!");
+          } else if (status === 409) {
+            closeApplyModal();
+            showInfoAlert("You have already applied to this job.");
+          } else if (status === 422) {
+            showErrorAlert("Please answer all the screening questions before applying.");
           } else {
             showErrorAlert("An error occurred applying to this job, please try again later.");
           }
@@ -67,20 +79,35 @@ export const initializeApplyButton = () => {
 
 /**
  * Generates and displays the embed code for job listings.
- * Creates an iframe with current search parameters.
+ * Creates a loader script tag with current search parameters and the embed
+ * options selected (theme, layout, max jobs and accent color).
  */
 export const renderEmbedCode = () => {
   const embedCode = document.getElementById("embed-code");
   const params = new URLSearchParams(window.location.search);
-  params.append("limit", "10");
-  embedCode.textContent = `
-<iframe id="gitjobs" src="${window.location.origin}/embed?${params.toString()}" style="width:100%;max-width:870px;height:100%;display:block;border:none;"></iframe>
+  params.delete("job_id");
+  params.delete("limit");
+  params.delete("offset");
 
-<!-- Uncomment the following lines for resizing iframes dynamically using open-iframe-resizer
-<script type="module">
-  import { initialize } from "https://cdn.jsdelivr.net/npm/@open-iframe-resizer/core@latest/dist/index.js";
-  initialize({}, "#gitjobs");
-</script> -->`;
+  const attributes = [`src="${window.location.origin}/static/js/embed/loader.js"`];
+  if (params.toString() !== "") {
+    attributes.push(`data-query="${params.toString().replaceAll("&", "&amp;")}"`);
+  }
+
+  const options = {
+    "data-limit": document.getElementById("embed-limit")?.value || "10",
+    "data-layout": document.getElementById("embed-layout")?.value,
+    "data-theme": document.getElementById("embed-theme")?.value,
+    "data-accent-color": document.getElementById("embed-accent-color")?.value,
+  };
+  Object.entries(options).forEach(([name, value]) => {
+    if (value) {
+      attributes.push(`${name}="${value}"`);
+    }
+  });
+
+  embedCode.textContent = `
+<script ${attributes.join("\n        ")}></script>`;
 };
 
 /**